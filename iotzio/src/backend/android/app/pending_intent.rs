@@ -1,6 +1,6 @@
 use crate::backend::android::content::{Context, Intent};
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JObject, JValue, JValueGen};
 use jni::JNIEnv;
 use std::ops::Deref;
@@ -2,7 +2,7 @@ use crate::backend::android::content::intent_filter::IntentFilter;
 use crate::backend::android::content::Intent;
 use crate::backend::android::hardware::usb::UsbManager;
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JObject, JValue};
 use jni::JNIEnv;
 use jni_min_helper::BroadcastReceiver;
@@ -1,5 +1,5 @@
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JObject, JString, JValue};
 use jni::JNIEnv;
 use std::ops::Deref;
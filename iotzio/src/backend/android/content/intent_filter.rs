@@ -1,5 +1,5 @@
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JObject, JValue};
 use jni::JNIEnv;
 use std::ops::Deref;
@@ -34,4 +34,18 @@ impl IntentFilter {
             }),
         }
     }
+
+    pub fn add_action(&self, env: &mut JNIEnv, action: &str) -> Result<(), DeviceError> {
+        let java_str = env.new_string(action)?;
+
+        utils::call_method(
+            env,
+            self.instance.as_obj(),
+            "addAction",
+            "(Ljava/lang/String;)V",
+            &[JValue::Object(&java_str)],
+        )?;
+
+        Ok(())
+    }
 }
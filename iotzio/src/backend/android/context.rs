@@ -1,5 +1,5 @@
 use crate::backend::android::content::Context;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::JObject;
 use jni::{JNIEnv, JavaVM};
 use std::panic;
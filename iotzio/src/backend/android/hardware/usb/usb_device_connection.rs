@@ -2,7 +2,7 @@
 
 use crate::backend::android::hardware::usb::{UsbEndpoint, UsbInterface};
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JByteArray, JObject, JValue};
 use jni::sys::jint;
 use jni::JNIEnv;
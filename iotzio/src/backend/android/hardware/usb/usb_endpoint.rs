@@ -1,7 +1,7 @@
 #![forbid(unsafe_code)]
 
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JObject};
 use jni::JNIEnv;
 use std::ops::Deref;
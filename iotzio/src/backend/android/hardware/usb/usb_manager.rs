@@ -3,7 +3,7 @@
 use crate::backend::android::app::PendingIntent;
 use crate::backend::android::hardware::usb::{UsbDevice, UsbDeviceConnection};
 use crate::backend::android::utils;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::{GlobalRef, JMap, JObject, JString, JValue};
 use jni::JNIEnv;
 use std::collections::HashMap;
@@ -23,6 +23,10 @@ impl Deref for UsbManager {
 }
 
 impl UsbManager {
+    pub const ACTION_USB_DEVICE_ATTACHED: &'static str = "android.hardware.usb.action.USB_DEVICE_ATTACHED";
+
+    pub const ACTION_USB_DEVICE_DETACHED: &'static str = "android.hardware.usb.action.USB_DEVICE_DETACHED";
+
     pub fn new(instance: &JObject, env: &mut JNIEnv) -> Result<Self, DeviceError> {
         let instance = env.new_global_ref(instance)?;
 
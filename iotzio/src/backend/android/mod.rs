@@ -6,6 +6,7 @@ mod hardware;
 mod os;
 mod permissions;
 mod utils;
+mod watcher;
 
 use jni::objects::{JClass, JObject};
 use jni::sys::{jboolean, JNI_FALSE, JNI_TRUE};
@@ -14,6 +15,7 @@ use jni::JNIEnv;
 pub use self::backend::*;
 use self::context::*;
 use self::permissions::*;
+pub use self::watcher::*;
 
 #[no_mangle]
 pub extern "system" fn Java_com_iotzio_api_AndroidHelper_onActivityCreateNative(
@@ -1,4 +1,4 @@
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::JNIEnv;
 
 #[derive(Debug, Clone)]
@@ -4,7 +4,7 @@ use crate::backend::android::app::PendingIntent;
 use crate::backend::android::content::{Context, Intent, IntentFilter};
 use crate::backend::android::hardware::usb::{UsbDevice, UsbManager};
 use crate::backend::android::os::BuildVersion;
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::objects::JObject;
 use jni::{JNIEnv, JavaVM};
 use jni_min_helper::BroadcastReceiver;
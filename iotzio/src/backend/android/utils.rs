@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 
-use crate::backend::DeviceError;
+use crate::backend::android::DeviceError;
 use jni::descriptors::Desc;
 use jni::errors::Error;
 use jni::objects::{JClass, JObject, JString, JValue, JValueOwned};
@@ -0,0 +1,106 @@
+#![forbid(unsafe_code)]
+
+use crate::backend::android::content::{Context, IntentFilter};
+use crate::backend::android::hardware::usb::UsbManager;
+use crate::backend::android::os::BuildVersion;
+use crate::backend::android::{get_android_context, list_connected_boards, DeviceError};
+use crate::communication::Version;
+use crate::iotzio::iotzio_service::diff_connected_boards;
+use crate::IotzioEvent;
+use async_std::channel::{unbounded, Receiver, Sender};
+use async_std::task::block_on;
+use jni_min_helper::BroadcastReceiver;
+use log::warn;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, Mutex};
+
+pub fn watch_connected_boards(parse_version: fn(&str) -> Option<Version>) -> Result<DeviceWatcher, DeviceError> {
+    let (java_vm, context_object) = get_android_context()?;
+
+    let mut env = java_vm.attach_current_thread()?;
+
+    let context = Context::new(&context_object, &mut env)?;
+
+    let (tx, rx) = unbounded();
+
+    let known_boards = Arc::new(Mutex::new(Vec::new()));
+
+    // Connected boards are listed again on every USB attach or detach broadcast and compared with the previous listing.
+
+    update_watcher(&tx, &known_boards, parse_version);
+
+    let context_listener = {
+        let tx = tx.clone();
+
+        Arc::new(BroadcastReceiver::build(move |_, _, _| {
+            update_watcher(&tx, &known_boards, parse_version);
+
+            Ok(())
+        })?)
+    };
+
+    let context_listener_filter = IntentFilter::new_action(&mut env, UsbManager::ACTION_USB_DEVICE_ATTACHED)?;
+
+    context_listener_filter.add_action(&mut env, UsbManager::ACTION_USB_DEVICE_DETACHED)?;
+
+    if BuildVersion::current(&mut env)? >= BuildVersion::TIRAMISU {
+        context.register_receiver_with_flags(
+            &mut env,
+            &context_listener,
+            &context_listener_filter,
+            Context::RECEIVER_NOT_EXPORTED,
+        )?;
+    } else {
+        context.register_receiver(&mut env, &context_listener, &context_listener_filter)?;
+    }
+
+    Ok(DeviceWatcher {
+        receiver: rx,
+        context,
+        context_listener,
+    })
+}
+
+pub struct DeviceWatcher {
+    pub receiver: Receiver<IotzioEvent>,
+    context: Context,
+    context_listener: Arc<BroadcastReceiver>,
+}
+
+impl Debug for DeviceWatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeviceWatcher")
+            .field("receiver", &self.receiver)
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for DeviceWatcher {
+    fn drop(&mut self) {
+        let result = get_android_context().and_then(|(java_vm, _)| {
+            let mut env = java_vm.attach_current_thread()?;
+
+            self.context.unregister_receiver(&mut env, &self.context_listener)
+        });
+
+        if let Err(x) = result {
+            warn!("Error unregistering Iotzio board watcher: {0}", x);
+        }
+    }
+}
+
+fn update_watcher(
+    tx: &Sender<IotzioEvent>,
+    known_boards: &Mutex<Vec<u64>>,
+    parse_version: fn(&str) -> Option<Version>,
+) {
+    match block_on(list_connected_boards(parse_version)) {
+        Ok(boards) => {
+            for event in diff_connected_boards(&mut known_boards.lock().unwrap(), boards) {
+                _ = tx.try_send(event);
+            }
+        }
+        Err(x) => warn!("Error listing connected Iotzio boards: {0}", x),
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::communication::Version;
 use crate::iotzio::{USB_PRODUCT_ID, USB_PRODUCT_NAME_PREFIX, USB_USAGE_ID, USB_USAGE_PAGE, USB_VENDOR_ID};
-use crate::IotzioInfo;
+use crate::{IotzioEvent, IotzioInfo};
 use async_std::channel::{unbounded, Receiver};
 use async_std::task::block_on;
 use js_sys::wasm_bindgen::prelude::wasm_bindgen;
@@ -16,9 +16,9 @@ use std::hash::Hash;
 use std::hash::{DefaultHasher, Hasher};
 use std::sync::Arc;
 use wasm_bindgen::closure::Closure;
-use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::wasm_bindgen::JsCast;
-use web_sys::{window, Hid, HidDevice, HidDeviceRequestOptions, HidInputReportEvent};
+use web_sys::{window, Hid, HidConnectionEvent, HidDevice, HidDeviceRequestOptions, HidInputReportEvent};
 
 #[derive(Serialize, Deserialize, Debug)]
 struct HidDeviceRequest {
@@ -49,34 +49,123 @@ pub async fn list_connected_boards(parse_version: fn(&str) -> Option<Version>) -
 
     let devices = cast::<js_sys::Array>(&js_devices)?
         .iter()
-        .filter_map(|x| {
-            let hid_device = match cast::<HidDevice>(&x) {
+        .filter_map(|x| get_iotzio_info(x, parse_version))
+        .collect();
+
+    Ok(devices)
+}
+
+pub fn watch_connected_boards(parse_version: fn(&str) -> Option<Version>) -> Result<DeviceWatcher, DeviceError> {
+    let api = get_web_hid_api()?;
+
+    let (tx, rx) = unbounded();
+
+    // Devices already permitted by the user are listed without showing the device chooser.
+
+    {
+        let tx = tx.clone();
+        let js_promise = api.get_devices();
+
+        spawn_local(async move {
+            let js_devices = match promise_to_future(js_promise).await {
                 Ok(x) => x,
-                Err(_) => return None,
+                Err(_) => return,
             };
 
-            if !hid_device.product_name().starts_with(USB_PRODUCT_NAME_PREFIX) {
-                return None;
+            if let Ok(devices) = cast::<js_sys::Array>(&js_devices) {
+                for iotzio_info in devices.iter().filter_map(|x| get_iotzio_info(x, parse_version)) {
+                    _ = tx.try_send(IotzioEvent::Connected(iotzio_info));
+                }
             }
+        });
+    }
 
-            let runtime_identifier = {
-                let mut hasher = DefaultHasher::new();
+    let on_connect = {
+        let tx = tx.clone();
 
-                to_string(&x).hash(&mut hasher);
+        Closure::wrap(Box::new(move |e: HidConnectionEvent| {
+            if let Some(iotzio_info) = get_iotzio_info(e.device().into(), parse_version) {
+                _ = tx.try_send(IotzioEvent::Connected(iotzio_info));
+            }
+        }) as Box<dyn FnMut(HidConnectionEvent)>)
+    };
 
-                hasher.finish()
-            };
+    let on_disconnect = Closure::wrap(Box::new(move |e: HidConnectionEvent| {
+        if e.device().product_name().starts_with(USB_PRODUCT_NAME_PREFIX) {
+            _ = tx.try_send(IotzioEvent::Disconnected(get_runtime_identifier(&e.device().into())));
+        }
+    }) as Box<dyn FnMut(HidConnectionEvent)>);
 
-            parse_version(&hid_device.product_name()).map(|version| IotzioInfo {
-                device_info: DeviceInfo { hid_device_object: x }.into(),
-                version,
-                serial_number: None,
-                runtime_identifier,
-            })
-        })
-        .collect();
+    // The listeners are removed again when the watcher is dropped, also if registering one of them fails.
 
-    Ok(devices)
+    let device_watcher = DeviceWatcher {
+        receiver: rx,
+        api,
+        on_connect,
+        on_disconnect,
+    };
+
+    device_watcher
+        .api
+        .add_event_listener_with_callback("connect", device_watcher.on_connect.as_ref().unchecked_ref())
+        .map_err(|x| to_string(&x))?;
+
+    device_watcher
+        .api
+        .add_event_listener_with_callback("disconnect", device_watcher.on_disconnect.as_ref().unchecked_ref())
+        .map_err(|x| to_string(&x))?;
+
+    Ok(device_watcher)
+}
+
+#[derive(Debug)]
+pub struct DeviceWatcher {
+    pub receiver: Receiver<IotzioEvent>,
+    api: Hid,
+    on_connect: Closure<dyn FnMut(HidConnectionEvent)>,
+    on_disconnect: Closure<dyn FnMut(HidConnectionEvent)>,
+}
+
+impl Drop for DeviceWatcher {
+    fn drop(&mut self) {
+        _ = self
+            .api
+            .remove_event_listener_with_callback("connect", self.on_connect.as_ref().unchecked_ref());
+
+        _ = self
+            .api
+            .remove_event_listener_with_callback("disconnect", self.on_disconnect.as_ref().unchecked_ref());
+    }
+}
+
+#[inline]
+fn get_iotzio_info(hid_device_object: JsValue, parse_version: fn(&str) -> Option<Version>) -> Option<IotzioInfo> {
+    let hid_device = match cast::<HidDevice>(&hid_device_object) {
+        Ok(x) => x,
+        Err(_) => return None,
+    };
+
+    if !hid_device.product_name().starts_with(USB_PRODUCT_NAME_PREFIX) {
+        return None;
+    }
+
+    let runtime_identifier = get_runtime_identifier(&hid_device_object);
+
+    parse_version(&hid_device.product_name()).map(|version| IotzioInfo {
+        device_info: DeviceInfo { hid_device_object }.into(),
+        version,
+        serial_number: None,
+        runtime_identifier,
+    })
+}
+
+#[inline]
+fn get_runtime_identifier(hid_device_object: &JsValue) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    to_string(hid_device_object).hash(&mut hasher);
+
+    hasher.finish()
 }
 
 #[derive(Debug)]
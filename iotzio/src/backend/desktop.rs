@@ -1,13 +1,19 @@
 #![forbid(unsafe_code)]
 
 use crate::communication::Version;
+use crate::iotzio::iotzio_service::diff_connected_boards;
 use crate::iotzio::{USB_PRODUCT_ID, USB_PRODUCT_NAME_PREFIX, USB_USAGE_ID, USB_USAGE_PAGE, USB_VENDOR_ID};
-use crate::IotzioInfo;
+use crate::{IotzioEvent, IotzioInfo};
 use async_hid::SerialNumberExt;
+use async_std::channel::{unbounded, Receiver};
 use futures_lite::StreamExt;
+use log::warn;
 use std::fmt::{Debug, Formatter};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
+use std::time::Duration;
+
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
 
 pub async fn list_connected_boards(parse_version: fn(&str) -> Option<Version>) -> Result<Vec<IotzioInfo>, DeviceError> {
     let all_devices = async_hid::DeviceInfo::enumerate().await?;
@@ -47,6 +53,40 @@ pub async fn list_connected_boards(parse_version: fn(&str) -> Option<Version>) -
     Ok(vec)
 }
 
+pub fn watch_connected_boards(parse_version: fn(&str) -> Option<Version>) -> Result<DeviceWatcher, DeviceError> {
+    let (tx, rx) = unbounded();
+
+    // async-hid does not provide a device watcher in this version.
+    // Connected boards are listed periodically and compared with the previous listing instead.
+    // Listing stops once the receiver is dropped.
+
+    async_std::task::spawn(async move {
+        let mut known_boards = Vec::new();
+
+        while !tx.is_closed() {
+            match list_connected_boards(parse_version).await {
+                Ok(boards) => {
+                    for event in diff_connected_boards(&mut known_boards, boards) {
+                        if tx.send(event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(x) => warn!("Error listing connected Iotzio boards: {0}", x),
+            }
+
+            async_std::task::sleep(WATCH_INTERVAL).await;
+        }
+    });
+
+    Ok(DeviceWatcher { receiver: rx })
+}
+
+#[derive(Debug)]
+pub struct DeviceWatcher {
+    pub receiver: Receiver<IotzioEvent>,
+}
+
 #[derive(Debug)]
 pub struct DeviceInfo {
     inner: async_hid::DeviceInfo,
@@ -1,21 +1,31 @@
 cfg_if::cfg_if! {
     if #[cfg(all(feature = "_host", any(target_os = "windows", target_os = "linux", target_os = "macos")))] {
         mod desktop;
-        pub use self::desktop::*;
+        use self::desktop as platform;
     }
     else if #[cfg(all(feature = "_host", target_os = "android"))] {
         mod android;
-        pub use self::android::*;
+        use self::android as platform;
     }
     else if #[cfg(all(feature = "_host", target_family = "wasm"))] {
         mod browser;
-        pub use self::browser::*;
+        use self::browser as platform;
     }
     else if #[cfg(feature = "_host")] {
         compile_error!("Unsupported target. No backend available.");
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "_host", feature = "simulator", not(target_family = "wasm")))] {
+        mod simulator;
+        pub use self::simulator::*;
+    }
+    else if #[cfg(feature = "_host")] {
+        pub use self::platform::*;
+    }
+}
+
 #[cfg(all(feature = "_host", not(target_arch = "wasm32")))]
 static_assertions::assert_impl_all!(DeviceError: std::fmt::Debug, std::fmt::Display, Send, Sync);
 
@@ -39,3 +49,9 @@ static_assertions::assert_impl_all!(DeviceWriter: std::fmt::Debug, Send, Sync);
 
 #[cfg(all(feature = "_host", target_arch = "wasm32"))]
 static_assertions::assert_impl_all!(DeviceWriter: std::fmt::Debug);
+
+#[cfg(all(feature = "_host", not(target_arch = "wasm32")))]
+static_assertions::assert_impl_all!(DeviceWatcher: std::fmt::Debug, Send, Sync);
+
+#[cfg(all(feature = "_host", target_arch = "wasm32"))]
+static_assertions::assert_impl_all!(DeviceWatcher: std::fmt::Debug);
@@ -0,0 +1,238 @@
+#![forbid(unsafe_code)]
+
+use crate::backend::platform;
+use crate::communication::Version;
+use crate::simulator::{self, SimulatedBoard, SimulatedBoardEvent, SimulatedBoardState, SimulatedReport};
+use crate::{IotzioEvent, IotzioInfo};
+use async_std::channel::{unbounded, Receiver};
+use log::warn;
+use std::fmt::{Debug, Display, Formatter};
+
+pub async fn list_connected_boards(parse_version: fn(&str) -> Option<Version>) -> Result<Vec<IotzioInfo>, DeviceError> {
+    let simulated_boards: Vec<IotzioInfo> = simulator::connected_boards().into_iter().map(get_iotzio_info).collect();
+
+    // Missing platform support (e.g. no HID access in a CI environment) must not hide simulated boards.
+
+    let mut boards = match platform::list_connected_boards(parse_version).await {
+        Ok(x) => x,
+        Err(x) if !simulated_boards.is_empty() => {
+            warn!("Error listing connected Iotzio boards: {0}", x);
+
+            Vec::new()
+        }
+        Err(x) => return Err(DeviceError::Platform(x)),
+    };
+
+    boards.extend(simulated_boards);
+
+    Ok(boards)
+}
+
+pub fn watch_connected_boards(parse_version: fn(&str) -> Option<Version>) -> Result<DeviceWatcher, DeviceError> {
+    let (tx, rx) = unbounded();
+
+    let simulated_events = simulator::watch_connected_boards();
+
+    {
+        let tx = tx.clone();
+
+        async_std::task::spawn(async move {
+            while let Ok(event) = simulated_events.recv().await {
+                let event = match event {
+                    SimulatedBoardEvent::Connected(x) => IotzioEvent::Connected(get_iotzio_info(x)),
+                    SimulatedBoardEvent::Disconnected(x) => IotzioEvent::Disconnected(x),
+                };
+
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    // Missing platform support must not hide simulated boards, which may be connected at any time.
+    // The platform watcher is kept alive by this watcher, its events end once it is dropped.
+
+    let platform = match platform::watch_connected_boards(parse_version) {
+        Ok(platform_watcher) => {
+            let platform_events = platform_watcher.receiver.clone();
+
+            async_std::task::spawn(async move {
+                while let Ok(event) = platform_events.recv().await {
+                    if tx.send(event).await.is_err() {
+                        break;
+                    }
+                }
+            });
+
+            Some(platform_watcher)
+        }
+        Err(x) => {
+            warn!("Error watching connected Iotzio boards: {0}", x);
+
+            None
+        }
+    };
+
+    Ok(DeviceWatcher {
+        receiver: rx,
+        _platform: platform,
+    })
+}
+
+#[derive(Debug)]
+pub struct DeviceWatcher {
+    pub receiver: Receiver<IotzioEvent>,
+    _platform: Option<platform::DeviceWatcher>,
+}
+
+#[inline]
+fn get_iotzio_info(board: SimulatedBoard) -> IotzioInfo {
+    IotzioInfo {
+        version: board.version(),
+        serial_number: Some(board.serial_number()),
+        runtime_identifier: board.runtime_identifier(),
+        device_info: Box::new(DeviceInfo::Simulated(board)),
+    }
+}
+
+#[derive(Debug)]
+pub enum DeviceInfo {
+    Platform(platform::DeviceInfo),
+    Simulated(SimulatedBoard),
+}
+
+impl From<platform::DeviceInfo> for Box<DeviceInfo> {
+    #[inline]
+    fn from(value: platform::DeviceInfo) -> Self {
+        Box::new(DeviceInfo::Platform(value))
+    }
+}
+
+impl DeviceInfo {
+    pub async fn open(&self) -> Result<(DeviceReader, DeviceWriter), DeviceError> {
+        match self {
+            DeviceInfo::Platform(x) => {
+                let (reader, writer) = x.open().await.map_err(DeviceError::Platform)?;
+
+                Ok((DeviceReader::Platform(reader), DeviceWriter::Platform(writer)))
+            }
+            DeviceInfo::Simulated(x) => {
+                let (tx, rx) = unbounded();
+
+                let mut state = x.inner.lock().unwrap();
+
+                if state.connection.as_ref().is_some_and(|x| !x.is_closed()) {
+                    return Err(DeviceError::Simulated("Simulated board already opened.".to_string()));
+                }
+
+                state.connection = Some(tx);
+                state.connection_generation = state.connection_generation.wrapping_add(1);
+
+                let writer = DeviceWriter::Simulated {
+                    board: x.clone(),
+                    connection_generation: state.connection_generation,
+                };
+
+                drop(state);
+
+                Ok((DeviceReader::Simulated(rx), writer))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DeviceReader {
+    Platform(platform::DeviceReader),
+    Simulated(Receiver<SimulatedReport>),
+}
+
+impl DeviceReader {
+    pub async fn read_input_report(&mut self, buffer: &mut [u8]) -> Result<usize, DeviceError> {
+        match self {
+            DeviceReader::Platform(x) => x.read_input_report(buffer).await.map_err(DeviceError::Platform),
+            DeviceReader::Simulated(x) => {
+                let report = x
+                    .recv()
+                    .await
+                    .map_err(|_| DeviceError::Simulated("Simulated board disconnected.".to_string()))?;
+
+                simulator::write_input_report(report, buffer).map_err(DeviceError::Simulated)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DeviceWriter {
+    Platform(platform::DeviceWriter),
+    Simulated {
+        board: SimulatedBoard,
+        connection_generation: u32,
+    },
+}
+
+impl DeviceWriter {
+    pub async fn write_output_report(&mut self, buffer: &mut [u8]) -> Result<(), DeviceError> {
+        match self {
+            DeviceWriter::Platform(x) => x.write_output_report(buffer).await.map_err(DeviceError::Platform),
+            DeviceWriter::Simulated {
+                board,
+                connection_generation,
+            } => {
+                let mut state = board.inner.lock().unwrap();
+
+                match is_current_connection(&state, *connection_generation) {
+                    true => {
+                        simulator::handle_output_report(&mut state, buffer);
+
+                        Ok(())
+                    }
+                    false => Err(DeviceError::Simulated("Simulated board disconnected.".to_string())),
+                }
+            }
+        }
+    }
+}
+
+impl Drop for DeviceWriter {
+    #[inline]
+    fn drop(&mut self) {
+        if let DeviceWriter::Simulated {
+            board,
+            connection_generation,
+        } = self
+        {
+            let mut state = board.inner.lock().unwrap();
+
+            // A reopened board has a new connection, which must stay untouched by the previous writer.
+
+            if is_current_connection(&state, *connection_generation) {
+                if let Some(x) = state.connection.take() {
+                    x.close();
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_current_connection(state: &SimulatedBoardState, connection_generation: u32) -> bool {
+    state.connection_generation == connection_generation && state.connection.as_ref().is_some_and(|x| !x.is_closed())
+}
+
+#[derive(Debug)]
+pub enum DeviceError {
+    Platform(platform::DeviceError),
+    Simulated(String),
+}
+
+impl Display for DeviceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeviceError::Platform(x) => Display::fmt(x, f),
+            DeviceError::Simulated(x) => f.write_str(x),
+        }
+    }
+}
@@ -1,15 +1,20 @@
 use crate::modules;
-use crate::peripherals::gpio::{Drive, GpioPin, Level, Pull, SlewRate};
-use crate::peripherals::i2c::I2cBusNumber;
+use crate::peripherals::gpio::{Drive, GpioPin, Level, Pull, SlewRate, GPIO_PIN_COUNT};
+use crate::peripherals::i2c::{I2cAddress, I2cBusNumber};
+use crate::peripherals::pwm::PwmPolarity;
+use crate::peripherals::spi::SpiBusNumber;
+use crate::peripherals::uart::UartNumber;
 use crate::peripherals::BusBuffer;
 use serde::{Deserialize, Serialize};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "_host")] {
         use std::fmt;
+        use std::time::Duration;
     }
     else {
         use core::fmt;
+        use core::time::Duration;
     }
 }
 
@@ -60,12 +65,12 @@ pub enum Command {
     },
     I2c_ReadSingle {
         identifier: I2cBusNumber,
-        address: u16,
+        address: I2cAddress,
         buffer_size: u16,
     },
     I2c_StartReadChunked {
         identifier: I2cBusNumber,
-        address: u16,
+        address: I2cAddress,
         chunks_count: u32,
     },
     I2c_ReadChunk {
@@ -78,12 +83,12 @@ pub enum Command {
     },
     I2c_WriteSingle {
         identifier: I2cBusNumber,
-        address: u16,
+        address: I2cAddress,
         bytes: BusBuffer,
     },
     I2c_StartWriteChunked {
         identifier: I2cBusNumber,
-        address: u16,
+        address: I2cAddress,
         chunks_count: u32,
     },
     I2c_WriteChunk {
@@ -96,10 +101,348 @@ pub enum Command {
     },
     I2c_WriteReadSingle {
         identifier: I2cBusNumber,
-        address: u16,
+        address: I2cAddress,
         bytes: BusBuffer,
         buffer_size: u16,
     },
+    Spi_New {
+        config: modules::spi_bus::SpiConfig,
+    },
+    Spi_Drop {
+        identifier: SpiBusNumber,
+    },
+    Spi_ReadSingle {
+        identifier: SpiBusNumber,
+        buffer_size: u16,
+    },
+    Spi_StartReadChunked {
+        identifier: SpiBusNumber,
+        chunks_count: u32,
+    },
+    Spi_ReadChunk {
+        identifier: SpiBusNumber,
+        buffer_size: u16,
+        chunk_index: u32,
+    },
+    Spi_StopReadChunked {
+        identifier: SpiBusNumber,
+    },
+    Spi_WriteSingle {
+        identifier: SpiBusNumber,
+        bytes: BusBuffer,
+    },
+    Spi_StartWriteChunked {
+        identifier: SpiBusNumber,
+        chunks_count: u32,
+    },
+    Spi_WriteChunk {
+        identifier: SpiBusNumber,
+        bytes: BusBuffer,
+        chunk_index: u32,
+    },
+    Spi_StopWriteChunked {
+        identifier: SpiBusNumber,
+    },
+    Spi_TransferSingle {
+        identifier: SpiBusNumber,
+        bytes: BusBuffer,
+        buffer_size: u16,
+    },
+    Spi_StartTransferChunked {
+        identifier: SpiBusNumber,
+        chunks_count: u32,
+    },
+    Spi_TransferChunk {
+        identifier: SpiBusNumber,
+        bytes: BusBuffer,
+        buffer_size: u16,
+        chunk_index: u32,
+    },
+    Spi_StopTransferChunked {
+        identifier: SpiBusNumber,
+    },
+    PwmOutput_New {
+        pin: GpioPin,
+        frequency_hz: u32,
+        duty_cycle: u16,
+        polarity: PwmPolarity,
+    },
+    PwmOutput_Drop {
+        pin: GpioPin,
+    },
+    PwmOutput_SetDutyCycle {
+        pin: GpioPin,
+        duty_cycle: u16,
+    },
+    PwmOutput_SetFrequency {
+        pin: GpioPin,
+        frequency_hz: u32,
+    },
+    PwmOutput_SetPolarity {
+        pin: GpioPin,
+        polarity: PwmPolarity,
+    },
+    AnalogInput_New {
+        channel: modules::analog_input::AdcChannel,
+    },
+    AnalogInput_Drop {
+        channel: modules::analog_input::AdcChannel,
+    },
+    AnalogInput_Read {
+        channel: modules::analog_input::AdcChannel,
+        samples: u16,
+    },
+    Uart_New {
+        config: modules::uart::UartConfig,
+    },
+    Uart_Drop {
+        identifier: UartNumber,
+    },
+    Uart_Read {
+        identifier: UartNumber,
+        buffer_size: u16,
+    },
+    Uart_Write {
+        identifier: UartNumber,
+        bytes: BusBuffer,
+    },
+    Uart_Flush {
+        identifier: UartNumber,
+    },
+    OneWire_New {
+        pin: GpioPin,
+    },
+    OneWire_Drop {
+        pin: GpioPin,
+    },
+    OneWire_Reset {
+        pin: GpioPin,
+    },
+    OneWire_ReadBit {
+        pin: GpioPin,
+    },
+    OneWire_WriteBit {
+        pin: GpioPin,
+        bit: bool,
+    },
+    OneWire_ReadBytes {
+        pin: GpioPin,
+        buffer_size: u16,
+    },
+    OneWire_WriteBytes {
+        pin: GpioPin,
+        bytes: BusBuffer,
+    },
+    OneWire_Search {
+        pin: GpioPin,
+        alarm_only: bool,
+        previous: Option<modules::one_wire_bus::OneWireSearchState>,
+    },
+    I2c_Transaction {
+        identifier: I2cBusNumber,
+        address: I2cAddress,
+        operations: modules::i2c_bus::I2cTransactionOperations,
+        bytes: BusBuffer,
+    },
+    I2c_StartTransactionChunked {
+        identifier: I2cBusNumber,
+        address: I2cAddress,
+    },
+    I2c_TransactionReadChunk {
+        identifier: I2cBusNumber,
+        buffer_size: u16,
+        end: modules::i2c_bus::I2cTransactionChunkEnd,
+    },
+    I2c_TransactionWriteChunk {
+        identifier: I2cBusNumber,
+        bytes: BusBuffer,
+        end: modules::i2c_bus::I2cTransactionChunkEnd,
+    },
+    I2c_StopTransactionChunked {
+        identifier: I2cBusNumber,
+    },
+    I2c_Scan {
+        identifier: I2cBusNumber,
+    },
+    I2cTarget_New {
+        config: modules::i2c_target::I2cTargetConfig,
+        subscription: u32,
+    },
+    I2cTarget_Drop {
+        identifier: I2cBusNumber,
+    },
+    I2cTarget_WriteRegisters {
+        identifier: I2cBusNumber,
+        register: u8,
+        bytes: BusBuffer,
+    },
+    I2cTarget_ReadRegisters {
+        identifier: I2cBusNumber,
+        register: u8,
+        buffer_size: u16,
+    },
+    I2c_Recover {
+        identifier: I2cBusNumber,
+    },
+    InputPin_CancelWaitForSignal {
+        pin: GpioPin,
+    },
+    Batch {
+        count: u8,
+    },
+    OutputPort_New {
+        pins: heapless::Vec<modules::output_port::OutputPortPin, GPIO_PIN_COUNT>,
+    },
+    OutputPort_Drop {
+        pin_mask: u32,
+    },
+    OutputPort_SetLevels {
+        pin_mask: u32,
+        mask: u32,
+        value: u32,
+    },
+    InputPort_New {
+        pins: heapless::Vec<modules::input_port::InputPortPin, GPIO_PIN_COUNT>,
+    },
+    InputPort_Drop {
+        pin_mask: u32,
+    },
+    InputPort_GetLevels {
+        pin_mask: u32,
+    },
+    InputPort_WaitForChange {
+        pin_mask: u32,
+        levels: u32,
+    },
+    InputPort_CancelWaitForChange {
+        pin_mask: u32,
+    },
+    InputPin_Subscribe {
+        pin: GpioPin,
+        trigger: modules::input_pin::InterruptTrigger,
+        subscription: u32,
+    },
+    InputPin_Unsubscribe {
+        pin: GpioPin,
+    },
+    Counter_New {
+        pin: modules::counter::CounterPin,
+        edge: modules::counter::CounterEdge,
+    },
+    Counter_Drop {
+        pin: modules::counter::CounterPin,
+    },
+    Counter_Measure {
+        pin: modules::counter::CounterPin,
+        gate_time: Duration,
+    },
+    QuadratureEncoder_New {
+        pin_a: GpioPin,
+        pin_b: GpioPin,
+        index_pin: Option<GpioPin>,
+        pull_setting: Pull,
+    },
+    QuadratureEncoder_Drop {
+        pin_a: GpioPin,
+    },
+    QuadratureEncoder_Read {
+        pin_a: GpioPin,
+    },
+    QuadratureEncoder_Reset {
+        pin_a: GpioPin,
+    },
+    LedStrip_New {
+        pin: GpioPin,
+        led_type: modules::led_strip::LedStripType,
+        led_count: u16,
+        brightness: u8,
+    },
+    LedStrip_Drop {
+        pin: GpioPin,
+    },
+    LedStrip_WriteSingle {
+        pin: GpioPin,
+        bytes: BusBuffer,
+    },
+    LedStrip_StartWriteChunked {
+        pin: GpioPin,
+        chunks_count: u32,
+    },
+    LedStrip_WriteChunk {
+        pin: GpioPin,
+        bytes: BusBuffer,
+        chunk_index: u32,
+    },
+    LedStrip_StopWriteChunked {
+        pin: GpioPin,
+    },
+    LedStrip_SetBrightness {
+        pin: GpioPin,
+        brightness: u8,
+    },
+    Servo_New {
+        pin: GpioPin,
+        config: modules::servo::ServoConfig,
+        pulse_width: Option<Duration>,
+    },
+    Servo_Drop {
+        pin: GpioPin,
+    },
+    Servo_SetPulseWidth {
+        pin: GpioPin,
+        pulse_width: Duration,
+    },
+    Servo_Sweep {
+        pin: GpioPin,
+        pulse_width: Duration,
+        pulse_width_per_second: Duration,
+    },
+    Stepper_New {
+        config: modules::stepper::StepperConfig,
+        position: i64,
+        enabled: bool,
+    },
+    Stepper_Drop {
+        step_pin: GpioPin,
+    },
+    Stepper_MoveTo {
+        step_pin: GpioPin,
+        position: i64,
+    },
+    Stepper_MoveBy {
+        step_pin: GpioPin,
+        steps: i64,
+    },
+    Stepper_Stop {
+        step_pin: GpioPin,
+    },
+    Stepper_EmergencyStop {
+        step_pin: GpioPin,
+    },
+    Stepper_GetStatus {
+        step_pin: GpioPin,
+    },
+    Stepper_SetPosition {
+        step_pin: GpioPin,
+        position: i64,
+    },
+    Stepper_SetEnabled {
+        step_pin: GpioPin,
+        enabled: bool,
+    },
+    PulseIn_New {
+        config: modules::pulse_in::PulseInConfig,
+    },
+    PulseIn_Drop {
+        trigger_pin: GpioPin,
+    },
+    PulseIn_Measure {
+        trigger_pin: GpioPin,
+        timeout: Duration,
+    },
+    Uart_CancelRead {
+        identifier: UartNumber,
+    },
 }
 
 impl Command {
@@ -124,6 +467,95 @@ impl Command {
             Command::I2c_WriteChunk { .. } => 16,
             Command::I2c_StopWriteChunked { .. } => 17,
             Command::I2c_WriteReadSingle { .. } => 18,
+            Command::Spi_New { .. } => 19,
+            Command::Spi_Drop { .. } => 20,
+            Command::Spi_ReadSingle { .. } => 21,
+            Command::Spi_StartReadChunked { .. } => 22,
+            Command::Spi_ReadChunk { .. } => 23,
+            Command::Spi_StopReadChunked { .. } => 24,
+            Command::Spi_WriteSingle { .. } => 25,
+            Command::Spi_StartWriteChunked { .. } => 26,
+            Command::Spi_WriteChunk { .. } => 27,
+            Command::Spi_StopWriteChunked { .. } => 28,
+            Command::Spi_TransferSingle { .. } => 29,
+            Command::Spi_StartTransferChunked { .. } => 30,
+            Command::Spi_TransferChunk { .. } => 31,
+            Command::Spi_StopTransferChunked { .. } => 32,
+            Command::PwmOutput_New { .. } => 33,
+            Command::PwmOutput_Drop { .. } => 34,
+            Command::PwmOutput_SetDutyCycle { .. } => 35,
+            Command::PwmOutput_SetFrequency { .. } => 36,
+            Command::PwmOutput_SetPolarity { .. } => 37,
+            Command::AnalogInput_New { .. } => 38,
+            Command::AnalogInput_Drop { .. } => 39,
+            Command::AnalogInput_Read { .. } => 40,
+            Command::Uart_New { .. } => 41,
+            Command::Uart_Drop { .. } => 42,
+            Command::Uart_Read { .. } => 43,
+            Command::Uart_Write { .. } => 44,
+            Command::Uart_Flush { .. } => 45,
+            Command::OneWire_New { .. } => 46,
+            Command::OneWire_Drop { .. } => 47,
+            Command::OneWire_Reset { .. } => 48,
+            Command::OneWire_ReadBit { .. } => 49,
+            Command::OneWire_WriteBit { .. } => 50,
+            Command::OneWire_ReadBytes { .. } => 51,
+            Command::OneWire_WriteBytes { .. } => 52,
+            Command::OneWire_Search { .. } => 53,
+            Command::I2c_Transaction { .. } => 54,
+            Command::I2c_StartTransactionChunked { .. } => 55,
+            Command::I2c_TransactionReadChunk { .. } => 56,
+            Command::I2c_TransactionWriteChunk { .. } => 57,
+            Command::I2c_StopTransactionChunked { .. } => 58,
+            Command::I2c_Scan { .. } => 59,
+            Command::I2cTarget_New { .. } => 60,
+            Command::I2cTarget_Drop { .. } => 61,
+            Command::I2cTarget_WriteRegisters { .. } => 62,
+            Command::I2cTarget_ReadRegisters { .. } => 63,
+            Command::I2c_Recover { .. } => 64,
+            Command::InputPin_CancelWaitForSignal { .. } => 65,
+            Command::Batch { .. } => 66,
+            Command::OutputPort_New { .. } => 67,
+            Command::OutputPort_Drop { .. } => 68,
+            Command::OutputPort_SetLevels { .. } => 69,
+            Command::InputPort_New { .. } => 70,
+            Command::InputPort_Drop { .. } => 71,
+            Command::InputPort_GetLevels { .. } => 72,
+            Command::InputPort_WaitForChange { .. } => 73,
+            Command::InputPort_CancelWaitForChange { .. } => 74,
+            Command::InputPin_Subscribe { .. } => 75,
+            Command::InputPin_Unsubscribe { .. } => 76,
+            Command::Counter_New { .. } => 77,
+            Command::Counter_Drop { .. } => 78,
+            Command::Counter_Measure { .. } => 79,
+            Command::QuadratureEncoder_New { .. } => 80,
+            Command::QuadratureEncoder_Drop { .. } => 81,
+            Command::QuadratureEncoder_Read { .. } => 82,
+            Command::QuadratureEncoder_Reset { .. } => 83,
+            Command::LedStrip_New { .. } => 84,
+            Command::LedStrip_Drop { .. } => 85,
+            Command::LedStrip_WriteSingle { .. } => 86,
+            Command::LedStrip_StartWriteChunked { .. } => 87,
+            Command::LedStrip_WriteChunk { .. } => 88,
+            Command::LedStrip_StopWriteChunked { .. } => 89,
+            Command::LedStrip_SetBrightness { .. } => 90,
+            Command::Servo_New { .. } => 91,
+            Command::Servo_Drop { .. } => 92,
+            Command::Servo_SetPulseWidth { .. } => 93,
+            Command::Servo_Sweep { .. } => 94,
+            Command::Stepper_New { .. } => 95,
+            Command::Stepper_Drop { .. } => 96,
+            Command::Stepper_MoveTo { .. } => 97,
+            Command::Stepper_MoveBy { .. } => 98,
+            Command::Stepper_Stop { .. } => 99,
+            Command::Stepper_EmergencyStop { .. } => 100,
+            Command::Stepper_GetStatus { .. } => 101,
+            Command::Stepper_SetPosition { .. } => 102,
+            Command::Stepper_SetEnabled { .. } => 103,
+            Command::PulseIn_New { .. } => 104,
+            Command::PulseIn_Drop { .. } => 105,
+            Command::PulseIn_Measure { .. } => 106,
+            Command::Uart_CancelRead { .. } => 107,
         };
 
         debug_assert!(value < COMMAND_COUNT, "Command count not updated.");
@@ -132,4 +564,4 @@ impl Command {
     }
 }
 
-pub const COMMAND_COUNT: u16 = 19;
+pub const COMMAND_COUNT: u16 = 108;
@@ -0,0 +1,30 @@
+use crate::modules::i2c_target::I2cTargetDeviceEvent;
+use crate::peripherals::gpio::Level;
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::fmt;
+        use std::time::Duration;
+    }
+    else {
+        use core::fmt;
+        use core::time::Duration;
+    }
+}
+
+impl fmt::Display for DeviceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Events are built on the device without an allocator, so large variants cannot be boxed.
+#[allow(non_camel_case_types, clippy::large_enum_variant)]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DeviceEvent {
+    Overflow { dropped_count: u32 },
+    I2cTarget_Event { event: I2cTargetDeviceEvent },
+    InputPin_Triggered { level: Level, timestamp: Duration },
+}
@@ -1,4 +1,4 @@
-use crate::communication::{FatalError, Response};
+use crate::communication::{DeviceEvent, FatalError, Response};
 use crate::modules::ModuleError;
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +27,13 @@ pub enum DeviceReport {
     FatalError {
         error: FatalError,
     },
+    Event {
+        subscription: u32,
+        event: DeviceEvent,
+    },
+    Batch {
+        count: u8,
+    },
 }
 
 pub const DEVICE_REPORT_HEADER_SIZE: usize = 1;
@@ -11,7 +11,7 @@ cfg_if::cfg_if! {
     }
 }
 
-/// A fatal error means that communication with the Iotzio board can no longer proceed. Typically, this occurs when the physical USB connection has been lost. IotzioInfo must be opened again for further interaction.
+/// A fatal error means that communication with the Iotzio board can no longer proceed. Typically, this occurs when the physical USB connection has been lost. IotzioInfo must be opened again for further interaction, unless it was opened in resilient mode.
 #[non_exhaustive]
 #[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
 #[cfg_attr(feature = "_defmt", derive(defmt::Format))]
@@ -24,3 +24,9 @@ pub struct HostReport {
 }
 
 pub const HOST_REPORT_HEADER_SIZE: usize = 1 + 4 + 2;
+
+/// Maximum number of commands packed into a single batch report.
+pub const BATCH_MAX_COUNT: usize = u8::MAX as usize;
+
+/// Maximum size of the postcard encoded batch command (variant index varint, count), which precedes the packed host reports.
+pub const BATCH_HEADER_SIZE: usize = 2 + 1;
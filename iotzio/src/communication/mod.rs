@@ -2,6 +2,7 @@
 
 mod board_info;
 mod command;
+mod device_event;
 mod device_report;
 mod fatal_error;
 mod host_report;
@@ -12,23 +13,25 @@ mod version;
 cfg_if::cfg_if! {
     if #[cfg(feature = "_host")] {
         pub(crate) use self::command::*;
+        pub(crate) use self::device_event::*;
         pub(crate) use self::device_report::*;
         pub(crate) use self::host_report::*;
         pub(crate) use self::response::*;
         pub(crate) use self::board_info::*;
 
-        pub(crate) const IOTZIO_PROTOCOL_VERSION: u16 = 1;
+        pub(crate) const IOTZIO_PROTOCOL_VERSION: u16 = 7;
         pub(crate) const PROTOCOL_INFO_REPORT_ID: u8 = 0xFF;
         pub(crate) const PROTOCOL_INFO_BUFFER_SIZE: usize = 1025;
     }
     else {
         pub use self::command::*;
+        pub use self::device_event::*;
         pub use self::device_report::*;
         pub use self::host_report::*;
         pub use self::response::*;
         pub use self::board_info::*;
 
-        pub const IOTZIO_PROTOCOL_VERSION: u16 = 1;
+        pub const IOTZIO_PROTOCOL_VERSION: u16 = 7;
         pub const PROTOCOL_INFO_REPORT_ID: u8 = 0xFF;
         pub const PROTOCOL_INFO_BUFFER_SIZE: usize = 1025;
     }
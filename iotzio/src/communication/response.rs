@@ -7,9 +7,11 @@ use serde::{Deserialize, Serialize};
 cfg_if::cfg_if! {
     if #[cfg(feature = "_host")] {
         use std::fmt;
+        use std::time::Duration;
     }
     else {
         use core::fmt;
+        use core::time::Duration;
     }
 }
 
@@ -80,4 +82,274 @@ pub enum Response {
     I2c_WriteReadSingle {
         result: Result<BusBuffer, modules::i2c_bus::I2cBusModuleError>,
     },
+    Spi_New {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_Drop {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_ReadSingle {
+        result: Result<BusBuffer, modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_StartReadChunked {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_ReadChunk {
+        result: Result<BusBuffer, modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_StopReadChunked {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_WriteSingle {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_StartWriteChunked {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_WriteChunk {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_StopWriteChunked {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_TransferSingle {
+        result: Result<BusBuffer, modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_StartTransferChunked {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_TransferChunk {
+        result: Result<BusBuffer, modules::spi_bus::SpiBusModuleError>,
+    },
+    Spi_StopTransferChunked {
+        result: Result<(), modules::spi_bus::SpiBusModuleError>,
+    },
+    PwmOutput_New {
+        result: Result<(), modules::pwm_output::PwmOutputModuleError>,
+    },
+    PwmOutput_Drop {
+        result: Result<(), modules::pwm_output::PwmOutputModuleError>,
+    },
+    PwmOutput_SetDutyCycle {
+        result: Result<(), modules::pwm_output::PwmOutputModuleError>,
+    },
+    PwmOutput_SetFrequency {
+        result: Result<(), modules::pwm_output::PwmOutputModuleError>,
+    },
+    PwmOutput_SetPolarity {
+        result: Result<(), modules::pwm_output::PwmOutputModuleError>,
+    },
+    AnalogInput_New {
+        result: Result<(), modules::analog_input::AnalogInputModuleError>,
+    },
+    AnalogInput_Drop {
+        result: Result<(), modules::analog_input::AnalogInputModuleError>,
+    },
+    AnalogInput_Read {
+        result: Result<u32, modules::analog_input::AnalogInputModuleError>,
+    },
+    Uart_New {
+        result: Result<(), modules::uart::UartModuleError>,
+    },
+    Uart_Drop {
+        result: Result<(), modules::uart::UartModuleError>,
+    },
+    Uart_Read {
+        result: Result<BusBuffer, modules::uart::UartModuleError>,
+    },
+    Uart_Write {
+        result: Result<(), modules::uart::UartModuleError>,
+    },
+    Uart_Flush {
+        result: Result<(), modules::uart::UartModuleError>,
+    },
+    OneWire_New {
+        result: Result<(), modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_Drop {
+        result: Result<(), modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_Reset {
+        result: Result<bool, modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_ReadBit {
+        result: Result<bool, modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_WriteBit {
+        result: Result<(), modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_ReadBytes {
+        result: Result<BusBuffer, modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_WriteBytes {
+        result: Result<(), modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    OneWire_Search {
+        result: Result<Option<modules::one_wire_bus::OneWireSearchState>, modules::one_wire_bus::OneWireBusModuleError>,
+    },
+    I2c_Transaction {
+        result: Result<BusBuffer, modules::i2c_bus::I2cBusModuleError>,
+    },
+    I2c_StartTransactionChunked {
+        result: Result<(), modules::i2c_bus::I2cBusModuleError>,
+    },
+    I2c_TransactionReadChunk {
+        result: Result<BusBuffer, modules::i2c_bus::I2cBusModuleError>,
+    },
+    I2c_TransactionWriteChunk {
+        result: Result<(), modules::i2c_bus::I2cBusModuleError>,
+    },
+    I2c_StopTransactionChunked {
+        result: Result<(), modules::i2c_bus::I2cBusModuleError>,
+    },
+    I2c_Scan {
+        result: Result<BusBuffer, modules::i2c_bus::I2cBusModuleError>,
+    },
+    I2cTarget_New {
+        result: Result<(), modules::i2c_target::I2cTargetModuleError>,
+    },
+    I2cTarget_Drop {
+        result: Result<(), modules::i2c_target::I2cTargetModuleError>,
+    },
+    I2cTarget_WriteRegisters {
+        result: Result<(), modules::i2c_target::I2cTargetModuleError>,
+    },
+    I2cTarget_ReadRegisters {
+        result: Result<BusBuffer, modules::i2c_target::I2cTargetModuleError>,
+    },
+    I2c_Recover {
+        result: Result<(), modules::i2c_bus::I2cBusModuleError>,
+    },
+    InputPin_CancelWaitForSignal {
+        result: Result<(), modules::input_pin::InputPinModuleError>,
+    },
+    Batch {
+        result: Result<(), modules::ModuleError>,
+    },
+    OutputPort_New {
+        result: Result<(), modules::output_port::OutputPortModuleError>,
+    },
+    OutputPort_Drop {
+        result: Result<(), modules::output_port::OutputPortModuleError>,
+    },
+    OutputPort_SetLevels {
+        result: Result<(), modules::output_port::OutputPortModuleError>,
+    },
+    InputPort_New {
+        result: Result<(), modules::input_port::InputPortModuleError>,
+    },
+    InputPort_Drop {
+        result: Result<(), modules::input_port::InputPortModuleError>,
+    },
+    InputPort_GetLevels {
+        result: Result<u32, modules::input_port::InputPortModuleError>,
+    },
+    InputPort_WaitForChange {
+        result: Result<u32, modules::input_port::InputPortModuleError>,
+    },
+    InputPort_CancelWaitForChange {
+        result: Result<(), modules::input_port::InputPortModuleError>,
+    },
+    InputPin_Subscribe {
+        result: Result<(), modules::input_pin::InputPinModuleError>,
+    },
+    InputPin_Unsubscribe {
+        result: Result<(), modules::input_pin::InputPinModuleError>,
+    },
+    Counter_New {
+        result: Result<(), modules::counter::CounterModuleError>,
+    },
+    Counter_Drop {
+        result: Result<(), modules::counter::CounterModuleError>,
+    },
+    Counter_Measure {
+        result: Result<modules::counter::CounterMeasurement, modules::counter::CounterModuleError>,
+    },
+    QuadratureEncoder_New {
+        result: Result<Duration, modules::quadrature_encoder::QuadratureEncoderModuleError>,
+    },
+    QuadratureEncoder_Drop {
+        result: Result<(), modules::quadrature_encoder::QuadratureEncoderModuleError>,
+    },
+    QuadratureEncoder_Read {
+        result: Result<
+            modules::quadrature_encoder::QuadratureEncoderReading,
+            modules::quadrature_encoder::QuadratureEncoderModuleError,
+        >,
+    },
+    QuadratureEncoder_Reset {
+        result: Result<Duration, modules::quadrature_encoder::QuadratureEncoderModuleError>,
+    },
+    LedStrip_New {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    LedStrip_Drop {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    LedStrip_WriteSingle {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    LedStrip_StartWriteChunked {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    LedStrip_WriteChunk {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    LedStrip_StopWriteChunked {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    LedStrip_SetBrightness {
+        result: Result<(), modules::led_strip::LedStripModuleError>,
+    },
+    Servo_New {
+        result: Result<(), modules::servo::ServoModuleError>,
+    },
+    Servo_Drop {
+        result: Result<(), modules::servo::ServoModuleError>,
+    },
+    Servo_SetPulseWidth {
+        result: Result<(), modules::servo::ServoModuleError>,
+    },
+    Servo_Sweep {
+        result: Result<(), modules::servo::ServoModuleError>,
+    },
+    Stepper_New {
+        result: Result<(), modules::stepper::StepperModuleError>,
+    },
+    Stepper_Drop {
+        result: Result<(), modules::stepper::StepperModuleError>,
+    },
+    Stepper_MoveTo {
+        result: Result<i64, modules::stepper::StepperModuleError>,
+    },
+    Stepper_MoveBy {
+        result: Result<i64, modules::stepper::StepperModuleError>,
+    },
+    Stepper_Stop {
+        result: Result<i64, modules::stepper::StepperModuleError>,
+    },
+    Stepper_EmergencyStop {
+        result: Result<i64, modules::stepper::StepperModuleError>,
+    },
+    Stepper_GetStatus {
+        result: Result<modules::stepper::StepperStatus, modules::stepper::StepperModuleError>,
+    },
+    Stepper_SetPosition {
+        result: Result<(), modules::stepper::StepperModuleError>,
+    },
+    Stepper_SetEnabled {
+        result: Result<(), modules::stepper::StepperModuleError>,
+    },
+    PulseIn_New {
+        result: Result<(), modules::pulse_in::PulseInModuleError>,
+    },
+    PulseIn_Drop {
+        result: Result<(), modules::pulse_in::PulseInModuleError>,
+    },
+    PulseIn_Measure {
+        result: Result<Duration, modules::pulse_in::PulseInModuleError>,
+    },
+    Uart_CancelRead {
+        result: Result<BusBuffer, modules::uart::UartModuleError>,
+    },
 }
@@ -0,0 +1,40 @@
+use crate::communication::FatalError;
+use crate::modules::i2c_bus::I2cBusModuleError;
+use crate::modules::output_pin::OutputPinModuleError;
+use crate::modules::pwm_output::PwmOutputModuleError;
+use thiserror_no_std::Error;
+
+/// Error that can occur while running a command batch. The index refers to the position of the command in the batch.
+#[non_exhaustive]
+#[derive(Error, Debug)]
+pub enum BatchError {
+    /// Module instance of the batch command belongs to another Iotzio board. No command of the batch was sent.
+    #[error("Module instance of batch command {index} belongs to another Iotzio board.")]
+    ForeignModule { index: u32 },
+
+    /// Commands of the batch do not fit a single report. No command of the batch was sent.
+    #[error("Commands of the batch do not fit a single report.")]
+    BatchTooLarge,
+
+    /// Output pin batch command failed.
+    #[error("Batch command {index} failed: {error}")]
+    OutputPinCommandFailed { index: u32, error: OutputPinModuleError },
+
+    /// PWM output batch command failed.
+    #[error("Batch command {index} failed: {error}")]
+    PwmOutputCommandFailed { index: u32, error: PwmOutputModuleError },
+
+    /// I2C bus batch command failed.
+    #[error("Batch command {index} failed: {error}")]
+    I2cBusCommandFailed { index: u32, error: I2cBusModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<FatalError> for BatchError {
+    fn from(value: FatalError) -> Self {
+        BatchError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::communication::Command;
+use crate::modules::i2c_bus::I2cBus;
+use crate::modules::output_pin::OutputPin;
+use crate::modules::pwm_output::PwmOutput;
+use crate::peripherals::gpio::Level;
+use crate::peripherals::i2c::I2cAddress;
+use crate::peripherals::BusBuffer;
+use crate::socket::Socket;
+use std::sync::Arc;
+
+/// A batch of commands sent to the Iotzio board at once. Instead of one report per command, the commands are packed into a single report.
+/// They are executed back to back by the Iotzio board, e.g. to update several output pins at the same time. A batch not fitting a single report fails without sending anything.
+#[derive(Debug, Default)]
+pub struct CommandBatch<'a> {
+    pub(crate) entries: Vec<BatchEntry<'a>>,
+}
+
+#[derive(Debug)]
+pub(crate) enum BatchEntry<'a> {
+    OutputPinSetLevel {
+        output_pin: &'a OutputPin,
+        level: Level,
+    },
+    PwmOutputSetDutyCycle {
+        pwm_output: &'a PwmOutput,
+        duty_cycle: u16,
+    },
+    I2cWrite {
+        i2c_bus: &'a I2cBus,
+        address: I2cAddress,
+        bytes: Vec<u8>,
+    },
+}
+
+impl<'a> CommandBatch<'a> {
+    /// Set the level of the given output pin.
+    #[inline]
+    pub fn set_level(&mut self, output_pin: &'a OutputPin, level: Level) -> &mut Self {
+        self.entries.push(BatchEntry::OutputPinSetLevel { output_pin, level });
+        self
+    }
+
+    /// Set the duty cycle of the given PWM output.
+    #[inline]
+    pub fn set_duty_cycle(&mut self, pwm_output: &'a PwmOutput, duty_cycle: u16) -> &mut Self {
+        self.entries
+            .push(BatchEntry::PwmOutputSetDutyCycle { pwm_output, duty_cycle });
+        self
+    }
+
+    /// Write to address from bytes on the given I2C bus. At most 512 bytes can be written by a single batch command.
+    #[inline]
+    pub fn i2c_write(&mut self, i2c_bus: &'a I2cBus, address: I2cAddress, bytes: &[u8]) -> &mut Self {
+        self.entries.push(BatchEntry::I2cWrite {
+            i2c_bus,
+            address,
+            bytes: bytes.to_vec(),
+        });
+        self
+    }
+
+    /// Returns the number of commands in the batch.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the batch contains no commands.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl BatchEntry<'_> {
+    #[inline]
+    pub(crate) fn socket(&self) -> &Arc<Socket> {
+        match self {
+            BatchEntry::OutputPinSetLevel { output_pin, .. } => &output_pin.socket,
+            BatchEntry::PwmOutputSetDutyCycle { pwm_output, .. } => &pwm_output.socket,
+            BatchEntry::I2cWrite { i2c_bus, .. } => &i2c_bus.socket,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn command(&self) -> Command {
+        match self {
+            BatchEntry::OutputPinSetLevel { output_pin, level } => Command::OutputPin_SetLevel {
+                pin: output_pin.pin,
+                level: *level,
+            },
+            BatchEntry::PwmOutputSetDutyCycle { pwm_output, duty_cycle } => Command::PwmOutput_SetDutyCycle {
+                pin: pwm_output.pin,
+                duty_cycle: *duty_cycle,
+            },
+            BatchEntry::I2cWrite {
+                i2c_bus,
+                address,
+                bytes,
+            } => Command::I2c_WriteSingle {
+                identifier: i2c_bus.bus_number,
+                address: *address,
+                bytes: BusBuffer::from_slice(bytes).expect("Write length is checked before the batch is sent."),
+            },
+        }
+    }
+}
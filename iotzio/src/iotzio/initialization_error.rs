@@ -18,6 +18,14 @@ pub enum InitializationError {
     #[error("Mismatching Iotzio protocol version: Library version is {driver}, but Iotzio board has version {board}.")]
     MismatchingProtocolVersion { driver: u16, board: u16 },
 
+    /// No connected Iotzio board matches the request.
+    #[error("No matching Iotzio board connected.")]
+    NoMatchingDevice,
+
+    /// Several connected Iotzio boards match the request, so it is ambiguous which one to open.
+    #[error("{count} matching Iotzio boards connected, expected only one.")]
+    MultipleMatchingDevices { count: u32 },
+
     /// Fatal error.
     #[error("{error}")]
     FatalErrorWrapper { error: FatalError },
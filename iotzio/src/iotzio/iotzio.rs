@@ -1,9 +1,18 @@
 use crate::communication::{BoardInfo, Version};
+use crate::iotzio::{iotzio_service, BatchError, CommandBatch};
 use crate::modules;
+use crate::modules::counter::{CounterEdge, CounterPin};
+use crate::modules::input_port::InputPortPin;
+use crate::modules::led_strip::LedStripType;
+use crate::modules::output_port::OutputPortPin;
+use crate::modules::pulse_in::PulseInConfig;
+use crate::modules::servo::ServoConfig;
+use crate::modules::stepper::StepperConfig;
 use crate::peripherals::gpio::{Drive, GpioPin, Level, Pull, SlewRate};
+use crate::peripherals::pwm::PwmPolarity;
 use crate::socket::Socket;
-use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// The representation of an opened Iotzio device.
 #[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
@@ -36,7 +45,52 @@ impl Iotzio {
     /// The runtime identifier of the Iotzio device. As long as the device remains connected, the runtime identifier stays consistent. However, it may potentially change after the physical USB connection is reestablished.
     #[inline]
     pub fn runtime_identifier(&self) -> u64 {
-        self.socket.runtime_identifier.deref().clone()
+        **self.socket.runtime_identifier.lock().unwrap()
+    }
+
+    /// The timeout for commands answered right away by the device. Defaults to 5 seconds, None waits forever.
+    /// Commands waiting for a signal, e.g. input pin waits, are not affected and offer their own timeout variants.
+    /// Pulse-in measurements wait for their own timeout plus this timeout.
+    #[inline]
+    pub fn command_timeout(&self) -> Option<Duration> {
+        *self.socket.command_timeout.lock().unwrap()
+    }
+
+    /// Set the timeout for commands answered right away by the device. None waits forever.
+    #[inline]
+    pub fn set_command_timeout(&self, timeout: Option<Duration>) {
+        *self.socket.command_timeout.lock().unwrap() = timeout;
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Iotzio {
+    /// Send several commands to the Iotzio board at once. The commands are added to the batch by the given function.
+    /// The commands are packed into a single report and executed back to back by the Iotzio board, e.g. to update several output pins at the same time.
+    /// A batch not fitting a single report fails with [`BatchError::BatchTooLarge`] without sending anything.
+    /// All commands are executed even if one of them fails. The error of the first failed command is returned.
+    #[inline]
+    pub fn batch<'a>(&self, f: impl FnOnce(&mut CommandBatch<'a>)) -> Result<(), BatchError> {
+        let mut batch = CommandBatch::default();
+
+        f(&mut batch);
+
+        async_std::task::block_on(iotzio_service::run_batch(&self.socket, batch))
+    }
+}
+
+impl Iotzio {
+    /// Send several commands to the Iotzio board at once. The commands are added to the batch by the given function.
+    /// The commands are packed into a single report and executed back to back by the Iotzio board, e.g. to update several output pins at the same time.
+    /// A batch not fitting a single report fails with [`BatchError::BatchTooLarge`] without sending anything.
+    /// All commands are executed even if one of them fails. The error of the first failed command is returned.
+    #[inline]
+    pub async fn batch_async<'a>(&self, f: impl FnOnce(&mut CommandBatch<'a>)) -> Result<(), BatchError> {
+        let mut batch = CommandBatch::default();
+
+        f(&mut batch);
+
+        iotzio_service::run_batch(&self.socket, batch).await
     }
 }
 
@@ -60,6 +114,16 @@ impl Iotzio {
         ))
     }
 
+    /// Set up a new input port from the given pins. All pins of the port are sampled in the same instant.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub fn setup_input_port(
+        &self,
+        pins: Vec<InputPortPin>,
+    ) -> Result<modules::input_port::InputPort, modules::input_port::InputPortModuleError> {
+        async_std::task::block_on(modules::input_port::InputPort::new(&self.socket, pins))
+    }
+
     /// Set up a new output pin with given initial level, drive strength and slew rate.
     /// During the existence of the returned module instance, the pin cannot be used for other modules.
     #[inline]
@@ -79,6 +143,127 @@ impl Iotzio {
         ))
     }
 
+    /// Set up a new output port from the given pins. All pins of the port change their levels in the same instant.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub fn setup_output_port(
+        &self,
+        pins: Vec<OutputPortPin>,
+    ) -> Result<modules::output_port::OutputPort, modules::output_port::OutputPortModuleError> {
+        async_std::task::block_on(modules::output_port::OutputPort::new(&self.socket, pins))
+    }
+
+    /// Set up a new PWM output with given frequency, duty cycle and polarity.
+    /// Two neighbouring pins (e.g. Pin 0 and Pin 1) share the same PWM slice. Both can be used at the same time if they share the same frequency.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub fn setup_pwm_output(
+        &self,
+        pin: GpioPin,
+        frequency_hz: u32,
+        duty_cycle: u16,
+        polarity: PwmPolarity,
+    ) -> Result<modules::pwm_output::PwmOutput, modules::pwm_output::PwmOutputModuleError> {
+        async_std::task::block_on(modules::pwm_output::PwmOutput::new(
+            &self.socket,
+            pin,
+            frequency_hz,
+            duty_cycle,
+            polarity,
+        ))
+    }
+
+    /// Set up a new servo with the given configuration. Use ServoConfig::default() for most hobby servos.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub fn setup_servo(
+        &self,
+        pin: GpioPin,
+        config: ServoConfig,
+    ) -> Result<modules::servo::Servo, modules::servo::ServoModuleError> {
+        async_std::task::block_on(modules::servo::Servo::new(&self.socket, pin, config))
+    }
+
+    /// Set up a new stepper motor driver with STEP, DIR and optional ENABLE pins. Step pulses are generated by the device.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub fn setup_stepper(
+        &self,
+        config: StepperConfig,
+    ) -> Result<modules::stepper::Stepper, modules::stepper::StepperModuleError> {
+        async_std::task::block_on(modules::stepper::Stepper::new(&self.socket, config))
+    }
+
+    /// Set up a new edge counter on the given pin. Edges are counted on the device, so that signals up to several MHz can be measured.
+    /// During the existence of the returned module instance, the PWM slice of the pin cannot be used for other modules.
+    #[inline]
+    pub fn setup_counter(
+        &self,
+        pin: CounterPin,
+        edge: CounterEdge,
+    ) -> Result<modules::counter::Counter, modules::counter::CounterModuleError> {
+        async_std::task::block_on(modules::counter::Counter::new(&self.socket, pin, edge))
+    }
+
+    /// Set up a new quadrature encoder. Pin B must be the pin directly following pin A. An optional index pin latches the position on each index pulse.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub fn setup_quadrature_encoder(
+        &self,
+        pin_a: GpioPin,
+        pin_b: GpioPin,
+        index_pin: Option<GpioPin>,
+        pull_setting: Pull,
+    ) -> Result<modules::quadrature_encoder::QuadratureEncoder, modules::quadrature_encoder::QuadratureEncoderModuleError>
+    {
+        async_std::task::block_on(modules::quadrature_encoder::QuadratureEncoder::new(
+            &self.socket,
+            pin_a,
+            pin_b,
+            index_pin,
+            pull_setting,
+        ))
+    }
+
+    /// Set up a new triggered pulse measurement, e.g. for HC-SR04 ultrasonic rangefinders. The device emits the trigger pulse and times the echo pulse.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub fn setup_pulse_in(
+        &self,
+        config: PulseInConfig,
+    ) -> Result<modules::pulse_in::PulseIn, modules::pulse_in::PulseInModuleError> {
+        async_std::task::block_on(modules::pulse_in::PulseIn::new(&self.socket, config))
+    }
+
+    /// Set up a new addressable LED strip (WS2812, SK6812) with the given number of LEDs and global brightness. The LED timing is generated by the device.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub fn setup_led_strip(
+        &self,
+        pin: GpioPin,
+        led_type: LedStripType,
+        led_count: u16,
+        brightness: u8,
+    ) -> Result<modules::led_strip::LedStrip, modules::led_strip::LedStripModuleError> {
+        async_std::task::block_on(modules::led_strip::LedStrip::new(
+            &self.socket,
+            pin,
+            led_type,
+            led_count,
+            brightness,
+        ))
+    }
+
+    /// Set up a new analog input on the given ADC channel.
+    /// During the existence of the returned module instance, the channel cannot be used for other modules.
+    #[inline]
+    pub fn setup_analog_input(
+        &self,
+        channel: modules::analog_input::AdcChannel,
+    ) -> Result<modules::analog_input::AnalogInput, modules::analog_input::AnalogInputModuleError> {
+        async_std::task::block_on(modules::analog_input::AnalogInput::new(&self.socket, channel))
+    }
+
     /// Set up a new I2C bus using the given I2C configuration.
     /// With this module instance you can communicate directly to bus participants.
     /// Use this method also if you want to create specialized I2C bus dependent modules.
@@ -89,6 +274,47 @@ impl Iotzio {
     ) -> Result<modules::i2c_bus::I2cBus, modules::i2c_bus::I2cBusModuleError> {
         async_std::task::block_on(modules::i2c_bus::I2cBus::new(&self.socket, config))
     }
+
+    /// Set up a new SPI bus using the given SPI configuration.
+    /// With this module instance you can communicate directly to bus participants.
+    /// Use this method also if you want to create specialized SPI bus dependent modules.
+    #[inline]
+    pub fn setup_spi_bus(
+        &self,
+        config: modules::spi_bus::SpiConfig,
+    ) -> Result<modules::spi_bus::SpiBus, modules::spi_bus::SpiBusModuleError> {
+        async_std::task::block_on(modules::spi_bus::SpiBus::new(&self.socket, config))
+    }
+
+    /// Set up a new UART using the given UART configuration.
+    /// Received bytes are buffered on the device until they are read.
+    #[inline]
+    pub fn setup_uart(
+        &self,
+        config: modules::uart::UartConfig,
+    ) -> Result<modules::uart::Uart, modules::uart::UartModuleError> {
+        async_std::task::block_on(modules::uart::Uart::new(&self.socket, config))
+    }
+
+    /// Set up a new 1-Wire bus on the given pin. An external pull-up resistor is required.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub fn setup_one_wire_bus(
+        &self,
+        pin: GpioPin,
+    ) -> Result<modules::one_wire_bus::OneWireBus, modules::one_wire_bus::OneWireBusModuleError> {
+        async_std::task::block_on(modules::one_wire_bus::OneWireBus::new(&self.socket, pin))
+    }
+
+    /// Set up a new I2C target using the given configuration.
+    /// Write, read and general call events are pushed by the device and buffered until they are received.
+    #[inline]
+    pub fn setup_i2c_target(
+        &self,
+        config: modules::i2c_target::I2cTargetConfig,
+    ) -> Result<modules::i2c_target::I2cTarget, modules::i2c_target::I2cTargetModuleError> {
+        async_std::task::block_on(modules::i2c_target::I2cTarget::new(&self.socket, config))
+    }
 }
 
 #[cfg_attr(feature = "_ffi-async", uniffi::export)]
@@ -105,6 +331,16 @@ impl Iotzio {
         modules::input_pin::InputPin::new(&self.socket, pin, pull_setting, hysteresis).await
     }
 
+    /// Set up a new input port from the given pins. All pins of the port are sampled in the same instant.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub async fn setup_input_port_async(
+        &self,
+        pins: Vec<InputPortPin>,
+    ) -> Result<modules::input_port::InputPort, modules::input_port::InputPortModuleError> {
+        modules::input_port::InputPort::new(&self.socket, pins).await
+    }
+
     /// Set up a new output pin with given initial level, drive strength and slew rate.
     /// During the existence of the returned module instance, the pin cannot be used for other modules.
     #[inline]
@@ -118,6 +354,109 @@ impl Iotzio {
         modules::output_pin::OutputPin::new(&self.socket, pin, initial_level, drive_strength, slew_rate).await
     }
 
+    /// Set up a new output port from the given pins. All pins of the port change their levels in the same instant.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub async fn setup_output_port_async(
+        &self,
+        pins: Vec<OutputPortPin>,
+    ) -> Result<modules::output_port::OutputPort, modules::output_port::OutputPortModuleError> {
+        modules::output_port::OutputPort::new(&self.socket, pins).await
+    }
+
+    /// Set up a new PWM output with given frequency, duty cycle and polarity.
+    /// Two neighbouring pins (e.g. Pin 0 and Pin 1) share the same PWM slice. Both can be used at the same time if they share the same frequency.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub async fn setup_pwm_output_async(
+        &self,
+        pin: GpioPin,
+        frequency_hz: u32,
+        duty_cycle: u16,
+        polarity: PwmPolarity,
+    ) -> Result<modules::pwm_output::PwmOutput, modules::pwm_output::PwmOutputModuleError> {
+        modules::pwm_output::PwmOutput::new(&self.socket, pin, frequency_hz, duty_cycle, polarity).await
+    }
+
+    /// Set up a new servo with the given configuration. Use ServoConfig::default() for most hobby servos.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub async fn setup_servo_async(
+        &self,
+        pin: GpioPin,
+        config: ServoConfig,
+    ) -> Result<modules::servo::Servo, modules::servo::ServoModuleError> {
+        modules::servo::Servo::new(&self.socket, pin, config).await
+    }
+
+    /// Set up a new stepper motor driver with STEP, DIR and optional ENABLE pins. Step pulses are generated by the device.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub async fn setup_stepper_async(
+        &self,
+        config: StepperConfig,
+    ) -> Result<modules::stepper::Stepper, modules::stepper::StepperModuleError> {
+        modules::stepper::Stepper::new(&self.socket, config).await
+    }
+
+    /// Set up a new edge counter on the given pin. Edges are counted on the device, so that signals up to several MHz can be measured.
+    /// During the existence of the returned module instance, the PWM slice of the pin cannot be used for other modules.
+    #[inline]
+    pub async fn setup_counter_async(
+        &self,
+        pin: CounterPin,
+        edge: CounterEdge,
+    ) -> Result<modules::counter::Counter, modules::counter::CounterModuleError> {
+        modules::counter::Counter::new(&self.socket, pin, edge).await
+    }
+
+    /// Set up a new quadrature encoder. Pin B must be the pin directly following pin A. An optional index pin latches the position on each index pulse.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub async fn setup_quadrature_encoder_async(
+        &self,
+        pin_a: GpioPin,
+        pin_b: GpioPin,
+        index_pin: Option<GpioPin>,
+        pull_setting: Pull,
+    ) -> Result<modules::quadrature_encoder::QuadratureEncoder, modules::quadrature_encoder::QuadratureEncoderModuleError>
+    {
+        modules::quadrature_encoder::QuadratureEncoder::new(&self.socket, pin_a, pin_b, index_pin, pull_setting).await
+    }
+
+    /// Set up a new triggered pulse measurement, e.g. for HC-SR04 ultrasonic rangefinders. The device emits the trigger pulse and times the echo pulse.
+    /// During the existence of the returned module instance, the pins cannot be used for other modules.
+    #[inline]
+    pub async fn setup_pulse_in_async(
+        &self,
+        config: PulseInConfig,
+    ) -> Result<modules::pulse_in::PulseIn, modules::pulse_in::PulseInModuleError> {
+        modules::pulse_in::PulseIn::new(&self.socket, config).await
+    }
+
+    /// Set up a new addressable LED strip (WS2812, SK6812) with the given number of LEDs and global brightness. The LED timing is generated by the device.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub async fn setup_led_strip_async(
+        &self,
+        pin: GpioPin,
+        led_type: LedStripType,
+        led_count: u16,
+        brightness: u8,
+    ) -> Result<modules::led_strip::LedStrip, modules::led_strip::LedStripModuleError> {
+        modules::led_strip::LedStrip::new(&self.socket, pin, led_type, led_count, brightness).await
+    }
+
+    /// Set up a new analog input on the given ADC channel.
+    /// During the existence of the returned module instance, the channel cannot be used for other modules.
+    #[inline]
+    pub async fn setup_analog_input_async(
+        &self,
+        channel: modules::analog_input::AdcChannel,
+    ) -> Result<modules::analog_input::AnalogInput, modules::analog_input::AnalogInputModuleError> {
+        modules::analog_input::AnalogInput::new(&self.socket, channel).await
+    }
+
     /// Set up a new I2C bus using the given I2C configuration.
     /// With this module instance you can communicate directly to bus participants.
     /// Use this method also if you want to create specialized I2C bus dependent modules.
@@ -128,4 +467,45 @@ impl Iotzio {
     ) -> Result<modules::i2c_bus::I2cBus, modules::i2c_bus::I2cBusModuleError> {
         modules::i2c_bus::I2cBus::new(&self.socket, config).await
     }
+
+    /// Set up a new SPI bus using the given SPI configuration.
+    /// With this module instance you can communicate directly to bus participants.
+    /// Use this method also if you want to create specialized SPI bus dependent modules.
+    #[inline]
+    pub async fn setup_spi_bus_async(
+        &self,
+        config: modules::spi_bus::SpiConfig,
+    ) -> Result<modules::spi_bus::SpiBus, modules::spi_bus::SpiBusModuleError> {
+        modules::spi_bus::SpiBus::new(&self.socket, config).await
+    }
+
+    /// Set up a new UART using the given UART configuration.
+    /// Received bytes are buffered on the device until they are read.
+    #[inline]
+    pub async fn setup_uart_async(
+        &self,
+        config: modules::uart::UartConfig,
+    ) -> Result<modules::uart::Uart, modules::uart::UartModuleError> {
+        modules::uart::Uart::new(&self.socket, config).await
+    }
+
+    /// Set up a new 1-Wire bus on the given pin. An external pull-up resistor is required.
+    /// During the existence of the returned module instance, the pin cannot be used for other modules.
+    #[inline]
+    pub async fn setup_one_wire_bus_async(
+        &self,
+        pin: GpioPin,
+    ) -> Result<modules::one_wire_bus::OneWireBus, modules::one_wire_bus::OneWireBusModuleError> {
+        modules::one_wire_bus::OneWireBus::new(&self.socket, pin).await
+    }
+
+    /// Set up a new I2C target using the given configuration.
+    /// Write, read and general call events are pushed by the device and buffered until they are received.
+    #[inline]
+    pub async fn setup_i2c_target_async(
+        &self,
+        config: modules::i2c_target::I2cTargetConfig,
+    ) -> Result<modules::i2c_target::I2cTarget, modules::i2c_target::I2cTargetModuleError> {
+        modules::i2c_target::I2cTarget::new(&self.socket, config).await
+    }
 }
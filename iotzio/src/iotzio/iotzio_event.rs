@@ -0,0 +1,10 @@
+use crate::iotzio::iotzio_info::IotzioInfo;
+
+/// Hotplug event emitted by the Iotzio watcher.
+#[derive(Debug)]
+pub enum IotzioEvent {
+    /// An Iotzio board was connected to the host.
+    Connected(IotzioInfo),
+    /// The Iotzio board with the given runtime identifier was disconnected from the host.
+    Disconnected(u64),
+}
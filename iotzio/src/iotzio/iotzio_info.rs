@@ -40,7 +40,18 @@ impl IotzioInfo {
     /// Opens the Iotzio device.
     #[inline]
     pub fn open(&self) -> Result<Iotzio, InitializationError> {
-        async_std::task::block_on(iotzio_service::new_iotzio(self))
+        async_std::task::block_on(iotzio_service::new_iotzio(self, false))
+    }
+
+    /// Opens the Iotzio device in resilient mode. After the USB connection is lost, the command running into the fatal error still fails.
+    /// Subsequent commands reconnect as soon as a board with the same serial number is connected again.
+    /// These modules are set up again, so that their instances keep working: input pins, output pins and output ports with their last levels, input ports, I2C buses,
+    /// counters, quadrature encoders, LED strips with their last brightness, servos with their last pulse width, steppers and pulse-in modules.
+    /// Counters and quadrature encoders restart at zero and LED strip colors must be written again. The position of a stepper is unknown until it is set again, e.g. after homing.
+    /// SPI buses, PWM outputs, UARTs, OneWire buses, analog inputs, I2C targets and input pin subscriptions are not set up again and must be created again.
+    #[inline]
+    pub fn open_resilient(&self) -> Result<Iotzio, InitializationError> {
+        async_std::task::block_on(iotzio_service::new_iotzio(self, true))
     }
 }
 
@@ -49,6 +60,17 @@ impl IotzioInfo {
     /// Opens the Iotzio device.
     #[inline]
     pub async fn open_async(&self) -> Result<Iotzio, InitializationError> {
-        iotzio_service::new_iotzio(self).await
+        iotzio_service::new_iotzio(self, false).await
+    }
+
+    /// Opens the Iotzio device in resilient mode. After the USB connection is lost, the command running into the fatal error still fails.
+    /// Subsequent commands reconnect as soon as a board with the same serial number is connected again.
+    /// These modules are set up again, so that their instances keep working: input pins, output pins and output ports with their last levels, input ports, I2C buses,
+    /// counters, quadrature encoders, LED strips with their last brightness, servos with their last pulse width, steppers and pulse-in modules.
+    /// Counters and quadrature encoders restart at zero and LED strip colors must be written again. The position of a stepper is unknown until it is set again, e.g. after homing.
+    /// SPI buses, PWM outputs, UARTs, OneWire buses, analog inputs, I2C targets and input pin subscriptions are not set up again and must be created again.
+    #[inline]
+    pub async fn open_resilient_async(&self) -> Result<Iotzio, InitializationError> {
+        iotzio_service::new_iotzio(self, true).await
     }
 }
@@ -1,6 +1,7 @@
 use crate::iotzio::iotzio_info::IotzioInfo;
 use crate::iotzio::iotzio_service;
-use crate::InitializationError;
+use crate::iotzio::IotzioWatcher;
+use crate::{InitializationError, Iotzio};
 use std::marker::PhantomData;
 
 /// The iotzio manager is the entry point into the iotzio API. It lists connected Iotzio boards.
@@ -22,6 +23,48 @@ impl IotzioManager {
     }
 }
 
+impl IotzioManager {
+    /// Watches for Iotzio boards being connected or disconnected. Returns a stream of hotplug events.
+    /// The watch ends when the returned stream is dropped.
+    #[inline]
+    pub fn watch(&self) -> Result<IotzioWatcher, InitializationError> {
+        iotzio_service::watch_connected_boards()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl IotzioManager {
+    /// Opens the connected Iotzio board with the given serial number.
+    /// Boards without a known serial number before opening (Android, Webassembly) are opened to compare their serial number.
+    #[inline]
+    pub fn open_by_serial(&self, serial_number: &str) -> Result<Iotzio, InitializationError> {
+        async_std::task::block_on(iotzio_service::open_by_serial(serial_number))
+    }
+
+    /// Opens the first connected Iotzio board that is not already opened.
+    #[inline]
+    pub fn open_first(&self) -> Result<Iotzio, InitializationError> {
+        async_std::task::block_on(iotzio_service::open_first())
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl IotzioManager {
+    /// Opens the connected Iotzio board with the given serial number.
+    /// Boards without a known serial number before opening (Android, Webassembly) are opened to compare their serial number.
+    #[inline]
+    pub async fn open_by_serial_async(&self, serial_number: &str) -> Result<Iotzio, InitializationError> {
+        iotzio_service::open_by_serial(serial_number).await
+    }
+
+    /// Opens the first connected Iotzio board that is not already opened.
+    #[inline]
+    pub async fn open_first_async(&self) -> Result<Iotzio, InitializationError> {
+        iotzio_service::open_first().await
+    }
+}
+
 #[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
 impl IotzioManager {
     /// Lists all currently connected Iotzio boards.
@@ -1,8 +1,15 @@
 use crate::communication::{BoardInfo, Command, FatalError, ProtocolError, Response, Version};
 use crate::iotzio::iotzio_info::IotzioInfo;
-use crate::iotzio::USB_PRODUCT_NAME_PREFIX;
-use crate::socket::Socket;
+use crate::iotzio::{BatchEntry, BatchError, CommandBatch};
+use crate::iotzio::{IotzioEvent, IotzioWatcher, USB_PRODUCT_NAME_PREFIX};
+use crate::modules::i2c_bus::{I2cBus, I2cBusModuleError};
+use crate::modules::output_pin::OutputPinModuleError;
+use crate::modules::pwm_output::PwmOutputModuleError;
+use crate::peripherals::i2c::I2cError;
+use crate::peripherals::BUS_BUFFER_SIZE;
+use crate::socket::{ReconnectState, Socket};
 use crate::{backend, InitializationError, Iotzio};
+use std::sync::Arc;
 
 pub async fn list_connected_boards() -> Result<Vec<IotzioInfo>, InitializationError> {
     backend::list_connected_boards(parse_version)
@@ -12,6 +19,36 @@ pub async fn list_connected_boards() -> Result<Vec<IotzioInfo>, InitializationEr
         })
 }
 
+pub fn watch_connected_boards() -> Result<IotzioWatcher, InitializationError> {
+    backend::watch_connected_boards(parse_version)
+        .map(|device_watcher| IotzioWatcher { device_watcher })
+        .map_err(|x| InitializationError::DeviceOpenError {
+            error_message: format!("Error watching connected Iotzio boards: {0}", x),
+        })
+}
+
+/// Compares the given listing of connected boards with the runtime identifiers of the previous listing.
+/// Updates the known runtime identifiers and returns the resulting hotplug events.
+#[allow(unused)]
+pub fn diff_connected_boards(known: &mut Vec<u64>, boards: Vec<IotzioInfo>) -> Vec<IotzioEvent> {
+    let mut events: Vec<IotzioEvent> = known
+        .iter()
+        .filter(|x| !boards.iter().any(|y| y.runtime_identifier == **x))
+        .map(|x| IotzioEvent::Disconnected(*x))
+        .collect();
+
+    let previous = std::mem::replace(known, boards.iter().map(|x| x.runtime_identifier).collect());
+
+    events.extend(
+        boards
+            .into_iter()
+            .filter(|x| !previous.contains(&x.runtime_identifier))
+            .map(IotzioEvent::Connected),
+    );
+
+    events
+}
+
 fn parse_version(product_name: &str) -> Option<Version> {
     if product_name.starts_with(USB_PRODUCT_NAME_PREFIX) {
         let version_part = &product_name[USB_PRODUCT_NAME_PREFIX.len()..];
@@ -31,17 +68,170 @@ fn parse_version(product_name: &str) -> Option<Version> {
     None
 }
 
-pub async fn new_iotzio(iotzio_info: &IotzioInfo) -> Result<Iotzio, InitializationError> {
-    let socket = Socket::new(&iotzio_info).await?;
+pub async fn new_iotzio(iotzio_info: &IotzioInfo, resilient: bool) -> Result<Iotzio, InitializationError> {
+    let mut socket = Socket::new(&iotzio_info).await?;
 
     let board_info = initialize_board(&socket).await?;
 
+    if resilient {
+        socket.reconnect_state = Some(ReconnectState::new(board_info.serial_number.clone()));
+    }
+
     Ok(Iotzio {
         socket: socket.into(),
         board_info,
     })
 }
 
+pub async fn open_by_serial(serial_number: &str) -> Result<Iotzio, InitializationError> {
+    let iotzio_infos = list_connected_boards().await?;
+
+    let known: Vec<&IotzioInfo> = iotzio_infos
+        .iter()
+        .filter(|x| x.serial_number.as_deref() == Some(serial_number))
+        .collect();
+
+    match known.len() {
+        0 => {}
+        1 => return new_iotzio(known[0], false).await,
+        count => return Err(InitializationError::MultipleMatchingDevices { count: count as u32 }),
+    }
+
+    // Serial numbers may not be available before opening (Android, Webassembly). Those boards are opened and compared.
+
+    let mut matches = Vec::new();
+    let mut last_error = None;
+
+    for iotzio_info in iotzio_infos.iter().filter(|x| x.serial_number.is_none()) {
+        match new_iotzio(iotzio_info, false).await {
+            Ok(x) if x.board_info.serial_number == serial_number => matches.push(x),
+            Ok(_) => {}
+            Err(x) => last_error = Some(x),
+        }
+    }
+
+    match matches.len() {
+        0 => Err(last_error.unwrap_or(InitializationError::NoMatchingDevice)),
+        1 => Ok(matches.remove(0)),
+        count => Err(InitializationError::MultipleMatchingDevices { count: count as u32 }),
+    }
+}
+
+pub async fn open_first() -> Result<Iotzio, InitializationError> {
+    let iotzio_infos = list_connected_boards().await?;
+
+    let mut last_error = None;
+
+    // Boards already opened elsewhere are skipped.
+
+    for iotzio_info in iotzio_infos.iter() {
+        match new_iotzio(iotzio_info, false).await {
+            Ok(x) => return Ok(x),
+            Err(x) => last_error = Some(x),
+        }
+    }
+
+    Err(last_error.unwrap_or(InitializationError::NoMatchingDevice))
+}
+
+pub async fn run_batch(socket: &Arc<Socket>, batch: CommandBatch<'_>) -> Result<(), BatchError> {
+    for (index, entry) in batch.entries.iter().enumerate() {
+        if !Arc::ptr_eq(entry.socket(), socket) {
+            return Err(BatchError::ForeignModule { index: index as u32 });
+        }
+
+        if let BatchEntry::I2cWrite { bytes, .. } = entry {
+            if bytes.len() > BUS_BUFFER_SIZE {
+                return Err(BatchError::I2cBusCommandFailed {
+                    index: index as u32,
+                    error: I2cBusModuleError::from(I2cError::InvalidWriteBufferLength),
+                });
+            }
+        }
+    }
+
+    // Running I2C bus operations must not be interleaved with the batch. Buses are locked in a fixed order.
+
+    let mut i2c_buses: Vec<&I2cBus> = batch
+        .entries
+        .iter()
+        .filter_map(|x| match x {
+            BatchEntry::I2cWrite { i2c_bus, .. } => Some(*i2c_bus),
+            _ => None,
+        })
+        .collect();
+
+    i2c_buses.sort_by_key(|x| x.bus_number as u8);
+    i2c_buses.dedup_by(|x, y| std::ptr::eq(*x, *y));
+
+    let mut i2c_bus_guards = Vec::with_capacity(i2c_buses.len());
+
+    for i2c_bus in i2c_buses {
+        i2c_bus_guards.push(i2c_bus.mutex.lock().await);
+    }
+
+    let commands = batch.entries.iter().map(|x| x.command()).collect();
+
+    let results = socket.send_batch(commands).await?.ok_or(BatchError::BatchTooLarge)?;
+
+    drop(i2c_bus_guards);
+
+    // All commands were executed by the board, the first error is returned.
+
+    let mut first_error = None;
+
+    for (index, (entry, result)) in batch.entries.iter().zip(results).enumerate() {
+        let index = index as u32;
+
+        let error = match (entry, result) {
+            (BatchEntry::OutputPinSetLevel { output_pin, level }, Ok(Response::OutputPin_SetLevel { result })) => {
+                match result {
+                    Ok(_) => {
+                        *output_pin.level.lock().unwrap() = *level;
+                        None
+                    }
+                    Err(error) => Some(BatchError::OutputPinCommandFailed { index, error }),
+                }
+            }
+            (BatchEntry::OutputPinSetLevel { .. }, Err(x)) => Some(BatchError::OutputPinCommandFailed {
+                index,
+                error: OutputPinModuleError::from(x),
+            }),
+            (
+                BatchEntry::PwmOutputSetDutyCycle { pwm_output, duty_cycle },
+                Ok(Response::PwmOutput_SetDutyCycle { result }),
+            ) => match result {
+                Ok(_) => {
+                    *pwm_output.duty_cycle.lock().unwrap() = *duty_cycle;
+                    None
+                }
+                Err(error) => Some(BatchError::PwmOutputCommandFailed { index, error }),
+            },
+            (BatchEntry::PwmOutputSetDutyCycle { .. }, Err(x)) => Some(BatchError::PwmOutputCommandFailed {
+                index,
+                error: PwmOutputModuleError::from(x),
+            }),
+            (BatchEntry::I2cWrite { .. }, Ok(Response::I2c_WriteSingle { result })) => result
+                .err()
+                .map(|error| BatchError::I2cBusCommandFailed { index, error }),
+            (BatchEntry::I2cWrite { .. }, Err(x)) => Some(BatchError::I2cBusCommandFailed {
+                index,
+                error: I2cBusModuleError::from(x),
+            }),
+            _ => Some(BatchError::from(FatalError::from(ProtocolError::ReceivedWrongResponse))),
+        };
+
+        if first_error.is_none() {
+            first_error = error;
+        }
+    }
+
+    match first_error {
+        None => Ok(()),
+        Some(x) => Err(x),
+    }
+}
+
 #[inline]
 async fn initialize_board(socket: &Socket) -> Result<BoardInfo, FatalError> {
     let command = Command::Initialize;
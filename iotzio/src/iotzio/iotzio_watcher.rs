@@ -0,0 +1,19 @@
+use crate::backend::DeviceWatcher;
+use crate::iotzio::IotzioEvent;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Stream of hotplug events for Iotzio boards. Boards already connected when watching starts are reported as connected first.
+/// The stream ends if the platform stops delivering events. Dropping the stream stops watching.
+#[derive(Debug)]
+pub struct IotzioWatcher {
+    pub(crate) device_watcher: DeviceWatcher,
+}
+
+impl async_std::stream::Stream for IotzioWatcher {
+    type Item = IotzioEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.get_mut().device_watcher.receiver).poll_next(cx)
+    }
+}
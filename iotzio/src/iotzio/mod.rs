@@ -33,6 +33,18 @@ cfg_if::cfg_if! {
 
         mod iotzio_info;
         pub use self::iotzio_info::*;
+
+        mod iotzio_event;
+        pub use self::iotzio_event::*;
+
+        mod iotzio_watcher;
+        pub use self::iotzio_watcher::*;
+
+        mod command_batch;
+        pub use self::command_batch::*;
+
+        mod batch_error;
+        pub use self::batch_error::*;
     }
 }
 
@@ -12,5 +12,8 @@ pub mod modules;
 pub mod peripherals;
 pub mod socket;
 
+#[cfg(all(feature = "simulator", not(target_family = "wasm")))]
+pub mod simulator;
+
 #[allow(unused_imports)]
 pub use self::iotzio::*;
@@ -0,0 +1,31 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Channel of the analog to digital converter.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AdcChannel {
+    /// Pin 26
+    Pin26,
+    /// Pin 27
+    Pin27,
+    /// Pin 28
+    Pin28,
+    /// Internal temperature sensor of the Iotzio device.
+    TemperatureSensor,
+}
+
+impl TryFrom<GpioPin> for AdcChannel {
+    type Error = ();
+
+    fn try_from(value: GpioPin) -> Result<Self, Self::Error> {
+        match value {
+            GpioPin::Pin26 => Ok(AdcChannel::Pin26),
+            GpioPin::Pin27 => Ok(AdcChannel::Pin27),
+            GpioPin::Pin28 => Ok(AdcChannel::Pin28),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::modules::analog_input::{service, AdcChannel, AnalogInputModuleError};
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::Arc;
+
+/// Represents an analog input on the Iotzio device.
+/// Raw readings are 12 bit values in the range 0 to 4095, which corresponds to 0 V to 3.3 V.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct AnalogInput {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) channel: AdcChannel,
+}
+
+impl AnalogInput {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, channel: AdcChannel) -> Result<AnalogInput, AnalogInputModuleError> {
+        service::new(socket, channel).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl AnalogInput {
+    /// Returns used ADC channel.
+    #[inline]
+    pub fn get_channel(&self) -> AdcChannel {
+        self.channel
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl AnalogInput {
+    /// Returns a single raw sample.
+    #[inline]
+    pub async fn read_async(&self) -> Result<u16, AnalogInputModuleError> {
+        service::read(&self.socket, self.channel).await
+    }
+
+    /// Returns the mean of the given number of raw samples, taken back to back on the device.
+    #[inline]
+    pub async fn read_averaged_async(&self, samples: u16) -> Result<f32, AnalogInputModuleError> {
+        service::read_averaged(&self.socket, self.channel, samples).await
+    }
+
+    /// Returns a single sample converted to volts.
+    #[inline]
+    pub async fn read_volts_async(&self) -> Result<f32, AnalogInputModuleError> {
+        service::read_volts(&self.socket, self.channel).await
+    }
+
+    /// Returns the mean of the given number of samples converted to volts.
+    #[inline]
+    pub async fn read_averaged_volts_async(&self, samples: u16) -> Result<f32, AnalogInputModuleError> {
+        service::read_averaged_volts(&self.socket, self.channel, samples).await
+    }
+
+    /// Returns the die temperature in degrees Celsius, averaged over the given number of samples. Only supported by the temperature sensor channel.
+    #[inline]
+    pub async fn read_temperature_celsius_async(&self, samples: u16) -> Result<f32, AnalogInputModuleError> {
+        service::read_temperature_celsius(&self.socket, self.channel, samples).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl AnalogInput {
+    /// Returns a single raw sample.
+    #[inline]
+    pub fn read(&self) -> Result<u16, AnalogInputModuleError> {
+        block_on(service::read(&self.socket, self.channel))
+    }
+
+    /// Returns the mean of the given number of raw samples, taken back to back on the device.
+    #[inline]
+    pub fn read_averaged(&self, samples: u16) -> Result<f32, AnalogInputModuleError> {
+        block_on(service::read_averaged(&self.socket, self.channel, samples))
+    }
+
+    /// Returns a single sample converted to volts.
+    #[inline]
+    pub fn read_volts(&self) -> Result<f32, AnalogInputModuleError> {
+        block_on(service::read_volts(&self.socket, self.channel))
+    }
+
+    /// Returns the mean of the given number of samples converted to volts.
+    #[inline]
+    pub fn read_averaged_volts(&self, samples: u16) -> Result<f32, AnalogInputModuleError> {
+        block_on(service::read_averaged_volts(&self.socket, self.channel, samples))
+    }
+
+    /// Returns the die temperature in degrees Celsius, averaged over the given number of samples. Only supported by the temperature sensor channel.
+    #[inline]
+    pub fn read_temperature_celsius(&self, samples: u16) -> Result<f32, AnalogInputModuleError> {
+        block_on(service::read_temperature_celsius(&self.socket, self.channel, samples))
+    }
+}
+
+impl Drop for AnalogInput {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let channel = self.channel;
+
+        block_on(async move { _ = service::drop(&socket, channel).await })
+    }
+}
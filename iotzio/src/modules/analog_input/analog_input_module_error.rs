@@ -0,0 +1,39 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Analog input module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnalogInputModuleError {
+    /// At least one sample must be requested.
+    #[error("At least one sample must be requested.")]
+    InvalidSampleCount,
+
+    /// Requested operation is not supported by this ADC channel.
+    #[error("Requested operation is not supported by this ADC channel.")]
+    UnsupportedChannel,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for AnalogInputModuleError {
+    fn from(value: ModuleError) -> Self {
+        AnalogInputModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for AnalogInputModuleError {
+    fn from(value: FatalError) -> Self {
+        AnalogInputModuleError::FatalErrorWrapper { error: value }
+    }
+}
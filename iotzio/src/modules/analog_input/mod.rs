@@ -0,0 +1,14 @@
+mod adc_channel;
+mod analog_input_module_error;
+
+pub use self::adc_channel::*;
+pub use self::analog_input_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod analog_input;
+
+        pub use self::analog_input::*;
+    }
+}
@@ -0,0 +1,103 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::analog_input::{AdcChannel, AnalogInput, AnalogInputModuleError};
+use crate::socket::Socket;
+use std::sync::Arc;
+
+const ADC_MAX_VALUE: f32 = 4095.0;
+
+const ADC_REFERENCE_VOLTAGE: f32 = 3.3;
+
+// Temperature sensor characteristics, see RP2040 datasheet chapter 4.9.5.
+const TEMPERATURE_SENSOR_VOLTAGE_AT_27_DEGREES: f32 = 0.706;
+
+const TEMPERATURE_SENSOR_SLOPE: f32 = -0.001721;
+
+pub async fn new(socket: &Arc<Socket>, channel: AdcChannel) -> Result<AnalogInput, AnalogInputModuleError> {
+    let command = Command::AnalogInput_New { channel };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::AnalogInput_New { result } => result,
+        _ => Err(AnalogInputModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(AnalogInput {
+        socket: socket.clone(),
+        channel,
+    })
+}
+
+pub async fn drop(socket: &Socket, channel: AdcChannel) -> Result<(), AnalogInputModuleError> {
+    let command = Command::AnalogInput_Drop { channel };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::AnalogInput_Drop { result } => result,
+        _ => Err(AnalogInputModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn read(socket: &Socket, channel: AdcChannel) -> Result<u16, AnalogInputModuleError> {
+    read_sum(socket, channel, 1).await.map(|x| x as u16)
+}
+
+pub async fn read_averaged(socket: &Socket, channel: AdcChannel, samples: u16) -> Result<f32, AnalogInputModuleError> {
+    read_sum(socket, channel, samples)
+        .await
+        .map(|x| x as f32 / samples as f32)
+}
+
+pub async fn read_volts(socket: &Socket, channel: AdcChannel) -> Result<f32, AnalogInputModuleError> {
+    read(socket, channel).await.map(|x| to_volts(x as f32))
+}
+
+pub async fn read_averaged_volts(
+    socket: &Socket,
+    channel: AdcChannel,
+    samples: u16,
+) -> Result<f32, AnalogInputModuleError> {
+    read_averaged(socket, channel, samples).await.map(to_volts)
+}
+
+pub async fn read_temperature_celsius(
+    socket: &Socket,
+    channel: AdcChannel,
+    samples: u16,
+) -> Result<f32, AnalogInputModuleError> {
+    if channel != AdcChannel::TemperatureSensor {
+        return Err(AnalogInputModuleError::UnsupportedChannel);
+    }
+
+    let volts = read_averaged_volts(socket, channel, samples).await?;
+
+    Ok(27.0 + (volts - TEMPERATURE_SENSOR_VOLTAGE_AT_27_DEGREES) / TEMPERATURE_SENSOR_SLOPE)
+}
+
+#[inline]
+async fn read_sum(socket: &Socket, channel: AdcChannel, samples: u16) -> Result<u32, AnalogInputModuleError> {
+    if samples == 0 {
+        return Err(AnalogInputModuleError::InvalidSampleCount);
+    }
+
+    let command = Command::AnalogInput_Read { channel, samples };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::AnalogInput_Read { result } => result,
+        _ => Err(AnalogInputModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+fn to_volts(counts: f32) -> f32 {
+    counts * ADC_REFERENCE_VOLTAGE / ADC_MAX_VALUE
+}
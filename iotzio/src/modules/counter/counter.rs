@@ -0,0 +1,84 @@
+use crate::modules::counter::{service, CounterEdge, CounterModuleError, CounterPin};
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Represents an edge counter on the Iotzio device. Edges are counted on the device, using the PWM slice of the pin
+/// in counter mode. Therefore the other pin of the slice cannot be used for PWM output meanwhile.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct Counter {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) pin: CounterPin,
+    pub(crate) edge: CounterEdge,
+}
+
+impl Counter {
+    #[inline]
+    pub(crate) async fn new(
+        socket: &Arc<Socket>,
+        pin: CounterPin,
+        edge: CounterEdge,
+    ) -> Result<Counter, CounterModuleError> {
+        service::new(socket, pin, edge).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl Counter {
+    /// Returns used pin.
+    #[inline]
+    pub fn get_pin(&self) -> CounterPin {
+        self.pin
+    }
+
+    /// Returns the counted edge.
+    #[inline]
+    pub fn get_edge(&self) -> CounterEdge {
+        self.edge
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl Counter {
+    /// Count the edges occurring within the given gate time.
+    #[inline]
+    pub async fn count_edges_async(&self, gate_time: Duration) -> Result<u64, CounterModuleError> {
+        service::count_edges(&self.socket, self.pin, gate_time).await
+    }
+
+    /// Measure the frequency of the signal in Hertz by counting edges within the given gate time.
+    /// Longer gate times give a finer resolution.
+    #[inline]
+    pub async fn measure_frequency_hz_async(&self, gate_time: Duration) -> Result<f64, CounterModuleError> {
+        service::measure_frequency_hz(&self.socket, self.pin, gate_time).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl Counter {
+    /// Count the edges occurring within the given gate time.
+    #[inline]
+    pub fn count_edges(&self, gate_time: Duration) -> Result<u64, CounterModuleError> {
+        block_on(service::count_edges(&self.socket, self.pin, gate_time))
+    }
+
+    /// Measure the frequency of the signal in Hertz by counting edges within the given gate time.
+    /// Longer gate times give a finer resolution.
+    #[inline]
+    pub fn measure_frequency_hz(&self, gate_time: Duration) -> Result<f64, CounterModuleError> {
+        block_on(service::measure_frequency_hz(&self.socket, self.pin, gate_time))
+    }
+}
+
+impl Drop for Counter {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin = self.pin;
+
+        block_on(async move { _ = service::drop(&socket, pin).await })
+    }
+}
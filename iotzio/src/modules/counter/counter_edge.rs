@@ -0,0 +1,13 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Edge counted by a counter.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CounterEdge {
+    /// Count low to high transitions.
+    Rising,
+    /// Count high to low transitions.
+    Falling,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::time::Duration;
+    }
+    else {
+        use core::time::Duration;
+    }
+}
+
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CounterMeasurement {
+    /// Edges counted while the gate was open.
+    pub count: u64,
+    /// Time the gate was actually open, as measured by the device.
+    pub gate_time: Duration,
+}
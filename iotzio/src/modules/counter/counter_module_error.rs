@@ -0,0 +1,35 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Counter module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CounterModuleError {
+    /// Gate time must not be zero.
+    #[error("Gate time must not be zero.")]
+    GateTimeTooShort,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for CounterModuleError {
+    fn from(value: ModuleError) -> Self {
+        CounterModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for CounterModuleError {
+    fn from(value: FatalError) -> Self {
+        CounterModuleError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for counting edges. These are the B channel pins of the PWM slices.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CounterPin {
+    /// Pin 1
+    Pin1,
+    /// Pin 3
+    Pin3,
+    /// Pin 5
+    Pin5,
+    /// Pin 7
+    Pin7,
+    /// Pin 9
+    Pin9,
+    /// Pin 11
+    Pin11,
+    /// Pin 13
+    Pin13,
+    /// Pin 15
+    Pin15,
+    /// Pin 17
+    Pin17,
+    /// Pin 19
+    Pin19,
+    /// Pin 21
+    Pin21,
+    /// Pin 25
+    Pin25,
+    /// Pin 27
+    Pin27,
+}
+
+impl TryFrom<u8> for CounterPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(CounterPin::Pin1),
+            3 => Ok(CounterPin::Pin3),
+            5 => Ok(CounterPin::Pin5),
+            7 => Ok(CounterPin::Pin7),
+            9 => Ok(CounterPin::Pin9),
+            11 => Ok(CounterPin::Pin11),
+            13 => Ok(CounterPin::Pin13),
+            15 => Ok(CounterPin::Pin15),
+            17 => Ok(CounterPin::Pin17),
+            19 => Ok(CounterPin::Pin19),
+            21 => Ok(CounterPin::Pin21),
+            25 => Ok(CounterPin::Pin25),
+            27 => Ok(CounterPin::Pin27),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<CounterPin> for u8 {
+    fn from(pin: CounterPin) -> u8 {
+        match pin {
+            CounterPin::Pin1 => 1,
+            CounterPin::Pin3 => 3,
+            CounterPin::Pin5 => 5,
+            CounterPin::Pin7 => 7,
+            CounterPin::Pin9 => 9,
+            CounterPin::Pin11 => 11,
+            CounterPin::Pin13 => 13,
+            CounterPin::Pin15 => 15,
+            CounterPin::Pin17 => 17,
+            CounterPin::Pin19 => 19,
+            CounterPin::Pin21 => 21,
+            CounterPin::Pin25 => 25,
+            CounterPin::Pin27 => 27,
+        }
+    }
+}
+
+impl From<CounterPin> for GpioPin {
+    fn from(pin: CounterPin) -> GpioPin {
+        match pin {
+            CounterPin::Pin1 => GpioPin::Pin1,
+            CounterPin::Pin3 => GpioPin::Pin3,
+            CounterPin::Pin5 => GpioPin::Pin5,
+            CounterPin::Pin7 => GpioPin::Pin7,
+            CounterPin::Pin9 => GpioPin::Pin9,
+            CounterPin::Pin11 => GpioPin::Pin11,
+            CounterPin::Pin13 => GpioPin::Pin13,
+            CounterPin::Pin15 => GpioPin::Pin15,
+            CounterPin::Pin17 => GpioPin::Pin17,
+            CounterPin::Pin19 => GpioPin::Pin19,
+            CounterPin::Pin21 => GpioPin::Pin21,
+            CounterPin::Pin25 => GpioPin::Pin25,
+            CounterPin::Pin27 => GpioPin::Pin27,
+        }
+    }
+}
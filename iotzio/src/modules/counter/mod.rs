@@ -0,0 +1,21 @@
+mod counter_edge;
+mod counter_measurement;
+mod counter_module_error;
+mod counter_pin;
+
+pub use self::counter_edge::*;
+pub use self::counter_module_error::*;
+pub use self::counter_pin::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod counter;
+
+        pub use self::counter::*;
+        pub(crate) use self::counter_measurement::*;
+    }
+    else {
+        pub use self::counter_measurement::*;
+    }
+}
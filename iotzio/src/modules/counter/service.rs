@@ -0,0 +1,75 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::counter::{Counter, CounterEdge, CounterMeasurement, CounterModuleError, CounterPin};
+use crate::socket::Socket;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub async fn new(socket: &Arc<Socket>, pin: CounterPin, edge: CounterEdge) -> Result<Counter, CounterModuleError> {
+    let command = Command::Counter_New { pin, edge };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Counter_New { result } => result,
+        _ => Err(CounterModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(Counter {
+        socket: socket.clone(),
+        pin,
+        edge,
+    })
+}
+
+pub async fn drop(socket: &Socket, pin: CounterPin) -> Result<(), CounterModuleError> {
+    let command = Command::Counter_Drop { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Counter_Drop { result } => result,
+        _ => Err(CounterModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn count_edges(socket: &Socket, pin: CounterPin, gate_time: Duration) -> Result<u64, CounterModuleError> {
+    measure(socket, pin, gate_time).await.map(|x| x.count)
+}
+
+pub async fn measure_frequency_hz(
+    socket: &Socket,
+    pin: CounterPin,
+    gate_time: Duration,
+) -> Result<f64, CounterModuleError> {
+    // The frequency is based on the gate time measured by the device, which may slightly differ from the requested one.
+
+    measure(socket, pin, gate_time)
+        .await
+        .map(|x| x.count as f64 / x.gate_time.as_secs_f64())
+}
+
+#[inline]
+async fn measure(
+    socket: &Socket,
+    pin: CounterPin,
+    gate_time: Duration,
+) -> Result<CounterMeasurement, CounterModuleError> {
+    if gate_time.is_zero() {
+        return Err(CounterModuleError::GateTimeTooShort);
+    }
+
+    let command = Command::Counter_Measure { pin, gate_time };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Counter_Measure { result } => result,
+        _ => Err(CounterModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
@@ -1,5 +1,5 @@
 use crate::modules::i2c_bus::{service, I2cBusModuleError, I2cConfig};
-use crate::peripherals::i2c::I2cBusNumber;
+use crate::peripherals::i2c::{I2cAddress, I2cBusNumber};
 use crate::socket::Socket;
 use async_std::sync::Mutex;
 use async_std::task::block_on;
@@ -32,11 +32,42 @@ impl I2cBus {
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl I2cBus {
+    /// Probe every non reserved 7-bit address (0x08 to 0x77). Returns the addresses that acknowledged.
+    #[inline]
+    pub fn scan(&self) -> Result<Vec<u16>, I2cBusModuleError> {
+        block_on(service::scan(&self.socket, &self.mutex, self.bus_number))
+    }
+
+    /// Free a stuck bus by clocking SCL up to nine times until the target releases SDA, then issue a STOP.
+    #[inline]
+    pub fn recover(&self) -> Result<(), I2cBusModuleError> {
+        block_on(service::recover(&self.socket, &self.mutex, self.bus_number))
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl I2cBus {
+    /// Probe every non reserved 7-bit address (0x08 to 0x77). Returns the addresses that acknowledged.
+    #[inline]
+    pub async fn scan_async(&self) -> Result<Vec<u16>, I2cBusModuleError> {
+        service::scan(&self.socket, &self.mutex, self.bus_number).await
+    }
+
+    /// Free a stuck bus by clocking SCL up to nine times until the target releases SDA, then issue a STOP.
+    #[inline]
+    pub async fn recover_async(&self) -> Result<(), I2cBusModuleError> {
+        service::recover(&self.socket, &self.mutex, self.bus_number).await
+    }
+}
+
 #[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
 impl I2cBus {
     /// Read from address into buffer.
     #[inline]
-    pub fn read(&self, address: u16, buffer: &mut [u8]) -> Result<(), I2cBusModuleError> {
+    pub fn read(&self, address: I2cAddress, buffer: &mut [u8]) -> Result<(), I2cBusModuleError> {
         block_on(service::read(
             &self.socket,
             &self.mutex,
@@ -48,7 +79,7 @@ impl I2cBus {
 
     /// Write to address from bytes.
     #[inline]
-    pub fn write(&self, address: u16, bytes: &[u8]) -> Result<(), I2cBusModuleError> {
+    pub fn write(&self, address: I2cAddress, bytes: &[u8]) -> Result<(), I2cBusModuleError> {
         block_on(service::write(
             &self.socket,
             &self.mutex,
@@ -60,7 +91,7 @@ impl I2cBus {
 
     /// Write to address from bytes, read from address into buffer.
     #[inline]
-    pub fn write_read(&self, address: u16, bytes: &[u8], buffer: &mut [u8]) -> Result<(), I2cBusModuleError> {
+    pub fn write_read(&self, address: I2cAddress, bytes: &[u8], buffer: &mut [u8]) -> Result<(), I2cBusModuleError> {
         block_on(service::write_read(
             &self.socket,
             &self.mutex,
@@ -77,7 +108,7 @@ impl I2cBus {
 impl I2cBus {
     /// Read from address into buffer. Returns buffer.
     #[inline]
-    pub fn read(&self, address: u16, mut buffer: Vec<u8>) -> Result<Vec<u8>, I2cBusModuleError> {
+    pub fn read(&self, address: I2cAddress, mut buffer: Vec<u8>) -> Result<Vec<u8>, I2cBusModuleError> {
         block_on(service::read(
             &self.socket,
             &self.mutex,
@@ -90,7 +121,7 @@ impl I2cBus {
 
     /// Write to address from bytes.
     #[inline]
-    pub fn write(&self, address: u16, bytes: Vec<u8>) -> Result<(), I2cBusModuleError> {
+    pub fn write(&self, address: I2cAddress, bytes: Vec<u8>) -> Result<(), I2cBusModuleError> {
         block_on(service::write(
             &self.socket,
             &self.mutex,
@@ -102,7 +133,12 @@ impl I2cBus {
 
     /// Write to address from bytes, read from address into buffer. Returns buffer.
     #[inline]
-    pub fn write_read(&self, address: u16, bytes: Vec<u8>, mut buffer: Vec<u8>) -> Result<Vec<u8>, I2cBusModuleError> {
+    pub fn write_read(
+        &self,
+        address: I2cAddress,
+        bytes: Vec<u8>,
+        mut buffer: Vec<u8>,
+    ) -> Result<Vec<u8>, I2cBusModuleError> {
         block_on(service::write_read(
             &self.socket,
             &self.mutex,
@@ -119,19 +155,24 @@ impl I2cBus {
 impl I2cBus {
     /// Read from address into buffer.
     #[inline]
-    pub async fn read_async(&self, address: u16, buffer: &mut [u8]) -> Result<(), I2cBusModuleError> {
+    pub async fn read_async(&self, address: I2cAddress, buffer: &mut [u8]) -> Result<(), I2cBusModuleError> {
         service::read(&self.socket, &self.mutex, self.bus_number, address, buffer).await
     }
 
     /// Write to address from bytes.
     #[inline]
-    pub async fn write_async(&self, address: u16, bytes: &[u8]) -> Result<(), I2cBusModuleError> {
+    pub async fn write_async(&self, address: I2cAddress, bytes: &[u8]) -> Result<(), I2cBusModuleError> {
         service::write(&self.socket, &self.mutex, self.bus_number, address, bytes).await
     }
 
     /// Write to address from bytes, read from address into buffer.
     #[inline]
-    pub async fn write_read_async(&self, address: u16, write: &[u8], read: &mut [u8]) -> Result<(), I2cBusModuleError> {
+    pub async fn write_read_async(
+        &self,
+        address: I2cAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), I2cBusModuleError> {
         service::write_read(&self.socket, &self.mutex, self.bus_number, address, write, read).await
     }
 }
@@ -141,7 +182,7 @@ impl I2cBus {
 impl I2cBus {
     /// Read from address into buffer.
     #[inline]
-    pub async fn read_async(&self, address: u16, mut buffer: Vec<u8>) -> Result<Vec<u8>, I2cBusModuleError> {
+    pub async fn read_async(&self, address: I2cAddress, mut buffer: Vec<u8>) -> Result<Vec<u8>, I2cBusModuleError> {
         service::read(
             &self.socket,
             &self.mutex,
@@ -155,7 +196,7 @@ impl I2cBus {
 
     /// Write to address from bytes.
     #[inline]
-    pub async fn write_async(&self, address: u16, bytes: Vec<u8>) -> Result<(), I2cBusModuleError> {
+    pub async fn write_async(&self, address: I2cAddress, bytes: Vec<u8>) -> Result<(), I2cBusModuleError> {
         service::write(&self.socket, &self.mutex, self.bus_number, address, bytes.as_slice()).await
     }
 
@@ -163,7 +204,7 @@ impl I2cBus {
     #[inline]
     pub async fn write_read_async(
         &self,
-        address: u16,
+        address: I2cAddress,
         bytes: Vec<u8>,
         mut buffer: Vec<u8>,
     ) -> Result<Vec<u8>, I2cBusModuleError> {
@@ -203,7 +244,9 @@ impl embedded_hal::i2c::I2c for I2cBus {
             &self.socket,
             &self.mutex,
             self.bus_number,
-            address as u16,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
             read,
         ))
     }
@@ -214,7 +257,9 @@ impl embedded_hal::i2c::I2c for I2cBus {
             &self.socket,
             &self.mutex,
             self.bus_number,
-            address as u16,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
             write,
         ))
     }
@@ -230,7 +275,9 @@ impl embedded_hal::i2c::I2c for I2cBus {
             &self.socket,
             &self.mutex,
             self.bus_number,
-            address as u16,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
             write,
             read,
         ))
@@ -246,7 +293,9 @@ impl embedded_hal::i2c::I2c for I2cBus {
             &self.socket,
             &self.mutex,
             self.bus_number,
-            address as u16,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
             operations,
         ))
     }
@@ -256,12 +305,30 @@ impl embedded_hal::i2c::I2c for I2cBus {
 impl embedded_hal_async::i2c::I2c for I2cBus {
     #[inline]
     async fn read(&mut self, address: embedded_hal::i2c::SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
-        service::read(&self.socket, &self.mutex, self.bus_number, address as u16, read).await
+        service::read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
+            read,
+        )
+        .await
     }
 
     #[inline]
     async fn write(&mut self, address: embedded_hal::i2c::SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
-        service::write(&self.socket, &self.mutex, self.bus_number, address as u16, write).await
+        service::write(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
+            write,
+        )
+        .await
     }
 
     #[inline]
@@ -271,7 +338,17 @@ impl embedded_hal_async::i2c::I2c for I2cBus {
         write: &[u8],
         read: &mut [u8],
     ) -> Result<(), Self::Error> {
-        service::write_read(&self.socket, &self.mutex, self.bus_number, address as u16, write, read).await
+        service::write_read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
+            write,
+            read,
+        )
+        .await
     }
 
     #[inline]
@@ -280,6 +357,133 @@ impl embedded_hal_async::i2c::I2c for I2cBus {
         address: embedded_hal::i2c::SevenBitAddress,
         operations: &mut [embedded_hal::i2c::Operation<'_>],
     ) -> Result<(), Self::Error> {
-        service::transaction(&self.socket, &self.mutex, self.bus_number, address as u16, operations).await
+        service::transaction(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::SevenBit {
+                address: address as u16,
+            },
+            operations,
+        )
+        .await
+    }
+}
+
+#[cfg(all(feature = "embedded-hal", not(target_family = "wasm")))]
+impl embedded_hal::i2c::I2c<embedded_hal::i2c::TenBitAddress> for I2cBus {
+    #[inline]
+    fn read(&mut self, address: embedded_hal::i2c::TenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        block_on(service::read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            read,
+        ))
+    }
+
+    #[inline]
+    fn write(&mut self, address: embedded_hal::i2c::TenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        block_on(service::write(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            write,
+        ))
+    }
+
+    #[inline]
+    fn write_read(
+        &mut self,
+        address: embedded_hal::i2c::TenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        block_on(service::write_read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            write,
+            read,
+        ))
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: embedded_hal::i2c::TenBitAddress,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        block_on(service::transaction(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            operations,
+        ))
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal_async::i2c::I2c<embedded_hal::i2c::TenBitAddress> for I2cBus {
+    #[inline]
+    async fn read(&mut self, address: embedded_hal::i2c::TenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        service::read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            read,
+        )
+        .await
+    }
+
+    #[inline]
+    async fn write(&mut self, address: embedded_hal::i2c::TenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        service::write(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            write,
+        )
+        .await
+    }
+
+    #[inline]
+    async fn write_read(
+        &mut self,
+        address: embedded_hal::i2c::TenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        service::write_read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            write,
+            read,
+        )
+        .await
+    }
+
+    #[inline]
+    async fn transaction(
+        &mut self,
+        address: embedded_hal::i2c::TenBitAddress,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        service::transaction(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            I2cAddress::TenBit { address },
+            operations,
+        )
+        .await
     }
 }
@@ -4,9 +4,11 @@ use serde::{Deserialize, Serialize};
 cfg_if::cfg_if! {
     if #[cfg(feature = "_host")] {
         use std::fmt;
+        use std::time::Duration;
     }
     else {
         use core::fmt;
+        use core::time::Duration;
     }
 }
 
@@ -23,6 +25,8 @@ pub enum I2cConfig {
         sda: I2c0SdaPin,
         /// Frequency to want to use in Hertz. If no value is passed, 100 kHz is used.
         requested_frequency_hz: Option<u32>,
+        /// Maximum duration of a single bus operation. If no value is passed, operations never time out.
+        timeout: Option<Duration>,
     },
     /// Use I2C bus 1
     I2c1 {
@@ -32,6 +36,8 @@ pub enum I2cConfig {
         sda: I2c1SdaPin,
         /// Frequency to want to use in Hertz. If no value is passed, 100 kHz is used.
         requested_frequency_hz: Option<u32>,
+        /// Maximum duration of a single bus operation. If no value is passed, operations never time out.
+        timeout: Option<Duration>,
     },
 }
 
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Tells the device how a chunk of a chunked I2C transaction ends on the bus.
+/// Every operation is sent as at least one chunk, even if it is empty.
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cTransactionChunkEnd {
+    /// More chunks of the same direction follow, the transfer continues without any condition on the bus.
+    Continue,
+    /// Last chunk before the direction changes. A final read byte is NACKed and the next chunk starts with a repeated START.
+    RepeatedStart,
+    /// Last chunk of the transaction. A final read byte is NACKed and a STOP is issued.
+    Stop,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        pub(crate) const I2C_TRANSACTION_MAX_OPERATIONS: usize = 16;
+
+        pub(crate) type I2cTransactionOperations = heapless::Vec<I2cTransactionOperation, I2C_TRANSACTION_MAX_OPERATIONS>;
+    }
+    else {
+        pub const I2C_TRANSACTION_MAX_OPERATIONS: usize = 16;
+
+        pub type I2cTransactionOperations = heapless::Vec<I2cTransactionOperation, I2C_TRANSACTION_MAX_OPERATIONS>;
+    }
+}
+
+/// Single operation of an I2C transaction. Write bytes and read bytes of all operations are concatenated in order.
+/// A repeated START is issued whenever the direction changes, adjacent operations of the same direction are merged.
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cTransactionOperation {
+    Read { buffer_size: u16 },
+    Write { bytes_count: u16 },
+}
@@ -4,6 +4,8 @@ mod i2c1_scl_pin;
 mod i2c1_sda_pin;
 mod i2c_bus_module_error;
 mod i2c_config;
+mod i2c_transaction_chunk_end;
+mod i2c_transaction_operation;
 
 pub use self::i2c0_scl_pin::*;
 pub use self::i2c0_sda_pin::*;
@@ -18,5 +20,11 @@ cfg_if::cfg_if! {
         mod i2c_bus;
 
         pub use self::i2c_bus::*;
+        pub(crate) use self::i2c_transaction_chunk_end::*;
+        pub(crate) use self::i2c_transaction_operation::*;
+    }
+    else {
+        pub use self::i2c_transaction_chunk_end::*;
+        pub use self::i2c_transaction_operation::*;
     }
 }
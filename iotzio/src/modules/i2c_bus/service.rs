@@ -1,6 +1,10 @@
 use crate::communication::{Command, FatalError, ProtocolError, Response};
 use crate::modules::i2c_bus::{I2cBus, I2cBusModuleError, I2cConfig};
-use crate::peripherals::i2c::I2cBusNumber;
+#[cfg(feature = "embedded-hal")]
+use crate::modules::i2c_bus::{
+    I2cTransactionChunkEnd, I2cTransactionOperation, I2cTransactionOperations, I2C_TRANSACTION_MAX_OPERATIONS,
+};
+use crate::peripherals::i2c::{I2cAddress, I2cBusNumber};
 use crate::peripherals::{BusBuffer, BUS_BUFFER_SIZE};
 use crate::socket::Socket;
 use async_std::sync::Mutex;
@@ -49,7 +53,7 @@ pub async fn read(
     socket: &Arc<Socket>,
     mutex: &Mutex<()>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     buffer: &mut [u8],
 ) -> Result<(), I2cBusModuleError> {
     let _mutex_guard = mutex.lock().await;
@@ -62,7 +66,7 @@ pub async fn write(
     socket: &Arc<Socket>,
     mutex: &Mutex<()>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     bytes: &[u8],
 ) -> Result<(), I2cBusModuleError> {
     let _mutex_guard = mutex.lock().await;
@@ -75,7 +79,7 @@ pub async fn write_read(
     socket: &Arc<Socket>,
     mutex: &Mutex<()>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     bytes: &[u8],
     buffer: &mut [u8],
 ) -> Result<(), I2cBusModuleError> {
@@ -84,35 +88,69 @@ pub async fn write_read(
     write_read_inner(socket, identifier, address, bytes, buffer).await
 }
 
+#[inline]
+pub async fn recover(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    identifier: I2cBusNumber,
+) -> Result<(), I2cBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    let command = Command::I2c_Recover { identifier };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::I2c_Recover { result } => result,
+        _ => Err(I2cBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn scan(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    identifier: I2cBusNumber,
+) -> Result<Vec<u16>, I2cBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    // The device probes every non reserved 7-bit address. Addresses answering with AbortNoAcknowledge are skipped,
+    // any other I2C error aborts the scan.
+
+    let command = Command::I2c_Scan { identifier };
+
+    let response = socket.send(command).await??;
+
+    let bus_buffer = match response {
+        Response::I2c_Scan { result } => result,
+        _ => Err(I2cBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(bus_buffer.into_iter().map(u16::from).collect())
+}
+
 #[inline]
 #[cfg(feature = "embedded-hal")]
 pub async fn transaction(
     socket: &Arc<Socket>,
     mutex: &Mutex<()>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     operations: &mut [embedded_hal::i2c::Operation<'_>],
 ) -> Result<(), I2cBusModuleError> {
     let _mutex_guard = mutex.lock().await;
 
-    for operation in operations {
-        match operation {
-            embedded_hal::i2c::Operation::Read(x) => {
-                read_inner(socket, identifier, address, x).await?;
-            }
-            embedded_hal::i2c::Operation::Write(x) => {
-                write_inner(socket, identifier, address, x).await?;
-            }
-        }
-    }
-
-    Ok(())
+    transaction_inner(socket, identifier, address, operations).await
 }
 
 async fn read_inner(
     socket: &Arc<Socket>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     buffer: &mut [u8],
 ) -> Result<(), I2cBusModuleError> {
     if buffer.len() <= BUS_BUFFER_SIZE {
@@ -169,7 +207,7 @@ async fn read_inner(
             let response = socket.send(command).await??;
 
             let bus_buffer = match response {
-                Response::I2c_ReadSingle { result } => result,
+                Response::I2c_ReadChunk { result } => result,
                 _ => Err(I2cBusModuleError::from(FatalError::from(
                     ProtocolError::ReceivedWrongResponse,
                 ))),
@@ -189,7 +227,7 @@ async fn read_inner(
 async fn write_inner(
     socket: &Arc<Socket>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     bytes: &[u8],
 ) -> Result<(), I2cBusModuleError> {
     if bytes.len() <= BUS_BUFFER_SIZE {
@@ -258,7 +296,7 @@ async fn write_inner(
 async fn write_read_inner(
     socket: &Arc<Socket>,
     identifier: I2cBusNumber,
-    address: u16,
+    address: I2cAddress,
     bytes: &[u8],
     buffer: &mut [u8],
 ) -> Result<(), I2cBusModuleError> {
@@ -292,10 +330,183 @@ async fn write_read_inner(
     }
 }
 
+#[cfg(feature = "embedded-hal")]
+async fn transaction_inner(
+    socket: &Arc<Socket>,
+    identifier: I2cBusNumber,
+    address: I2cAddress,
+    operations: &mut [embedded_hal::i2c::Operation<'_>],
+) -> Result<(), I2cBusModuleError> {
+    let (read_count, write_count) =
+        operations
+            .iter()
+            .fold((0, 0), |(read_count, write_count), operation| match operation {
+                embedded_hal::i2c::Operation::Read(x) => (read_count + x.len(), write_count),
+                embedded_hal::i2c::Operation::Write(x) => (read_count, write_count + x.len()),
+            });
+
+    if operations.len() <= I2C_TRANSACTION_MAX_OPERATIONS
+        && read_count <= BUS_BUFFER_SIZE
+        && write_count <= BUS_BUFFER_SIZE
+    {
+        let mut transaction_operations = I2cTransactionOperations::new();
+        let mut bytes = BusBuffer::new();
+
+        for operation in operations.iter() {
+            let transaction_operation = match operation {
+                embedded_hal::i2c::Operation::Read(x) => I2cTransactionOperation::Read {
+                    buffer_size: x.len() as u16,
+                },
+                embedded_hal::i2c::Operation::Write(x) => {
+                    bytes.extend_from_slice(x).unwrap();
+
+                    I2cTransactionOperation::Write {
+                        bytes_count: x.len() as u16,
+                    }
+                }
+            };
+
+            transaction_operations.push(transaction_operation).unwrap();
+        }
+
+        let command = Command::I2c_Transaction {
+            identifier,
+            address,
+            operations: transaction_operations,
+            bytes,
+        };
+
+        let response = socket.send(command).await??;
+
+        let bus_buffer = match response {
+            Response::I2c_Transaction { result } => result,
+            _ => Err(I2cBusModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+
+        let mut received = bus_buffer.into_iter();
+
+        for operation in operations.iter_mut() {
+            if let embedded_hal::i2c::Operation::Read(x) = operation {
+                for (to, from) in x.iter_mut().zip(received.by_ref()) {
+                    *to = from;
+                }
+            }
+        }
+
+        Ok(())
+    } else {
+        {
+            let command = Command::I2c_StartTransactionChunked { identifier, address };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::I2c_StartTransactionChunked { result } => result,
+                _ => Err(I2cBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+
+        let chunked_auto_closeable = ChunkedAutoCloseable::new(socket, identifier, ChunkedModeType::Transaction);
+
+        // Every operation is sent as at least one chunk, so empty writes still address the device.
+        // The last chunk of a direction tells the device where to NACK, repeat the START or issue the STOP.
+
+        let directions: Vec<bool> = operations
+            .iter()
+            .map(|x| matches!(x, embedded_hal::i2c::Operation::Read(_)))
+            .collect();
+
+        for (operation_index, operation) in operations.iter_mut().enumerate() {
+            let operation_end = match directions.get(operation_index + 1) {
+                None => I2cTransactionChunkEnd::Stop,
+                Some(x) if *x != directions[operation_index] => I2cTransactionChunkEnd::RepeatedStart,
+                Some(_) => I2cTransactionChunkEnd::Continue,
+            };
+
+            match operation {
+                embedded_hal::i2c::Operation::Read(x) => {
+                    let chunks_count = x.len().div_ceil(BUS_BUFFER_SIZE).max(1);
+
+                    for chunk_index in 0..chunks_count {
+                        let chunk_start = chunk_index * BUS_BUFFER_SIZE;
+                        let chunk_end = usize::min(chunk_start + BUS_BUFFER_SIZE, x.len());
+                        let chunk = &mut x[chunk_start..chunk_end];
+
+                        let command = Command::I2c_TransactionReadChunk {
+                            identifier,
+                            buffer_size: chunk.len() as u16,
+                            end: chunk_end_of(chunk_index, chunks_count, operation_end),
+                        };
+
+                        let response = socket.send(command).await??;
+
+                        let bus_buffer = match response {
+                            Response::I2c_TransactionReadChunk { result } => result,
+                            _ => Err(I2cBusModuleError::from(FatalError::from(
+                                ProtocolError::ReceivedWrongResponse,
+                            ))),
+                        }?;
+
+                        for (to, from) in chunk.iter_mut().zip(bus_buffer) {
+                            *to = from;
+                        }
+                    }
+                }
+                embedded_hal::i2c::Operation::Write(x) => {
+                    let chunks_count = x.len().div_ceil(BUS_BUFFER_SIZE).max(1);
+
+                    for chunk_index in 0..chunks_count {
+                        let chunk_start = chunk_index * BUS_BUFFER_SIZE;
+                        let chunk_end = usize::min(chunk_start + BUS_BUFFER_SIZE, x.len());
+
+                        let command = Command::I2c_TransactionWriteChunk {
+                            identifier,
+                            bytes: BusBuffer::from_slice(&x[chunk_start..chunk_end]).unwrap(),
+                            end: chunk_end_of(chunk_index, chunks_count, operation_end),
+                        };
+
+                        let response = socket.send(command).await??;
+
+                        match response {
+                            Response::I2c_TransactionWriteChunk { result } => result,
+                            _ => Err(I2cBusModuleError::from(FatalError::from(
+                                ProtocolError::ReceivedWrongResponse,
+                            ))),
+                        }?;
+                    }
+                }
+            }
+        }
+
+        chunked_auto_closeable.drop_async().await?;
+
+        Ok(())
+    }
+}
+
+#[inline]
+#[cfg(feature = "embedded-hal")]
+fn chunk_end_of(
+    chunk_index: usize,
+    chunks_count: usize,
+    operation_end: I2cTransactionChunkEnd,
+) -> I2cTransactionChunkEnd {
+    match chunk_index + 1 == chunks_count {
+        true => operation_end,
+        false => I2cTransactionChunkEnd::Continue,
+    }
+}
+
 #[derive(Copy, Clone)]
 enum ChunkedModeType {
     Write,
     Read,
+    #[cfg_attr(not(feature = "embedded-hal"), allow(dead_code))]
+    Transaction,
 }
 
 struct ChunkedAutoCloseable {
@@ -365,6 +576,18 @@ async fn drop_async_inner(
                 ))),
             }?;
         }
+        ChunkedModeType::Transaction => {
+            let command = Command::I2c_StopTransactionChunked { identifier };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::I2c_StopTransactionChunked { result } => result,
+                _ => Err(I2cBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,161 @@
+use crate::communication::DeviceEvent;
+use crate::modules::i2c_target::{
+    service, I2cTargetConfig, I2cTargetEvent, I2cTargetEventStream, I2cTargetModuleError,
+};
+use crate::peripherals::i2c::I2cBusNumber;
+use crate::socket::Socket;
+use async_std::channel::Receiver;
+use async_std::task::block_on;
+use std::sync::Arc;
+
+/// Represents an I2C bus on the Iotzio device acting as target.
+/// The target answers to its address using a 256 byte register map. The first byte of each write selects the register,
+/// further bytes are stored starting at that register. Reads return the register map starting at the selected register.
+/// Write, read and general call events are pushed by the device and buffered on the host until they are received,
+/// so no event is lost between two waits.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct I2cTarget {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) subscription: u32,
+    pub(crate) receiver: Receiver<DeviceEvent>,
+    pub(crate) bus_number: I2cBusNumber,
+    pub(crate) address: u16,
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl I2cTarget {
+    /// The I2C bus number this instance is using.
+    #[inline]
+    pub fn bus_number(&self) -> I2cBusNumber {
+        self.bus_number
+    }
+
+    /// The 7-bit address this target answers to.
+    #[inline]
+    pub fn address(&self) -> u16 {
+        self.address
+    }
+}
+
+impl I2cTarget {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, config: I2cTargetConfig) -> Result<I2cTarget, I2cTargetModuleError> {
+        service::new(socket, config).await
+    }
+
+    /// Returns a stream of events reported by this target.
+    #[inline]
+    pub fn events(&self) -> I2cTargetEventStream<'_> {
+        I2cTargetEventStream::new(self)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl I2cTarget {
+    /// Wait for the next write, read or general call event.
+    #[inline]
+    pub fn wait_for_event(&self) -> Result<I2cTargetEvent, I2cTargetModuleError> {
+        block_on(service::receive_event(&self.socket, self.subscription, &self.receiver))
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl I2cTarget {
+    /// Wait for the next write, read or general call event.
+    #[inline]
+    pub async fn wait_for_event_async(&self) -> Result<I2cTargetEvent, I2cTargetModuleError> {
+        service::receive_event(&self.socket, self.subscription, &self.receiver).await
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
+impl I2cTarget {
+    /// Read from the register map starting at register into buffer.
+    #[inline]
+    pub fn read_registers(&self, register: u8, buffer: &mut [u8]) -> Result<(), I2cTargetModuleError> {
+        block_on(service::read_registers(&self.socket, self.bus_number, register, buffer))
+    }
+
+    /// Write bytes into the register map starting at register.
+    #[inline]
+    pub fn write_registers(&self, register: u8, bytes: &[u8]) -> Result<(), I2cTargetModuleError> {
+        block_on(service::write_registers(&self.socket, self.bus_number, register, bytes))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "_ffi-blocking"))]
+#[uniffi::export]
+impl I2cTarget {
+    /// Read from the register map starting at register into buffer. Returns buffer.
+    #[inline]
+    pub fn read_registers(&self, register: u8, mut buffer: Vec<u8>) -> Result<Vec<u8>, I2cTargetModuleError> {
+        block_on(service::read_registers(
+            &self.socket,
+            self.bus_number,
+            register,
+            buffer.as_mut_slice(),
+        ))
+        .map(|_| buffer)
+    }
+
+    /// Write bytes into the register map starting at register.
+    #[inline]
+    pub fn write_registers(&self, register: u8, bytes: Vec<u8>) -> Result<(), I2cTargetModuleError> {
+        block_on(service::write_registers(
+            &self.socket,
+            self.bus_number,
+            register,
+            bytes.as_slice(),
+        ))
+    }
+}
+
+#[cfg(not(feature = "_ffi-async"))]
+impl I2cTarget {
+    /// Read from the register map starting at register into buffer.
+    #[inline]
+    pub async fn read_registers_async(&self, register: u8, buffer: &mut [u8]) -> Result<(), I2cTargetModuleError> {
+        service::read_registers(&self.socket, self.bus_number, register, buffer).await
+    }
+
+    /// Write bytes into the register map starting at register.
+    #[inline]
+    pub async fn write_registers_async(&self, register: u8, bytes: &[u8]) -> Result<(), I2cTargetModuleError> {
+        service::write_registers(&self.socket, self.bus_number, register, bytes).await
+    }
+}
+
+#[cfg(feature = "_ffi-async")]
+#[uniffi::export]
+impl I2cTarget {
+    /// Read from the register map starting at register into buffer. Returns buffer.
+    #[inline]
+    pub async fn read_registers_async(
+        &self,
+        register: u8,
+        mut buffer: Vec<u8>,
+    ) -> Result<Vec<u8>, I2cTargetModuleError> {
+        service::read_registers(&self.socket, self.bus_number, register, buffer.as_mut_slice())
+            .await
+            .map(|_| buffer)
+    }
+
+    /// Write bytes into the register map starting at register.
+    #[inline]
+    pub async fn write_registers_async(&self, register: u8, bytes: Vec<u8>) -> Result<(), I2cTargetModuleError> {
+        service::write_registers(&self.socket, self.bus_number, register, bytes.as_slice()).await
+    }
+}
+
+impl Drop for I2cTarget {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let bus = self.bus_number;
+        let subscription = self.subscription;
+
+        block_on(async move { _ = service::drop(&socket, bus, subscription).await })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::modules::i2c_bus::{I2c0SclPin, I2c0SdaPin, I2c1SclPin, I2c1SdaPin};
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::fmt;
+    }
+    else {
+        use core::fmt;
+    }
+}
+
+/// I2C target configuration.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cTargetConfig {
+    /// Use I2C bus 0
+    I2c0 {
+        /// SCL pin to use
+        scl: I2c0SclPin,
+        /// SDA pin to use
+        sda: I2c0SdaPin,
+        /// 7-bit address the target answers to.
+        address: u16,
+        /// Whether general calls to address 0x00 are received.
+        general_call: bool,
+    },
+    /// Use I2C bus 1
+    I2c1 {
+        /// SCL pin to use
+        scl: I2c1SclPin,
+        /// SDA pin to use
+        sda: I2c1SdaPin,
+        /// 7-bit address the target answers to.
+        address: u16,
+        /// Whether general calls to address 0x00 are received.
+        general_call: bool,
+    },
+}
+
+impl fmt::Display for I2cTargetConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
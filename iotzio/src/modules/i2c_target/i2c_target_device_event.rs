@@ -0,0 +1,10 @@
+use crate::peripherals::BusBuffer;
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cTargetDeviceEvent {
+    WriteReceived { register: u8, bytes: BusBuffer },
+    ReadRequested { register: u8, length: u16 },
+    GeneralCall { bytes: BusBuffer },
+}
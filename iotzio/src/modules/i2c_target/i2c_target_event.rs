@@ -0,0 +1,30 @@
+use crate::modules::i2c_target::I2cTargetDeviceEvent;
+
+/// Event reported by an I2C target.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cTargetEvent {
+    /// The controller wrote bytes, which were stored into the register map starting at register.
+    WriteReceived { register: u8, bytes: Vec<u8> },
+    /// The controller read length bytes from the register map starting at register.
+    ReadRequested { register: u8, length: u16 },
+    /// The controller sent a general call to address 0x00.
+    GeneralCall { bytes: Vec<u8> },
+    /// The device dropped the given count of events, as they could not be delivered fast enough.
+    Overflow { dropped_count: u32 },
+}
+
+impl From<I2cTargetDeviceEvent> for I2cTargetEvent {
+    fn from(value: I2cTargetDeviceEvent) -> Self {
+        match value {
+            I2cTargetDeviceEvent::WriteReceived { register, bytes } => I2cTargetEvent::WriteReceived {
+                register,
+                bytes: bytes.to_vec(),
+            },
+            I2cTargetDeviceEvent::ReadRequested { register, length } => {
+                I2cTargetEvent::ReadRequested { register, length }
+            }
+            I2cTargetDeviceEvent::GeneralCall { bytes } => I2cTargetEvent::GeneralCall { bytes: bytes.to_vec() },
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::modules::i2c_target::{service, I2cTarget, I2cTargetEvent, I2cTargetModuleError};
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+cfg_if::cfg_if! {
+    if #[cfg(target_family = "wasm")] {
+        type I2cTargetEventFuture<'a> = Pin<Box<dyn Future<Output = Result<I2cTargetEvent, I2cTargetModuleError>> + 'a>>;
+    }
+    else {
+        type I2cTargetEventFuture<'a> = Pin<Box<dyn Future<Output = Result<I2cTargetEvent, I2cTargetModuleError>> + Send + 'a>>;
+    }
+}
+
+/// Adapter implementing the futures Stream trait for the events of an I2C target.
+/// The stream ends after the first error.
+pub struct I2cTargetEventStream<'a> {
+    target: &'a I2cTarget,
+    future: Option<I2cTargetEventFuture<'a>>,
+    terminated: bool,
+}
+
+impl Debug for I2cTargetEventStream<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("I2cTargetEventStream")
+            .field("target", &self.target)
+            .field("terminated", &self.terminated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> I2cTargetEventStream<'a> {
+    pub(crate) fn new(target: &'a I2cTarget) -> I2cTargetEventStream<'a> {
+        I2cTargetEventStream {
+            target,
+            future: None,
+            terminated: false,
+        }
+    }
+}
+
+impl async_std::stream::Stream for I2cTargetEventStream<'_> {
+    type Item = Result<I2cTargetEvent, I2cTargetModuleError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let target = this.target;
+
+        let future = this.future.get_or_insert_with(|| {
+            Box::pin(async move { service::receive_event(&target.socket, target.subscription, &target.receiver).await })
+        });
+
+        match future.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                this.future = None;
+                this.terminated = result.is_err();
+
+                Poll::Ready(Some(result))
+            }
+        }
+    }
+}
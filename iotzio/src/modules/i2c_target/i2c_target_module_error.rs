@@ -0,0 +1,46 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use crate::peripherals::i2c::I2cError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// I2C target module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cTargetModuleError {
+    /// Accessed registers exceed the 256 byte register map.
+    #[error("Accessed registers exceed the 256 byte register map.")]
+    RegisterRangeOutOfBounds,
+
+    /// I2C error.
+    #[error("{error}")]
+    I2cErrorWrapper { error: I2cError },
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<I2cError> for I2cTargetModuleError {
+    fn from(value: I2cError) -> Self {
+        I2cTargetModuleError::I2cErrorWrapper { error: value }
+    }
+}
+
+impl From<ModuleError> for I2cTargetModuleError {
+    fn from(value: ModuleError) -> Self {
+        I2cTargetModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for I2cTargetModuleError {
+    fn from(value: FatalError) -> Self {
+        I2cTargetModuleError::FatalErrorWrapper { error: value }
+    }
+}
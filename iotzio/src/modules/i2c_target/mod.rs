@@ -0,0 +1,23 @@
+mod i2c_target_config;
+mod i2c_target_device_event;
+mod i2c_target_module_error;
+
+pub use self::i2c_target_config::*;
+pub use self::i2c_target_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod i2c_target;
+        mod i2c_target_event;
+        mod i2c_target_event_stream;
+
+        pub use self::i2c_target::*;
+        pub use self::i2c_target_event::*;
+        pub use self::i2c_target_event_stream::*;
+        pub(crate) use self::i2c_target_device_event::*;
+    }
+    else {
+        pub use self::i2c_target_device_event::*;
+    }
+}
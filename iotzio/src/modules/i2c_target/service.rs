@@ -0,0 +1,143 @@
+use crate::communication::{Command, DeviceEvent, FatalError, ProtocolError, Response};
+use crate::modules::i2c_target::{I2cTarget, I2cTargetConfig, I2cTargetEvent, I2cTargetModuleError};
+use crate::peripherals::i2c::I2cBusNumber;
+use crate::peripherals::{BusBuffer, BUS_BUFFER_SIZE};
+use crate::socket::Socket;
+use async_std::channel::Receiver;
+use std::sync::Arc;
+
+const REGISTER_MAP_SIZE: usize = 256;
+
+static_assertions::const_assert!(REGISTER_MAP_SIZE <= BUS_BUFFER_SIZE);
+
+pub async fn new(socket: &Arc<Socket>, config: I2cTargetConfig) -> Result<I2cTarget, I2cTargetModuleError> {
+    let (identifier, address) = match &config {
+        I2cTargetConfig::I2c0 { address, .. } => (I2cBusNumber::I2c0, *address),
+        I2cTargetConfig::I2c1 { address, .. } => (I2cBusNumber::I2c1, *address),
+    };
+
+    // The subscription is registered before the command is sent, as events may arrive ahead of the response.
+
+    let (subscription, receiver) = socket.subscribe();
+
+    let command = Command::I2cTarget_New { config, subscription };
+
+    let result = async {
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::I2cTarget_New { result } => result,
+            _ => Err(I2cTargetModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }
+    }
+    .await;
+
+    match result {
+        Ok(_) => Ok(I2cTarget {
+            socket: socket.clone(),
+            subscription,
+            receiver,
+            bus_number: identifier,
+            address,
+        }),
+        Err(x) => {
+            socket.unsubscribe(subscription);
+
+            Err(x)
+        }
+    }
+}
+
+pub async fn drop(socket: &Socket, identifier: I2cBusNumber, subscription: u32) -> Result<(), I2cTargetModuleError> {
+    socket.unsubscribe(subscription);
+
+    let command = Command::I2cTarget_Drop { identifier };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::I2cTarget_Drop { result } => result,
+        _ => Err(I2cTargetModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn write_registers(
+    socket: &Socket,
+    identifier: I2cBusNumber,
+    register: u8,
+    bytes: &[u8],
+) -> Result<(), I2cTargetModuleError> {
+    if register as usize + bytes.len() > REGISTER_MAP_SIZE {
+        return Err(I2cTargetModuleError::RegisterRangeOutOfBounds);
+    }
+
+    // The register map is at most 256 bytes, so it always fits into a single bus buffer.
+
+    let command = Command::I2cTarget_WriteRegisters {
+        identifier,
+        register,
+        bytes: BusBuffer::from_slice(bytes).unwrap(),
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::I2cTarget_WriteRegisters { result } => result,
+        _ => Err(I2cTargetModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn read_registers(
+    socket: &Socket,
+    identifier: I2cBusNumber,
+    register: u8,
+    buffer: &mut [u8],
+) -> Result<(), I2cTargetModuleError> {
+    if register as usize + buffer.len() > REGISTER_MAP_SIZE {
+        return Err(I2cTargetModuleError::RegisterRangeOutOfBounds);
+    }
+
+    let command = Command::I2cTarget_ReadRegisters {
+        identifier,
+        register,
+        buffer_size: buffer.len() as u16,
+    };
+
+    let response = socket.send(command).await??;
+
+    let bus_buffer = match response {
+        Response::I2cTarget_ReadRegisters { result } => result,
+        _ => Err(I2cTargetModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    for (to, from) in buffer.iter_mut().zip(bus_buffer) {
+        *to = from;
+    }
+
+    Ok(())
+}
+
+#[inline]
+pub async fn receive_event(
+    socket: &Socket,
+    subscription: u32,
+    receiver: &Receiver<DeviceEvent>,
+) -> Result<I2cTargetEvent, I2cTargetModuleError> {
+    match socket.receive_event(subscription, receiver).await? {
+        DeviceEvent::I2cTarget_Event { event } => Ok(I2cTargetEvent::from(event)),
+        DeviceEvent::Overflow { dropped_count } => Ok(I2cTargetEvent::Overflow { dropped_count }),
+        _ => Err(I2cTargetModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::modules::input_pin::{service, InputPinModuleError};
+use crate::modules::input_pin::{service, InputPinEventStream, InputPinModuleError, InterruptTrigger};
 use crate::peripherals::gpio::{GpioPin, Level, Pull};
 use crate::socket::Socket;
 use async_std::task::block_on;
@@ -25,6 +25,28 @@ impl InputPin {
     ) -> Result<InputPin, InputPinModuleError> {
         service::new(socket, pin, pull_setting, hysteresis).await
     }
+
+    /// Subscribe to the events of the given trigger. The device pushes timestamped events as they occur, so that
+    /// no edge is missed between two waits. Level triggers fire on subscribing while the pin is at that level and
+    /// whenever the pin enters it. Only one subscription per pin can be active at a time.
+    #[inline]
+    pub async fn subscribe_async(
+        &self,
+        trigger: InterruptTrigger,
+    ) -> Result<InputPinEventStream<'_>, InputPinModuleError> {
+        service::subscribe(self, trigger).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl InputPin {
+    /// Subscribe to the events of the given trigger. The device pushes timestamped events as they occur, so that
+    /// no edge is missed between two waits. Level triggers fire on subscribing while the pin is at that level and
+    /// whenever the pin enters it. Only one subscription per pin can be active at a time.
+    #[inline]
+    pub fn subscribe(&self, trigger: InterruptTrigger) -> Result<InputPinEventStream<'_>, InputPinModuleError> {
+        block_on(service::subscribe(self, trigger))
+    }
 }
 
 #[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
@@ -71,49 +93,97 @@ impl InputPin {
     /// Wait until the pin is high. If it is already high, return immediately.
     #[inline]
     pub async fn wait_for_high_async(&self) -> Result<(), InputPinModuleError> {
-        service::wait_for_high(&self.socket, self.pin).await
+        service::wait_for_high(&self.socket, self.pin, None).await
     }
 
     /// Wait until the pin is low. If it is already low, return immediately.
     #[inline]
     pub async fn wait_for_low_async(&self) -> Result<(), InputPinModuleError> {
-        service::wait_for_low(&self.socket, self.pin).await
+        service::wait_for_low(&self.socket, self.pin, None).await
     }
 
     /// Wait for the pin to undergo a transition from low to high.
     #[inline]
     pub async fn wait_for_rising_edge_async(&self) -> Result<(), InputPinModuleError> {
-        service::wait_for_rising_edge(&self.socket, self.pin).await
+        service::wait_for_rising_edge(&self.socket, self.pin, None).await
     }
 
     /// Wait for the pin to undergo a transition from high to low.
     #[inline]
     pub async fn wait_for_falling_edge_async(&self) -> Result<(), InputPinModuleError> {
-        service::wait_for_falling_edge(&self.socket, self.pin).await
+        service::wait_for_falling_edge(&self.socket, self.pin, None).await
     }
 
     /// Wait for the pin to undergo any transition, i.e. low to high OR high to low.
     #[inline]
     pub async fn wait_for_any_edge_async(&self) -> Result<(), InputPinModuleError> {
-        service::wait_for_any_edge(&self.socket, self.pin).await
+        service::wait_for_any_edge(&self.socket, self.pin, None).await
     }
 
     /// Wait for the pin to undergo a pulse transition from low to high to low again. Returns pulse width when succeeded.
     #[inline]
     pub async fn wait_for_high_pulse_async(&self) -> Result<Duration, InputPinModuleError> {
-        service::wait_for_high_pulse(&self.socket, self.pin).await
+        service::wait_for_high_pulse(&self.socket, self.pin, None).await
     }
 
     /// Wait for the pin to undergo a pulse transition from high to low to high again. Returns pulse width when succeeded.
     #[inline]
     pub async fn wait_for_low_pulse_async(&self) -> Result<Duration, InputPinModuleError> {
-        service::wait_for_low_pulse(&self.socket, self.pin).await
+        service::wait_for_low_pulse(&self.socket, self.pin, None).await
     }
 
     /// Wait for the pin to undergo a pulse transition, i.e. from low to high to low again OR from high to low to high again. Returns pulse width when succeeded.
     #[inline]
     pub async fn wait_for_any_pulse_async(&self) -> Result<Duration, InputPinModuleError> {
-        service::wait_for_any_pulse(&self.socket, self.pin).await
+        service::wait_for_any_pulse(&self.socket, self.pin, None).await
+    }
+
+    /// Wait until the pin is high. If it is already high, return immediately. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_high_timeout_async(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        service::wait_for_high(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait until the pin is low. If it is already low, return immediately. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_low_timeout_async(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        service::wait_for_low(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait for the pin to undergo a transition from low to high. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_rising_edge_timeout_async(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        service::wait_for_rising_edge(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait for the pin to undergo a transition from high to low. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_falling_edge_timeout_async(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        service::wait_for_falling_edge(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait for the pin to undergo any transition, i.e. low to high OR high to low. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_any_edge_timeout_async(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        service::wait_for_any_edge(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait for the pin to undergo a pulse transition from low to high to low again. Returns pulse width when succeeded. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_high_pulse_timeout_async(&self, timeout: Duration) -> Result<Duration, InputPinModuleError> {
+        service::wait_for_high_pulse(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait for the pin to undergo a pulse transition from high to low to high again. Returns pulse width when succeeded. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_low_pulse_timeout_async(&self, timeout: Duration) -> Result<Duration, InputPinModuleError> {
+        service::wait_for_low_pulse(&self.socket, self.pin, Some(timeout)).await
+    }
+
+    /// Wait for the pin to undergo a pulse transition, i.e. from low to high to low again OR from high to low to high again. Returns pulse width when succeeded. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub async fn wait_for_any_pulse_timeout_async(&self, timeout: Duration) -> Result<Duration, InputPinModuleError> {
+        service::wait_for_any_pulse(&self.socket, self.pin, Some(timeout)).await
     }
 }
 
@@ -141,49 +211,97 @@ impl InputPin {
     /// Wait until the pin is high. If it is already high, return immediately.
     #[inline]
     pub fn wait_for_high(&self) -> Result<(), InputPinModuleError> {
-        block_on(service::wait_for_high(&self.socket, self.pin))
+        block_on(service::wait_for_high(&self.socket, self.pin, None))
     }
 
     /// Wait until the pin is low. If it is already low, return immediately.
     #[inline]
     pub fn wait_for_low(&self) -> Result<(), InputPinModuleError> {
-        block_on(service::wait_for_low(&self.socket, self.pin))
+        block_on(service::wait_for_low(&self.socket, self.pin, None))
     }
 
     /// Wait for the pin to undergo a transition from low to high.
     #[inline]
     pub fn wait_for_rising_edge(&self) -> Result<(), InputPinModuleError> {
-        block_on(service::wait_for_rising_edge(&self.socket, self.pin))
+        block_on(service::wait_for_rising_edge(&self.socket, self.pin, None))
     }
 
     /// Wait for the pin to undergo a transition from high to low.
     #[inline]
     pub fn wait_for_falling_edge(&self) -> Result<(), InputPinModuleError> {
-        block_on(service::wait_for_falling_edge(&self.socket, self.pin))
+        block_on(service::wait_for_falling_edge(&self.socket, self.pin, None))
     }
 
     /// Wait for the pin to undergo any transition, i.e low to high OR high to low.
     #[inline]
     pub fn wait_for_any_edge(&self) -> Result<(), InputPinModuleError> {
-        block_on(service::wait_for_any_edge(&self.socket, self.pin))
+        block_on(service::wait_for_any_edge(&self.socket, self.pin, None))
     }
 
     /// Wait for the pin to undergo a pulse transition from low to high to low again. Returns pulse width when succeeded.
     #[inline]
     pub fn wait_for_high_pulse(&self) -> Result<Duration, InputPinModuleError> {
-        block_on(service::wait_for_high_pulse(&self.socket, self.pin))
+        block_on(service::wait_for_high_pulse(&self.socket, self.pin, None))
     }
 
     /// Wait for the pin to undergo a pulse transition from high to low to high again. Returns pulse width when succeeded.
     #[inline]
     pub fn wait_for_low_pulse(&self) -> Result<Duration, InputPinModuleError> {
-        block_on(service::wait_for_low_pulse(&self.socket, self.pin))
+        block_on(service::wait_for_low_pulse(&self.socket, self.pin, None))
     }
 
     /// Wait for the pin to undergo a pulse transition, i.e. from low to high to low again OR from high to low to high again. Returns pulse width when succeeded.
     #[inline]
     pub fn wait_for_any_pulse(&self) -> Result<Duration, InputPinModuleError> {
-        block_on(service::wait_for_any_pulse(&self.socket, self.pin))
+        block_on(service::wait_for_any_pulse(&self.socket, self.pin, None))
+    }
+
+    /// Wait until the pin is high. If it is already high, return immediately. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_high_timeout(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        block_on(service::wait_for_high(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait until the pin is low. If it is already low, return immediately. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_low_timeout(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        block_on(service::wait_for_low(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait for the pin to undergo a transition from low to high. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_rising_edge_timeout(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        block_on(service::wait_for_rising_edge(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait for the pin to undergo a transition from high to low. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_falling_edge_timeout(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        block_on(service::wait_for_falling_edge(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait for the pin to undergo any transition, i.e. low to high OR high to low. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_any_edge_timeout(&self, timeout: Duration) -> Result<(), InputPinModuleError> {
+        block_on(service::wait_for_any_edge(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait for the pin to undergo a pulse transition from low to high to low again. Returns pulse width when succeeded. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_high_pulse_timeout(&self, timeout: Duration) -> Result<Duration, InputPinModuleError> {
+        block_on(service::wait_for_high_pulse(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait for the pin to undergo a pulse transition from high to low to high again. Returns pulse width when succeeded. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_low_pulse_timeout(&self, timeout: Duration) -> Result<Duration, InputPinModuleError> {
+        block_on(service::wait_for_low_pulse(&self.socket, self.pin, Some(timeout)))
+    }
+
+    /// Wait for the pin to undergo a pulse transition, i.e. from low to high to low again OR from high to low to high again. Returns pulse width when succeeded. Fails with a command timeout error if the signal does not occur within the given timeout.
+    #[inline]
+    pub fn wait_for_any_pulse_timeout(&self, timeout: Duration) -> Result<Duration, InputPinModuleError> {
+        block_on(service::wait_for_any_pulse(&self.socket, self.pin, Some(timeout)))
     }
 }
 
@@ -219,26 +337,26 @@ impl embedded_hal::digital::InputPin for InputPin {
 impl embedded_hal_async::digital::Wait for InputPin {
     #[inline]
     async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
-        service::wait_for_high(&self.socket, self.pin).await
+        service::wait_for_high(&self.socket, self.pin, None).await
     }
 
     #[inline]
     async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
-        service::wait_for_low(&self.socket, self.pin).await
+        service::wait_for_low(&self.socket, self.pin, None).await
     }
 
     #[inline]
     async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
-        service::wait_for_rising_edge(&self.socket, self.pin).await
+        service::wait_for_rising_edge(&self.socket, self.pin, None).await
     }
 
     #[inline]
     async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
-        service::wait_for_falling_edge(&self.socket, self.pin).await
+        service::wait_for_falling_edge(&self.socket, self.pin, None).await
     }
 
     #[inline]
     async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
-        service::wait_for_any_edge(&self.socket, self.pin).await
+        service::wait_for_any_edge(&self.socket, self.pin, None).await
     }
 }
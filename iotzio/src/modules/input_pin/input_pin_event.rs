@@ -0,0 +1,12 @@
+use crate::peripherals::gpio::Level;
+use std::time::Duration;
+
+/// Event reported by an input pin subscription.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum InputPinEvent {
+    /// The trigger fired. The level is the pin level right after the trigger, the timestamp is the device uptime.
+    Triggered { level: Level, timestamp: Duration },
+    /// The device dropped the given count of events, as they could not be delivered fast enough.
+    Overflow { dropped_count: u32 },
+}
@@ -0,0 +1,95 @@
+use crate::communication::DeviceEvent;
+use crate::modules::input_pin::{service, InputPin, InputPinEvent, InputPinModuleError};
+use async_std::channel::Receiver;
+use async_std::task::block_on;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+cfg_if::cfg_if! {
+    if #[cfg(target_family = "wasm")] {
+        type InputPinEventFuture<'a> = Pin<Box<dyn Future<Output = Result<InputPinEvent, InputPinModuleError>> + 'a>>;
+    }
+    else {
+        type InputPinEventFuture<'a> = Pin<Box<dyn Future<Output = Result<InputPinEvent, InputPinModuleError>> + Send + 'a>>;
+    }
+}
+
+/// Stream of the events pushed by the device for a subscribed input pin. Events are buffered on the host until
+/// they are taken from the stream, so no event is lost between two polls.
+/// The stream ends after the first error. Dropping the stream ends the subscription.
+pub struct InputPinEventStream<'a> {
+    input_pin: &'a InputPin,
+    subscription: u32,
+    receiver: Receiver<DeviceEvent>,
+    future: Option<InputPinEventFuture<'a>>,
+    terminated: bool,
+}
+
+impl Debug for InputPinEventStream<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InputPinEventStream")
+            .field("input_pin", &self.input_pin)
+            .field("subscription", &self.subscription)
+            .field("terminated", &self.terminated)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> InputPinEventStream<'a> {
+    pub(crate) fn new(
+        input_pin: &'a InputPin,
+        subscription: u32,
+        receiver: Receiver<DeviceEvent>,
+    ) -> InputPinEventStream<'a> {
+        InputPinEventStream {
+            input_pin,
+            subscription,
+            receiver,
+            future: None,
+            terminated: false,
+        }
+    }
+}
+
+impl async_std::stream::Stream for InputPinEventStream<'_> {
+    type Item = Result<InputPinEvent, InputPinModuleError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let socket = &this.input_pin.socket;
+        let subscription = this.subscription;
+        let receiver = this.receiver.clone();
+
+        let future = this.future.get_or_insert_with(|| {
+            Box::pin(async move { service::receive_event(socket, subscription, &receiver).await })
+        });
+
+        match future.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                this.future = None;
+                this.terminated = result.is_err();
+
+                Poll::Ready(Some(result))
+            }
+        }
+    }
+}
+
+impl Drop for InputPinEventStream<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.input_pin.socket.clone();
+        let pin = self.input_pin.pin;
+        let subscription = self.subscription;
+
+        block_on(async move { _ = service::unsubscribe(&socket, pin, subscription).await })
+    }
+}
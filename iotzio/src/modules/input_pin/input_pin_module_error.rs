@@ -9,6 +9,10 @@ use thiserror_no_std::Error;
 #[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
 #[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum InputPinModuleError {
+    /// Input pin already has an active event subscription.
+    #[error("Input pin already has an active event subscription.")]
+    AlreadySubscribed,
+
     /// Module error.
     #[error("{error}")]
     ModuleErrorWrapper { error: ModuleError },
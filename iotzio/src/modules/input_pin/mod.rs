@@ -9,8 +9,12 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "_host")] {
         mod service;
         mod input_pin;
+        mod input_pin_event;
+        mod input_pin_event_stream;
 
         pub use self::input_pin::*;
+        pub use self::input_pin_event::*;
+        pub use self::input_pin_event_stream::*;
         pub(crate) use self::signal_type::*;
     }
     else {
@@ -1,5 +1,9 @@
-use crate::communication::{Command, FatalError, ProtocolError, Response};
-use crate::modules::input_pin::{InputPin, InputPinModuleError, SignalTypeRequest, SignalTypeResponse};
+use crate::communication::{Command, DeviceEvent, FatalError, ProtocolError, Response};
+use crate::modules::input_pin::{
+    InputPin, InputPinEvent, InputPinEventStream, InputPinModuleError, InterruptTrigger, SignalTypeRequest,
+    SignalTypeResponse,
+};
+use crate::modules::ModuleError;
 use crate::peripherals::gpio::{GpioPin, Level, Pull};
 use crate::socket::Socket;
 use std::sync::Arc;
@@ -68,8 +72,12 @@ pub async fn get_level(socket: &Socket, pin: GpioPin) -> Result<Level, InputPinM
     }
 }
 
-pub async fn wait_for_high(socket: &Socket, pin: GpioPin) -> Result<(), InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::High).await? {
+pub async fn wait_for_high(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<(), InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::High, timeout).await? {
         SignalTypeResponse::High => Ok(()),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -77,8 +85,8 @@ pub async fn wait_for_high(socket: &Socket, pin: GpioPin) -> Result<(), InputPin
     }
 }
 
-pub async fn wait_for_low(socket: &Socket, pin: GpioPin) -> Result<(), InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::Low).await? {
+pub async fn wait_for_low(socket: &Socket, pin: GpioPin, timeout: Option<Duration>) -> Result<(), InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::Low, timeout).await? {
         SignalTypeResponse::Low => Ok(()),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -86,8 +94,12 @@ pub async fn wait_for_low(socket: &Socket, pin: GpioPin) -> Result<(), InputPinM
     }
 }
 
-pub async fn wait_for_rising_edge(socket: &Socket, pin: GpioPin) -> Result<(), InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::RisingEdge).await? {
+pub async fn wait_for_rising_edge(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<(), InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::RisingEdge, timeout).await? {
         SignalTypeResponse::RisingEdge => Ok(()),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -95,8 +107,12 @@ pub async fn wait_for_rising_edge(socket: &Socket, pin: GpioPin) -> Result<(), I
     }
 }
 
-pub async fn wait_for_falling_edge(socket: &Socket, pin: GpioPin) -> Result<(), InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::FallingEdge).await? {
+pub async fn wait_for_falling_edge(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<(), InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::FallingEdge, timeout).await? {
         SignalTypeResponse::FallingEdge => Ok(()),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -104,8 +120,12 @@ pub async fn wait_for_falling_edge(socket: &Socket, pin: GpioPin) -> Result<(),
     }
 }
 
-pub async fn wait_for_any_edge(socket: &Socket, pin: GpioPin) -> Result<(), InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::AnyEdge).await? {
+pub async fn wait_for_any_edge(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<(), InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::AnyEdge, timeout).await? {
         SignalTypeResponse::AnyEdge => Ok(()),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -113,8 +133,12 @@ pub async fn wait_for_any_edge(socket: &Socket, pin: GpioPin) -> Result<(), Inpu
     }
 }
 
-pub async fn wait_for_high_pulse(socket: &Socket, pin: GpioPin) -> Result<Duration, InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::HighPulse).await? {
+pub async fn wait_for_high_pulse(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<Duration, InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::HighPulse, timeout).await? {
         SignalTypeResponse::HighPulse(x) => Ok(x),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -122,8 +146,12 @@ pub async fn wait_for_high_pulse(socket: &Socket, pin: GpioPin) -> Result<Durati
     }
 }
 
-pub async fn wait_for_low_pulse(socket: &Socket, pin: GpioPin) -> Result<Duration, InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::LowPulse).await? {
+pub async fn wait_for_low_pulse(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<Duration, InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::LowPulse, timeout).await? {
         SignalTypeResponse::LowPulse(x) => Ok(x),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -131,8 +159,12 @@ pub async fn wait_for_low_pulse(socket: &Socket, pin: GpioPin) -> Result<Duratio
     }
 }
 
-pub async fn wait_for_any_pulse(socket: &Socket, pin: GpioPin) -> Result<Duration, InputPinModuleError> {
-    match wait_for(socket, pin, SignalTypeRequest::AnyPulse).await? {
+pub async fn wait_for_any_pulse(
+    socket: &Socket,
+    pin: GpioPin,
+    timeout: Option<Duration>,
+) -> Result<Duration, InputPinModuleError> {
+    match wait_for(socket, pin, SignalTypeRequest::AnyPulse, timeout).await? {
         SignalTypeResponse::AnyPulse(x) => Ok(x),
         _ => Err(InputPinModuleError::from(FatalError::from(
             ProtocolError::ReceivedWrongResponse,
@@ -145,10 +177,25 @@ async fn wait_for(
     socket: &Socket,
     pin: GpioPin,
     signal_type: SignalTypeRequest,
+    timeout: Option<Duration>,
 ) -> Result<SignalTypeResponse, InputPinModuleError> {
     let command = Command::InputPin_WaitForSignal { pin, signal_type };
 
-    let response = socket.send(command).await??;
+    let cancel_command = Command::InputPin_CancelWaitForSignal { pin };
+
+    let response = match timeout {
+        None => socket.send_cancellable(command, cancel_command).await??,
+        Some(x) => match async_std::future::timeout(x, socket.send_cancellable(command, cancel_command)).await {
+            Ok(x) => x??,
+            Err(_) => {
+                // The wait is aborted on the device before returning, so that the pin can be waited on again right away.
+
+                socket.send_pending_cancellations().await;
+
+                return Err(InputPinModuleError::from(ModuleError::CommandTimeout));
+            }
+        },
+    };
 
     match response {
         Response::InputPin_WaitForSignal { result } => result,
@@ -157,3 +204,71 @@ async fn wait_for(
         ))),
     }
 }
+
+pub async fn subscribe(
+    input_pin: &InputPin,
+    trigger: InterruptTrigger,
+) -> Result<InputPinEventStream<'_>, InputPinModuleError> {
+    // The subscription is registered before the command is sent, as events may arrive ahead of the response.
+
+    let socket = &input_pin.socket;
+    let pin = input_pin.pin;
+
+    let (subscription, receiver) = socket.subscribe();
+
+    let command = Command::InputPin_Subscribe {
+        pin,
+        trigger,
+        subscription,
+    };
+
+    let result = async {
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::InputPin_Subscribe { result } => result,
+            _ => Err(InputPinModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }
+    }
+    .await;
+
+    match result {
+        Ok(_) => Ok(InputPinEventStream::new(input_pin, subscription, receiver)),
+        Err(x) => {
+            socket.unsubscribe(subscription);
+
+            Err(x)
+        }
+    }
+}
+
+pub async fn unsubscribe(socket: &Socket, pin: GpioPin, subscription: u32) -> Result<(), InputPinModuleError> {
+    socket.unsubscribe(subscription);
+
+    let command = Command::InputPin_Unsubscribe { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::InputPin_Unsubscribe { result } => result,
+        _ => Err(InputPinModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn receive_event(
+    socket: &Socket,
+    subscription: u32,
+    receiver: &async_std::channel::Receiver<DeviceEvent>,
+) -> Result<InputPinEvent, InputPinModuleError> {
+    match socket.receive_event(subscription, receiver).await? {
+        DeviceEvent::InputPin_Triggered { level, timestamp } => Ok(InputPinEvent::Triggered { level, timestamp }),
+        DeviceEvent::Overflow { dropped_count } => Ok(InputPinEvent::Overflow { dropped_count }),
+        _ => Err(InputPinModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
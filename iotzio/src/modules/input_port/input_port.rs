@@ -0,0 +1,100 @@
+use crate::modules::input_port::{service, InputPortModuleError, InputPortPin};
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Represents a group of input pins on the Iotzio device that are sampled at once.
+/// Bit n of a port value belongs to the nth pin the port was set up with. A set bit means the pin is high.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct InputPort {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) pins: Vec<InputPortPin>,
+    pub(crate) pin_mask: u32,
+}
+
+impl InputPort {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, pins: Vec<InputPortPin>) -> Result<InputPort, InputPortModuleError> {
+        service::new(socket, pins).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl InputPort {
+    /// Returns used pins in port order.
+    #[inline]
+    pub fn get_pins(&self) -> Vec<InputPortPin> {
+        self.pins.clone()
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl InputPort {
+    /// Returns the levels of all pins of the port, sampled at once.
+    #[inline]
+    pub async fn get_levels_async(&self) -> Result<u32, InputPortModuleError> {
+        service::get_levels(&self.socket, &self.pins, self.pin_mask).await
+    }
+
+    /// Wait until the level of any pin differs from the given port value and return the new levels. If they already differ, return immediately.
+    #[inline]
+    pub async fn wait_for_change_async(&self, levels: u32) -> Result<u32, InputPortModuleError> {
+        service::wait_for_change(&self.socket, &self.pins, self.pin_mask, levels, None).await
+    }
+
+    /// Wait until the level of any pin differs from the given port value and return the new levels. If they already differ, return immediately. Fails with a command timeout error if no change occurs within the given timeout.
+    #[inline]
+    pub async fn wait_for_change_timeout_async(
+        &self,
+        levels: u32,
+        timeout: Duration,
+    ) -> Result<u32, InputPortModuleError> {
+        service::wait_for_change(&self.socket, &self.pins, self.pin_mask, levels, Some(timeout)).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl InputPort {
+    /// Returns the levels of all pins of the port, sampled at once.
+    #[inline]
+    pub fn get_levels(&self) -> Result<u32, InputPortModuleError> {
+        block_on(service::get_levels(&self.socket, &self.pins, self.pin_mask))
+    }
+
+    /// Wait until the level of any pin differs from the given port value and return the new levels. If they already differ, return immediately.
+    #[inline]
+    pub fn wait_for_change(&self, levels: u32) -> Result<u32, InputPortModuleError> {
+        block_on(service::wait_for_change(
+            &self.socket,
+            &self.pins,
+            self.pin_mask,
+            levels,
+            None,
+        ))
+    }
+
+    /// Wait until the level of any pin differs from the given port value and return the new levels. If they already differ, return immediately. Fails with a command timeout error if no change occurs within the given timeout.
+    #[inline]
+    pub fn wait_for_change_timeout(&self, levels: u32, timeout: Duration) -> Result<u32, InputPortModuleError> {
+        block_on(service::wait_for_change(
+            &self.socket,
+            &self.pins,
+            self.pin_mask,
+            levels,
+            Some(timeout),
+        ))
+    }
+}
+
+impl Drop for InputPort {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin_mask = self.pin_mask;
+
+        block_on(async move { _ = service::drop(&socket, pin_mask).await })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use crate::peripherals::gpio::GpioPin;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Input port module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum InputPortModuleError {
+    /// Input port needs at least one pin.
+    #[error("Input port needs at least one pin.")]
+    NoPins,
+
+    /// Pin is used more than once within the input port.
+    #[error("Pin {pin} is used more than once within the input port.")]
+    DuplicatePin { pin: GpioPin },
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for InputPortModuleError {
+    fn from(value: ModuleError) -> Self {
+        InputPortModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for InputPortModuleError {
+    fn from(value: FatalError) -> Self {
+        InputPortModuleError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::peripherals::gpio::{GpioPin, Pull};
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a single pin within an input port.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InputPortPin {
+    /// Pin to use.
+    pub pin: GpioPin,
+    /// Pull setting of the pin.
+    pub pull_setting: Pull,
+    /// Whether hysteresis is enabled for the pin.
+    pub hysteresis: bool,
+}
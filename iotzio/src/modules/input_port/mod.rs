@@ -0,0 +1,14 @@
+mod input_port_module_error;
+mod input_port_pin;
+
+pub use self::input_port_module_error::*;
+pub use self::input_port_pin::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod input_port;
+
+        pub use self::input_port::*;
+    }
+}
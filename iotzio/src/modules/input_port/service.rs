@@ -0,0 +1,131 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::input_port::{InputPort, InputPortModuleError, InputPortPin};
+use crate::modules::ModuleError;
+use crate::peripherals::gpio::GPIO_PIN_COUNT;
+use crate::socket::Socket;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub async fn new(socket: &Arc<Socket>, pins: Vec<InputPortPin>) -> Result<InputPort, InputPortModuleError> {
+    if pins.is_empty() {
+        return Err(InputPortModuleError::NoPins);
+    }
+
+    let mut pin_mask = 0u32;
+
+    for x in pins.iter() {
+        let bit = gpio_bit(x);
+
+        if pin_mask & bit != 0 {
+            return Err(InputPortModuleError::DuplicatePin { pin: x.pin });
+        }
+
+        pin_mask |= bit;
+    }
+
+    // Pins are unique at this point, so they always fit.
+
+    let command = Command::InputPort_New {
+        pins: heapless::Vec::<InputPortPin, GPIO_PIN_COUNT>::from_slice(&pins).unwrap(),
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::InputPort_New { result } => result,
+        _ => Err(InputPortModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(InputPort {
+        socket: socket.clone(),
+        pins,
+        pin_mask,
+    })
+}
+
+pub async fn drop(socket: &Socket, pin_mask: u32) -> Result<(), InputPortModuleError> {
+    let command = Command::InputPort_Drop { pin_mask };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::InputPort_Drop { result } => result,
+        _ => Err(InputPortModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn get_levels(socket: &Socket, pins: &[InputPortPin], pin_mask: u32) -> Result<u32, InputPortModuleError> {
+    let command = Command::InputPort_GetLevels { pin_mask };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::InputPort_GetLevels { result } => result.map(|x| to_port_value(pins, x)),
+        _ => Err(InputPortModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn wait_for_change(
+    socket: &Socket,
+    pins: &[InputPortPin],
+    pin_mask: u32,
+    levels: u32,
+    timeout: Option<Duration>,
+) -> Result<u32, InputPortModuleError> {
+    let command = Command::InputPort_WaitForChange {
+        pin_mask,
+        levels: to_gpio_mask(pins, levels),
+    };
+
+    let cancel_command = Command::InputPort_CancelWaitForChange { pin_mask };
+
+    let response = match timeout {
+        None => socket.send_cancellable(command, cancel_command).await??,
+        Some(x) => match async_std::future::timeout(x, socket.send_cancellable(command, cancel_command)).await {
+            Ok(x) => x??,
+            Err(_) => {
+                // The wait is aborted on the device before returning, so that the port can be waited on again right away.
+
+                socket.send_pending_cancellations().await;
+
+                return Err(InputPortModuleError::from(ModuleError::CommandTimeout));
+            }
+        },
+    };
+
+    match response {
+        Response::InputPort_WaitForChange { result } => result.map(|x| to_port_value(pins, x)),
+        _ => Err(InputPortModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+fn gpio_bit(pin: &InputPortPin) -> u32 {
+    1 << u8::from(pin.pin)
+}
+
+/// Translate a port value (bit n is the nth pin of the port) into a mask of GPIO numbers.
+#[inline]
+fn to_gpio_mask(pins: &[InputPortPin], value: u32) -> u32 {
+    pins.iter()
+        .enumerate()
+        .filter(|(index, _)| value & (1 << index) != 0)
+        .fold(0u32, |mask, (_, x)| mask | gpio_bit(x))
+}
+
+/// Translate a mask of GPIO numbers into a port value (bit n is the nth pin of the port).
+#[inline]
+fn to_port_value(pins: &[InputPortPin], gpio_mask: u32) -> u32 {
+    pins.iter()
+        .enumerate()
+        .filter(|(_, x)| gpio_mask & gpio_bit(x) != 0)
+        .fold(0u32, |value, (index, _)| value | (1 << index))
+}
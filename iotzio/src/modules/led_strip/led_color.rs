@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Color of a single LED of an LED strip.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LedColor {
+    /// Red channel.
+    pub red: u8,
+    /// Green channel.
+    pub green: u8,
+    /// Blue channel.
+    pub blue: u8,
+    /// White channel. Ignored by LED strips without a white LED.
+    pub white: u8,
+}
+
+impl LedColor {
+    /// Create a color without white channel.
+    #[inline]
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> LedColor {
+        LedColor {
+            red,
+            green,
+            blue,
+            white: 0,
+        }
+    }
+
+    /// Create a color with white channel.
+    #[inline]
+    pub const fn rgbw(red: u8, green: u8, blue: u8, white: u8) -> LedColor {
+        LedColor {
+            red,
+            green,
+            blue,
+            white,
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::modules::led_strip::{service, LedColor, LedStripModuleError, LedStripType};
+use crate::peripherals::gpio::GpioPin;
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+
+/// Represents an addressable LED strip (WS2812, SK6812) on the Iotzio device. The timing of the single wire protocol is
+/// generated by the device. Colors are scaled by a global brightness on the device, so that changing the brightness
+/// neither requires writing the colors again nor loses their resolution.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct LedStrip {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) mutex: Mutex<()>,
+    pub(crate) pin: GpioPin,
+    pub(crate) led_type: LedStripType,
+    pub(crate) led_count: u16,
+    pub(crate) brightness: AtomicU8,
+}
+
+impl LedStrip {
+    #[inline]
+    pub(crate) async fn new(
+        socket: &Arc<Socket>,
+        pin: GpioPin,
+        led_type: LedStripType,
+        led_count: u16,
+        brightness: u8,
+    ) -> Result<LedStrip, LedStripModuleError> {
+        service::new(socket, pin, led_type, led_count, brightness).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl LedStrip {
+    /// Returns the data pin of the LED strip.
+    #[inline]
+    pub fn get_pin(&self) -> GpioPin {
+        self.pin
+    }
+
+    /// Returns the type of the LEDs.
+    #[inline]
+    pub fn get_led_type(&self) -> LedStripType {
+        self.led_type
+    }
+
+    /// Returns the number of LEDs.
+    #[inline]
+    pub fn get_led_count(&self) -> u16 {
+        self.led_count
+    }
+
+    /// Returns the global brightness, where 255 shows the colors unscaled.
+    #[inline]
+    pub fn get_brightness(&self) -> u8 {
+        self.brightness.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl LedStrip {
+    /// Set the global brightness, where 255 shows the colors unscaled. The LEDs are updated immediately.
+    #[inline]
+    pub async fn set_brightness_async(&self, brightness: u8) -> Result<(), LedStripModuleError> {
+        service::set_brightness(&self.socket, &self.brightness, self.pin, brightness).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl LedStrip {
+    /// Set the global brightness, where 255 shows the colors unscaled. The LEDs are updated immediately.
+    #[inline]
+    pub fn set_brightness(&self, brightness: u8) -> Result<(), LedStripModuleError> {
+        block_on(service::set_brightness(
+            &self.socket,
+            &self.brightness,
+            self.pin,
+            brightness,
+        ))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
+impl LedStrip {
+    /// Show the given colors, starting at the first LED. LEDs beyond the given colors keep their color.
+    #[inline]
+    pub fn write(&self, colors: &[LedColor]) -> Result<(), LedStripModuleError> {
+        block_on(service::write(
+            &self.socket,
+            &self.mutex,
+            self.pin,
+            self.led_type,
+            self.led_count,
+            colors,
+        ))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "_ffi-blocking"))]
+#[uniffi::export]
+impl LedStrip {
+    /// Show the given colors, starting at the first LED. LEDs beyond the given colors keep their color.
+    #[inline]
+    pub fn write(&self, colors: Vec<LedColor>) -> Result<(), LedStripModuleError> {
+        block_on(service::write(
+            &self.socket,
+            &self.mutex,
+            self.pin,
+            self.led_type,
+            self.led_count,
+            colors.as_slice(),
+        ))
+    }
+}
+
+#[cfg(not(feature = "_ffi-async"))]
+impl LedStrip {
+    /// Show the given colors, starting at the first LED. LEDs beyond the given colors keep their color.
+    #[inline]
+    pub async fn write_async(&self, colors: &[LedColor]) -> Result<(), LedStripModuleError> {
+        service::write(
+            &self.socket,
+            &self.mutex,
+            self.pin,
+            self.led_type,
+            self.led_count,
+            colors,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "_ffi-async")]
+#[uniffi::export]
+impl LedStrip {
+    /// Show the given colors, starting at the first LED. LEDs beyond the given colors keep their color.
+    #[inline]
+    pub async fn write_async(&self, colors: Vec<LedColor>) -> Result<(), LedStripModuleError> {
+        service::write(
+            &self.socket,
+            &self.mutex,
+            self.pin,
+            self.led_type,
+            self.led_count,
+            colors.as_slice(),
+        )
+        .await
+    }
+}
+
+impl Drop for LedStrip {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin = self.pin;
+
+        block_on(async move { _ = service::drop(&socket, pin).await })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// LED strip module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LedStripModuleError {
+    /// LED count must be between 1 and the maximum LED count.
+    #[error("LED count must be between 1 and {max}.")]
+    LedCountOutOfRange { max: u16 },
+
+    /// More colors given than the LED strip has LEDs.
+    #[error("More colors given than the LED strip has LEDs ({led_count}).")]
+    TooManyColors { led_count: u16 },
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for LedStripModuleError {
+    fn from(value: ModuleError) -> Self {
+        LedStripModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for LedStripModuleError {
+    fn from(value: FatalError) -> Self {
+        LedStripModuleError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,27 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of LEDs of a single LED strip. The device keeps the colors of all LEDs, so that the brightness can be changed without writing the colors again.
+pub const LED_STRIP_MAX_LED_COUNT: u16 = 1024;
+
+/// Type of the LEDs of an LED strip. All supported types share the same 800 kHz single wire timing.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LedStripType {
+    /// WS2812, WS2812B or SK6812 RGB LEDs, expecting the channels in green, red, blue order.
+    Ws2812,
+    /// SK6812 RGBW LEDs, expecting the channels in green, red, blue, white order.
+    Sk6812Rgbw,
+}
+
+impl LedStripType {
+    /// Number of bytes sent to a single LED.
+    #[inline]
+    pub const fn bytes_per_led(&self) -> usize {
+        match self {
+            LedStripType::Ws2812 => 3,
+            LedStripType::Sk6812Rgbw => 4,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+mod led_color;
+mod led_strip_module_error;
+mod led_strip_type;
+
+pub use self::led_color::*;
+pub use self::led_strip_module_error::*;
+pub use self::led_strip_type::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod led_strip;
+
+        pub use self::led_strip::*;
+    }
+}
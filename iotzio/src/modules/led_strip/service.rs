@@ -0,0 +1,218 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::led_strip::{LedColor, LedStrip, LedStripModuleError, LedStripType, LED_STRIP_MAX_LED_COUNT};
+use crate::peripherals::gpio::GpioPin;
+use crate::peripherals::{BusBuffer, BUS_BUFFER_SIZE};
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
+
+pub async fn new(
+    socket: &Arc<Socket>,
+    pin: GpioPin,
+    led_type: LedStripType,
+    led_count: u16,
+    brightness: u8,
+) -> Result<LedStrip, LedStripModuleError> {
+    if !(1..=LED_STRIP_MAX_LED_COUNT).contains(&led_count) {
+        return Err(LedStripModuleError::LedCountOutOfRange {
+            max: LED_STRIP_MAX_LED_COUNT,
+        });
+    }
+
+    let command = Command::LedStrip_New {
+        pin,
+        led_type,
+        led_count,
+        brightness,
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::LedStrip_New { result } => result,
+        _ => Err(LedStripModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(LedStrip {
+        socket: socket.clone(),
+        mutex: Mutex::new(()),
+        pin,
+        led_type,
+        led_count,
+        brightness: AtomicU8::new(brightness),
+    })
+}
+
+pub async fn drop(socket: &Socket, pin: GpioPin) -> Result<(), LedStripModuleError> {
+    let command = Command::LedStrip_Drop { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::LedStrip_Drop { result } => result,
+        _ => Err(LedStripModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn write(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    led_type: LedStripType,
+    led_count: u16,
+    colors: &[LedColor],
+) -> Result<(), LedStripModuleError> {
+    if colors.len() > led_count as usize {
+        return Err(LedStripModuleError::TooManyColors { led_count });
+    }
+
+    // Colors are sent in the channel order of the LEDs. Brightness is applied by the device.
+
+    let bytes: Vec<u8> = colors
+        .iter()
+        .flat_map(|x| {
+            [x.green, x.red, x.blue, x.white]
+                .into_iter()
+                .take(led_type.bytes_per_led())
+        })
+        .collect();
+
+    let _mutex_guard = mutex.lock().await;
+
+    if bytes.len() <= BUS_BUFFER_SIZE {
+        let command = Command::LedStrip_WriteSingle {
+            pin,
+            bytes: BusBuffer::from_slice(&bytes).unwrap(),
+        };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::LedStrip_WriteSingle { result } => result,
+            _ => Err(LedStripModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+
+        Ok(())
+    } else {
+        let chunks = bytes.chunks(BUS_BUFFER_SIZE).enumerate();
+        let chunks_count = chunks.len();
+
+        {
+            let command = Command::LedStrip_StartWriteChunked {
+                pin,
+                chunks_count: chunks_count as u32,
+            };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::LedStrip_StartWriteChunked { result } => result,
+                _ => Err(LedStripModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+
+        let chunked_auto_closeable = ChunkedAutoCloseable::new(socket, pin);
+
+        for (chunk_index, chunk) in chunks {
+            let command = Command::LedStrip_WriteChunk {
+                pin,
+                bytes: BusBuffer::from_slice(chunk).unwrap(),
+                chunk_index: chunk_index as u32,
+            };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::LedStrip_WriteChunk { result } => result,
+                _ => Err(LedStripModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+
+        // The device shows the colors only after all announced chunks arrived, so that an interrupted write leaves the strip untouched.
+
+        chunked_auto_closeable.drop_async().await?;
+
+        Ok(())
+    }
+}
+
+pub async fn set_brightness(
+    socket: &Socket,
+    brightness_state: &AtomicU8,
+    pin: GpioPin,
+    brightness: u8,
+) -> Result<(), LedStripModuleError> {
+    let command = Command::LedStrip_SetBrightness { pin, brightness };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::LedStrip_SetBrightness { result } => result,
+        _ => Err(LedStripModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    brightness_state.store(brightness, Ordering::Relaxed);
+
+    Ok(())
+}
+
+struct ChunkedAutoCloseable {
+    socket: Option<Arc<Socket>>,
+    pin: GpioPin,
+}
+
+impl ChunkedAutoCloseable {
+    pub fn new(socket: &Arc<Socket>, pin: GpioPin) -> ChunkedAutoCloseable {
+        ChunkedAutoCloseable {
+            socket: Some(socket.clone()),
+            pin,
+        }
+    }
+
+    pub async fn drop_async(mut self) -> Result<(), LedStripModuleError> {
+        match self.socket.take() {
+            None => Ok(()),
+            Some(socket) => drop_async_inner(socket, self.pin).await,
+        }
+    }
+}
+
+impl Drop for ChunkedAutoCloseable {
+    fn drop(&mut self) {
+        match self.socket.take() {
+            None => {}
+            Some(socket) => {
+                let pin = self.pin;
+
+                block_on(async move { _ = drop_async_inner(socket, pin).await })
+            }
+        }
+    }
+}
+
+async fn drop_async_inner(socket: Arc<Socket>, pin: GpioPin) -> Result<(), LedStripModuleError> {
+    let command = Command::LedStrip_StopWriteChunked { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::LedStrip_StopWriteChunked { result } => result,
+        _ => Err(LedStripModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
@@ -1,8 +1,22 @@
 #![forbid(unsafe_code)]
 
+pub mod analog_input;
+pub mod counter;
 pub mod i2c_bus;
+pub mod i2c_target;
 pub mod input_pin;
+pub mod input_port;
+pub mod led_strip;
 mod modules_error;
+pub mod one_wire_bus;
 pub mod output_pin;
+pub mod output_port;
+pub mod pulse_in;
+pub mod pwm_output;
+pub mod quadrature_encoder;
+pub mod servo;
+pub mod spi_bus;
+pub mod stepper;
+pub mod uart;
 
 pub use self::modules_error::*;
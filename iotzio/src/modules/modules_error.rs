@@ -30,4 +30,8 @@ pub enum ModuleError {
     /// The requested module instance was no longer found on the device. Maybe it was previously disabled.
     #[error("The requested module instance was no longer found on the device. Maybe it was previously disabled.")]
     ModuleInstanceNotFound,
+
+    /// The command did not complete within the timeout.
+    #[error("The command did not complete within the timeout.")]
+    CommandTimeout,
 }
@@ -0,0 +1,10 @@
+/// Temperature reading of a DS18B20 sensor.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Ds18b20Reading {
+    /// ROM code of the sensor.
+    pub rom: u64,
+
+    /// Measured temperature in degrees Celsius.
+    pub temperature_celsius: f32,
+}
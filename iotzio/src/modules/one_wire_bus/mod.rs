@@ -0,0 +1,19 @@
+mod one_wire_bus_module_error;
+mod search_state;
+
+pub use self::one_wire_bus_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod ds18b20_reading;
+        mod one_wire_bus;
+
+        pub use self::ds18b20_reading::*;
+        pub use self::one_wire_bus::*;
+        pub(crate) use self::search_state::*;
+    }
+    else {
+        pub use self::search_state::*;
+    }
+}
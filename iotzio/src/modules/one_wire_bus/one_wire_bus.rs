@@ -0,0 +1,244 @@
+use crate::modules::one_wire_bus::{service, Ds18b20Reading, OneWireBusModuleError};
+use crate::peripherals::gpio::GpioPin;
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::Arc;
+
+/// Represents a 1-Wire bus on the Iotzio device.
+/// ROM codes are 64 bit values with the family code in the lowest byte, as transmitted on the bus.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct OneWireBus {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) mutex: Mutex<()>,
+    pub(crate) pin: GpioPin,
+}
+
+impl OneWireBus {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, pin: GpioPin) -> Result<OneWireBus, OneWireBusModuleError> {
+        service::new(socket, pin).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl OneWireBus {
+    /// Returns used pin.
+    #[inline]
+    pub fn get_pin(&self) -> GpioPin {
+        self.pin
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl OneWireBus {
+    /// Send a reset pulse. Returns whether at least one device answered with a presence pulse.
+    #[inline]
+    pub async fn reset_async(&self) -> Result<bool, OneWireBusModuleError> {
+        service::reset(&self.socket, &self.mutex, self.pin).await
+    }
+
+    /// Read a single bit.
+    #[inline]
+    pub async fn read_bit_async(&self) -> Result<bool, OneWireBusModuleError> {
+        service::read_bit(&self.socket, &self.mutex, self.pin).await
+    }
+
+    /// Write a single bit.
+    #[inline]
+    pub async fn write_bit_async(&self, bit: bool) -> Result<(), OneWireBusModuleError> {
+        service::write_bit(&self.socket, &self.mutex, self.pin, bit).await
+    }
+
+    /// Read a single byte.
+    #[inline]
+    pub async fn read_byte_async(&self) -> Result<u8, OneWireBusModuleError> {
+        service::read_byte(&self.socket, &self.mutex, self.pin).await
+    }
+
+    /// Write a single byte.
+    #[inline]
+    pub async fn write_byte_async(&self, byte: u8) -> Result<(), OneWireBusModuleError> {
+        service::write_byte(&self.socket, &self.mutex, self.pin, byte).await
+    }
+
+    /// Search the ROM codes of all devices on the bus.
+    #[inline]
+    pub async fn search_async(&self) -> Result<Vec<u64>, OneWireBusModuleError> {
+        service::search(&self.socket, &self.mutex, self.pin, false).await
+    }
+
+    /// Search the ROM codes of all devices on the bus with an alarm condition set.
+    #[inline]
+    pub async fn search_alarm_async(&self) -> Result<Vec<u64>, OneWireBusModuleError> {
+        service::search(&self.socket, &self.mutex, self.pin, true).await
+    }
+
+    /// Address the device with the given ROM code. Must follow a reset.
+    #[inline]
+    pub async fn match_rom_async(&self, rom: u64) -> Result<(), OneWireBusModuleError> {
+        service::match_rom(&self.socket, &self.mutex, self.pin, rom).await
+    }
+
+    /// Address all devices on the bus at once. Must follow a reset.
+    #[inline]
+    pub async fn skip_rom_async(&self) -> Result<(), OneWireBusModuleError> {
+        service::skip_rom(&self.socket, &self.mutex, self.pin).await
+    }
+
+    /// Start a temperature conversion on all DS18B20 sensors on the bus and read the results.
+    #[inline]
+    pub async fn read_ds18b20_temperatures_async(&self) -> Result<Vec<Ds18b20Reading>, OneWireBusModuleError> {
+        service::read_ds18b20_temperatures(&self.socket, &self.mutex, self.pin).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl OneWireBus {
+    /// Send a reset pulse. Returns whether at least one device answered with a presence pulse.
+    #[inline]
+    pub fn reset(&self) -> Result<bool, OneWireBusModuleError> {
+        block_on(service::reset(&self.socket, &self.mutex, self.pin))
+    }
+
+    /// Read a single bit.
+    #[inline]
+    pub fn read_bit(&self) -> Result<bool, OneWireBusModuleError> {
+        block_on(service::read_bit(&self.socket, &self.mutex, self.pin))
+    }
+
+    /// Write a single bit.
+    #[inline]
+    pub fn write_bit(&self, bit: bool) -> Result<(), OneWireBusModuleError> {
+        block_on(service::write_bit(&self.socket, &self.mutex, self.pin, bit))
+    }
+
+    /// Read a single byte.
+    #[inline]
+    pub fn read_byte(&self) -> Result<u8, OneWireBusModuleError> {
+        block_on(service::read_byte(&self.socket, &self.mutex, self.pin))
+    }
+
+    /// Write a single byte.
+    #[inline]
+    pub fn write_byte(&self, byte: u8) -> Result<(), OneWireBusModuleError> {
+        block_on(service::write_byte(&self.socket, &self.mutex, self.pin, byte))
+    }
+
+    /// Search the ROM codes of all devices on the bus.
+    #[inline]
+    pub fn search(&self) -> Result<Vec<u64>, OneWireBusModuleError> {
+        block_on(service::search(&self.socket, &self.mutex, self.pin, false))
+    }
+
+    /// Search the ROM codes of all devices on the bus with an alarm condition set.
+    #[inline]
+    pub fn search_alarm(&self) -> Result<Vec<u64>, OneWireBusModuleError> {
+        block_on(service::search(&self.socket, &self.mutex, self.pin, true))
+    }
+
+    /// Address the device with the given ROM code. Must follow a reset.
+    #[inline]
+    pub fn match_rom(&self, rom: u64) -> Result<(), OneWireBusModuleError> {
+        block_on(service::match_rom(&self.socket, &self.mutex, self.pin, rom))
+    }
+
+    /// Address all devices on the bus at once. Must follow a reset.
+    #[inline]
+    pub fn skip_rom(&self) -> Result<(), OneWireBusModuleError> {
+        block_on(service::skip_rom(&self.socket, &self.mutex, self.pin))
+    }
+
+    /// Start a temperature conversion on all DS18B20 sensors on the bus and read the results.
+    #[inline]
+    pub fn read_ds18b20_temperatures(&self) -> Result<Vec<Ds18b20Reading>, OneWireBusModuleError> {
+        block_on(service::read_ds18b20_temperatures(&self.socket, &self.mutex, self.pin))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
+impl OneWireBus {
+    /// Read into buffer.
+    #[inline]
+    pub fn read_bytes(&self, buffer: &mut [u8]) -> Result<(), OneWireBusModuleError> {
+        block_on(service::read_bytes(&self.socket, &self.mutex, self.pin, buffer))
+    }
+
+    /// Write from bytes.
+    #[inline]
+    pub fn write_bytes(&self, bytes: &[u8]) -> Result<(), OneWireBusModuleError> {
+        block_on(service::write_bytes(&self.socket, &self.mutex, self.pin, bytes))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "_ffi-blocking"))]
+#[uniffi::export]
+impl OneWireBus {
+    /// Read into buffer. Returns buffer.
+    #[inline]
+    pub fn read_bytes(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, OneWireBusModuleError> {
+        block_on(service::read_bytes(
+            &self.socket,
+            &self.mutex,
+            self.pin,
+            buffer.as_mut_slice(),
+        ))
+        .map(|_| buffer)
+    }
+
+    /// Write from bytes.
+    #[inline]
+    pub fn write_bytes(&self, bytes: Vec<u8>) -> Result<(), OneWireBusModuleError> {
+        block_on(service::write_bytes(
+            &self.socket,
+            &self.mutex,
+            self.pin,
+            bytes.as_slice(),
+        ))
+    }
+}
+
+#[cfg(not(feature = "_ffi-async"))]
+impl OneWireBus {
+    /// Read into buffer.
+    #[inline]
+    pub async fn read_bytes_async(&self, buffer: &mut [u8]) -> Result<(), OneWireBusModuleError> {
+        service::read_bytes(&self.socket, &self.mutex, self.pin, buffer).await
+    }
+
+    /// Write from bytes.
+    #[inline]
+    pub async fn write_bytes_async(&self, bytes: &[u8]) -> Result<(), OneWireBusModuleError> {
+        service::write_bytes(&self.socket, &self.mutex, self.pin, bytes).await
+    }
+}
+
+#[cfg(feature = "_ffi-async")]
+#[uniffi::export]
+impl OneWireBus {
+    /// Read into buffer. Returns buffer.
+    #[inline]
+    pub async fn read_bytes_async(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, OneWireBusModuleError> {
+        service::read_bytes(&self.socket, &self.mutex, self.pin, buffer.as_mut_slice())
+            .await
+            .map(|_| buffer)
+    }
+
+    /// Write from bytes.
+    #[inline]
+    pub async fn write_bytes_async(&self, bytes: Vec<u8>) -> Result<(), OneWireBusModuleError> {
+        service::write_bytes(&self.socket, &self.mutex, self.pin, bytes.as_slice()).await
+    }
+}
+
+impl Drop for OneWireBus {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin = self.pin;
+
+        block_on(async move { _ = service::drop(&socket, pin).await })
+    }
+}
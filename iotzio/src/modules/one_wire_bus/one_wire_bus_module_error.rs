@@ -0,0 +1,39 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// OneWire bus module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum OneWireBusModuleError {
+    /// No device answered the reset pulse with a presence pulse.
+    #[error("No device answered the reset pulse with a presence pulse.")]
+    NoPresencePulse,
+
+    /// Data received from the device has an invalid CRC.
+    #[error("Data received from device {rom} has an invalid CRC.")]
+    CrcMismatch { rom: u64 },
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for OneWireBusModuleError {
+    fn from(value: ModuleError) -> Self {
+        OneWireBusModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for OneWireBusModuleError {
+    fn from(value: FatalError) -> Self {
+        OneWireBusModuleError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OneWireSearchState {
+    pub rom: u64,
+    pub last_discrepancy: u8,
+}
@@ -0,0 +1,348 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::one_wire_bus::{Ds18b20Reading, OneWireBus, OneWireBusModuleError};
+use crate::peripherals::gpio::GpioPin;
+use crate::peripherals::{BusBuffer, BUS_BUFFER_SIZE};
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+
+const MATCH_ROM: u8 = 0x55;
+
+const SKIP_ROM: u8 = 0xCC;
+
+const DS18B20_FAMILY_CODE: u8 = 0x28;
+
+const DS18B20_CONVERT_T: u8 = 0x44;
+
+const DS18B20_READ_SCRATCHPAD: u8 = 0xBE;
+
+const DS18B20_CONVERSION_TIME: Duration = Duration::from_millis(750);
+
+pub async fn new(socket: &Arc<Socket>, pin: GpioPin) -> Result<OneWireBus, OneWireBusModuleError> {
+    let command = Command::OneWire_New { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OneWire_New { result } => result,
+        _ => Err(OneWireBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(OneWireBus {
+        socket: socket.clone(),
+        mutex: Mutex::new(()),
+        pin,
+    })
+}
+
+pub async fn drop(socket: &Socket, pin: GpioPin) -> Result<(), OneWireBusModuleError> {
+    let command = Command::OneWire_Drop { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OneWire_Drop { result } => result,
+        _ => Err(OneWireBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn reset(socket: &Socket, mutex: &Mutex<()>, pin: GpioPin) -> Result<bool, OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    reset_inner(socket, pin).await
+}
+
+#[inline]
+pub async fn read_bit(socket: &Socket, mutex: &Mutex<()>, pin: GpioPin) -> Result<bool, OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    let command = Command::OneWire_ReadBit { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OneWire_ReadBit { result } => result,
+        _ => Err(OneWireBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn write_bit(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    bit: bool,
+) -> Result<(), OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    let command = Command::OneWire_WriteBit { pin, bit };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OneWire_WriteBit { result } => result,
+        _ => Err(OneWireBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn read_byte(socket: &Socket, mutex: &Mutex<()>, pin: GpioPin) -> Result<u8, OneWireBusModuleError> {
+    let mut buffer = [0u8; 1];
+
+    read_bytes(socket, mutex, pin, &mut buffer).await.map(|_| buffer[0])
+}
+
+#[inline]
+pub async fn write_byte(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    byte: u8,
+) -> Result<(), OneWireBusModuleError> {
+    write_bytes(socket, mutex, pin, &[byte]).await
+}
+
+#[inline]
+pub async fn read_bytes(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    buffer: &mut [u8],
+) -> Result<(), OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    read_bytes_inner(socket, pin, buffer).await
+}
+
+#[inline]
+pub async fn write_bytes(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    bytes: &[u8],
+) -> Result<(), OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    write_bytes_inner(socket, pin, bytes).await
+}
+
+#[inline]
+pub async fn match_rom(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    rom: u64,
+) -> Result<(), OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    match_rom_inner(socket, pin, rom).await
+}
+
+#[inline]
+pub async fn skip_rom(socket: &Socket, mutex: &Mutex<()>, pin: GpioPin) -> Result<(), OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    write_bytes_inner(socket, pin, &[SKIP_ROM]).await
+}
+
+#[inline]
+pub async fn search(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+    alarm_only: bool,
+) -> Result<Vec<u64>, OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    search_inner(socket, pin, alarm_only).await
+}
+
+pub async fn read_ds18b20_temperatures(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    pin: GpioPin,
+) -> Result<Vec<Ds18b20Reading>, OneWireBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    let roms: Vec<u64> = search_inner(socket, pin, false)
+        .await?
+        .into_iter()
+        .filter(|x| (*x as u8) == DS18B20_FAMILY_CODE)
+        .collect();
+
+    if roms.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Start conversion on all sensors at once, then wait for the slowest (12 bit) conversion time.
+
+    if !reset_inner(socket, pin).await? {
+        return Err(OneWireBusModuleError::NoPresencePulse);
+    }
+
+    write_bytes_inner(socket, pin, &[SKIP_ROM, DS18B20_CONVERT_T]).await?;
+
+    async_std::task::sleep(DS18B20_CONVERSION_TIME).await;
+
+    let mut readings = Vec::with_capacity(roms.len());
+
+    for rom in roms {
+        if !reset_inner(socket, pin).await? {
+            return Err(OneWireBusModuleError::NoPresencePulse);
+        }
+
+        match_rom_inner(socket, pin, rom).await?;
+
+        write_bytes_inner(socket, pin, &[DS18B20_READ_SCRATCHPAD]).await?;
+
+        let mut scratchpad = [0u8; 9];
+
+        read_bytes_inner(socket, pin, &mut scratchpad).await?;
+
+        if crc8(&scratchpad[..8]) != scratchpad[8] {
+            return Err(OneWireBusModuleError::CrcMismatch { rom });
+        }
+
+        readings.push(Ds18b20Reading {
+            rom,
+            temperature_celsius: i16::from_le_bytes([scratchpad[0], scratchpad[1]]) as f32 / 16.0,
+        });
+    }
+
+    Ok(readings)
+}
+
+async fn reset_inner(socket: &Socket, pin: GpioPin) -> Result<bool, OneWireBusModuleError> {
+    let command = Command::OneWire_Reset { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OneWire_Reset { result } => result,
+        _ => Err(OneWireBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+async fn read_bytes_inner(socket: &Socket, pin: GpioPin, buffer: &mut [u8]) -> Result<(), OneWireBusModuleError> {
+    for chunk in buffer.chunks_mut(BUS_BUFFER_SIZE) {
+        let command = Command::OneWire_ReadBytes {
+            pin,
+            buffer_size: chunk.len() as u16,
+        };
+
+        let response = socket.send(command).await??;
+
+        let bus_buffer = match response {
+            Response::OneWire_ReadBytes { result } => result,
+            _ => Err(OneWireBusModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+
+        for (to, from) in chunk.iter_mut().zip(bus_buffer) {
+            *to = from;
+        }
+    }
+
+    Ok(())
+}
+
+async fn write_bytes_inner(socket: &Socket, pin: GpioPin, bytes: &[u8]) -> Result<(), OneWireBusModuleError> {
+    for chunk in bytes.chunks(BUS_BUFFER_SIZE) {
+        let command = Command::OneWire_WriteBytes {
+            pin,
+            bytes: BusBuffer::from_slice(chunk).unwrap(),
+        };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::OneWire_WriteBytes { result } => result,
+            _ => Err(OneWireBusModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+    }
+
+    Ok(())
+}
+
+async fn match_rom_inner(socket: &Socket, pin: GpioPin, rom: u64) -> Result<(), OneWireBusModuleError> {
+    let mut bytes = [0u8; 9];
+
+    bytes[0] = MATCH_ROM;
+    bytes[1..].copy_from_slice(&rom.to_le_bytes());
+
+    write_bytes_inner(socket, pin, &bytes).await
+}
+
+async fn search_inner(socket: &Socket, pin: GpioPin, alarm_only: bool) -> Result<Vec<u64>, OneWireBusModuleError> {
+    // Every search command finds one device. The device continues after the last discrepancy of the
+    // previous search state. A last discrepancy of zero means that there are no more devices to find.
+
+    let mut roms = Vec::new();
+    let mut previous = None;
+
+    loop {
+        let command = Command::OneWire_Search {
+            pin,
+            alarm_only,
+            previous,
+        };
+
+        let response = socket.send(command).await??;
+
+        let state = match response {
+            Response::OneWire_Search { result } => result,
+            _ => Err(OneWireBusModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+
+        match state {
+            None => break,
+            Some(x) => {
+                roms.push(x.rom);
+
+                if x.last_discrepancy == 0 {
+                    break;
+                }
+
+                previous = Some(x);
+            }
+        }
+    }
+
+    Ok(roms)
+}
+
+#[inline]
+fn crc8(bytes: &[u8]) -> u8 {
+    // Dallas/Maxim CRC8, polynomial x^8 + x^5 + x^4 + 1.
+
+    bytes.iter().fold(0u8, |crc, byte| {
+        (0..8)
+            .fold((crc, *byte), |(crc, byte), _| {
+                let mix = (crc ^ byte) & 0x01;
+                let crc = match mix {
+                    0 => crc >> 1,
+                    _ => (crc >> 1) ^ 0x8C,
+                };
+
+                (crc, byte >> 1)
+            })
+            .0
+    })
+}
@@ -0,0 +1,14 @@
+mod output_port_module_error;
+mod output_port_pin;
+
+pub use self::output_port_module_error::*;
+pub use self::output_port_pin::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod output_port;
+
+        pub use self::output_port::*;
+    }
+}
@@ -0,0 +1,95 @@
+use crate::modules::output_port::{service, OutputPortModuleError, OutputPortPin};
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::{Arc, Mutex};
+
+/// Represents a group of output pins on the Iotzio device that change their levels at once.
+/// Bit n of a port value belongs to the nth pin the port was set up with.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct OutputPort {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) pins: Vec<OutputPortPin>,
+    pub(crate) pin_mask: u32,
+    pub(crate) value: Mutex<u32>,
+}
+
+impl OutputPort {
+    #[inline]
+    pub(crate) async fn new(
+        socket: &Arc<Socket>,
+        pins: Vec<OutputPortPin>,
+    ) -> Result<OutputPort, OutputPortModuleError> {
+        service::new(socket, pins).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl OutputPort {
+    /// Returns used pins in port order.
+    #[inline]
+    pub fn get_pins(&self) -> Vec<OutputPortPin> {
+        self.pins.clone()
+    }
+
+    /// Returns current port value.
+    #[inline]
+    pub fn get_value(&self) -> u32 {
+        *self.value.lock().unwrap()
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl OutputPort {
+    /// Sets the levels of all pins of the port at once. Bits beyond the port's pin count are ignored.
+    #[inline]
+    pub async fn set_value_async(&self, value: u32) -> Result<(), OutputPortModuleError> {
+        service::set_masked(&self.socket, &self.value, &self.pins, self.pin_mask, u32::MAX, value).await
+    }
+
+    /// Sets the levels of the pins selected by mask at once. All other pins keep their level.
+    #[inline]
+    pub async fn set_masked_async(&self, mask: u32, value: u32) -> Result<(), OutputPortModuleError> {
+        service::set_masked(&self.socket, &self.value, &self.pins, self.pin_mask, mask, value).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl OutputPort {
+    /// Sets the levels of all pins of the port at once. Bits beyond the port's pin count are ignored.
+    #[inline]
+    pub fn set_value(&self, value: u32) -> Result<(), OutputPortModuleError> {
+        block_on(service::set_masked(
+            &self.socket,
+            &self.value,
+            &self.pins,
+            self.pin_mask,
+            u32::MAX,
+            value,
+        ))
+    }
+
+    /// Sets the levels of the pins selected by mask at once. All other pins keep their level.
+    #[inline]
+    pub fn set_masked(&self, mask: u32, value: u32) -> Result<(), OutputPortModuleError> {
+        block_on(service::set_masked(
+            &self.socket,
+            &self.value,
+            &self.pins,
+            self.pin_mask,
+            mask,
+            value,
+        ))
+    }
+}
+
+impl Drop for OutputPort {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin_mask = self.pin_mask;
+
+        block_on(async move { _ = service::drop(&socket, pin_mask).await })
+    }
+}
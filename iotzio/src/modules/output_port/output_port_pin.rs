@@ -0,0 +1,17 @@
+use crate::peripherals::gpio::{Drive, GpioPin, Level, SlewRate};
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a single pin within an output port.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OutputPortPin {
+    /// Pin to use.
+    pub pin: GpioPin,
+    /// Level of the pin after setup.
+    pub initial_level: Level,
+    /// Drive strength of the pin.
+    pub drive_strength: Drive,
+    /// Slew rate of the pin.
+    pub slew_rate: SlewRate,
+}
@@ -0,0 +1,117 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::output_port::{OutputPort, OutputPortModuleError, OutputPortPin};
+use crate::peripherals::gpio::{Level, GPIO_PIN_COUNT};
+use crate::socket::Socket;
+use std::ops::DerefMut;
+use std::sync::{Arc, Mutex};
+
+pub async fn new(socket: &Arc<Socket>, pins: Vec<OutputPortPin>) -> Result<OutputPort, OutputPortModuleError> {
+    if pins.is_empty() {
+        return Err(OutputPortModuleError::NoPins);
+    }
+
+    let mut pin_mask = 0u32;
+
+    for x in pins.iter() {
+        let bit = gpio_bit(x);
+
+        if pin_mask & bit != 0 {
+            return Err(OutputPortModuleError::DuplicatePin { pin: x.pin });
+        }
+
+        pin_mask |= bit;
+    }
+
+    let value = pins
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| x.initial_level == Level::High)
+        .fold(0u32, |value, (index, _)| value | (1 << index));
+
+    // Pins are unique at this point, so they always fit.
+
+    let command = Command::OutputPort_New {
+        pins: heapless::Vec::<OutputPortPin, GPIO_PIN_COUNT>::from_slice(&pins).unwrap(),
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OutputPort_New { result } => result,
+        _ => Err(OutputPortModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(OutputPort {
+        socket: socket.clone(),
+        pins,
+        pin_mask,
+        value: value.into(),
+    })
+}
+
+pub async fn drop(socket: &Socket, pin_mask: u32) -> Result<(), OutputPortModuleError> {
+    let command = Command::OutputPort_Drop { pin_mask };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::OutputPort_Drop { result } => result,
+        _ => Err(OutputPortModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn set_masked(
+    socket: &Socket,
+    value_mutex: &Mutex<u32>,
+    pins: &[OutputPortPin],
+    pin_mask: u32,
+    mask: u32,
+    value: u32,
+) -> Result<(), OutputPortModuleError> {
+    let mask = mask & port_mask(pins);
+
+    {
+        let command = Command::OutputPort_SetLevels {
+            pin_mask,
+            mask: to_gpio_mask(pins, mask),
+            value: to_gpio_mask(pins, value & mask),
+        };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::OutputPort_SetLevels { result } => result,
+            _ => Err(OutputPortModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }
+    }
+    .map(|_| {
+        let mut current = value_mutex.lock().unwrap();
+
+        *current.deref_mut() = (*current & !mask) | (value & mask);
+    })
+}
+
+#[inline]
+fn gpio_bit(pin: &OutputPortPin) -> u32 {
+    1 << u8::from(pin.pin)
+}
+
+#[inline]
+fn port_mask(pins: &[OutputPortPin]) -> u32 {
+    (1 << pins.len()) - 1
+}
+
+/// Translate a port value (bit n is the nth pin of the port) into a mask of GPIO numbers.
+#[inline]
+fn to_gpio_mask(pins: &[OutputPortPin], value: u32) -> u32 {
+    pins.iter()
+        .enumerate()
+        .filter(|(index, _)| value & (1 << index) != 0)
+        .fold(0u32, |mask, (_, x)| mask | gpio_bit(x))
+}
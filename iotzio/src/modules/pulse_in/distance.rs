@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Speed of sound in dry air at 20 °C.
+pub const SPEED_OF_SOUND_METERS_PER_SECOND: f64 = 343.0;
+
+/// Distance measured by an ultrasonic rangefinder.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Distance {
+    /// Distance in meters.
+    pub meters: f64,
+}
+
+impl Distance {
+    /// Converts the width of an echo pulse into the distance of the reflecting object. The sound travels the distance twice.
+    #[inline]
+    pub fn from_echo_time(echo_time: Duration, speed_of_sound_meters_per_second: f64) -> Distance {
+        Distance {
+            meters: echo_time.as_secs_f64() * speed_of_sound_meters_per_second / 2.0,
+        }
+    }
+
+    /// Distance in centimeters.
+    #[inline]
+    pub fn centimeters(&self) -> f64 {
+        self.meters * 100.0
+    }
+
+    /// Distance in millimeters.
+    #[inline]
+    pub fn millimeters(&self) -> f64 {
+        self.meters * 1000.0
+    }
+}
@@ -0,0 +1,16 @@
+mod pulse_in_config;
+mod pulse_in_module_error;
+
+pub use self::pulse_in_config::*;
+pub use self::pulse_in_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod distance;
+        mod service;
+        mod pulse_in;
+
+        pub use self::distance::*;
+        pub use self::pulse_in::*;
+    }
+}
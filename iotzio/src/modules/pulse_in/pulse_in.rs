@@ -0,0 +1,88 @@
+use crate::modules::pulse_in::{service, Distance, PulseInConfig, PulseInModuleError};
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Represents a triggered pulse measurement on the Iotzio device, e.g. for HC-SR04 ultrasonic rangefinders.
+/// The device emits the trigger pulse and times the following echo pulse in one go, so that USB latency does not
+/// affect the result. An echo pulse already in progress when triggering is not measured.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct PulseIn {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) mutex: Mutex<()>,
+    pub(crate) config: PulseInConfig,
+}
+
+impl PulseIn {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, config: PulseInConfig) -> Result<PulseIn, PulseInModuleError> {
+        service::new(socket, config).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl PulseIn {
+    /// Returns the configuration of the pulse measurement.
+    #[inline]
+    pub fn get_config(&self) -> PulseInConfig {
+        self.config
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl PulseIn {
+    /// Emits the trigger pulse and returns the width of the echo pulse. Fails with Timeout if the echo pulse did not end
+    /// within the timeout, counted from the trigger pulse.
+    #[inline]
+    pub async fn measure_async(&self, timeout: Duration) -> Result<Duration, PulseInModuleError> {
+        service::measure(&self.socket, &self.mutex, self.config.trigger_pin, timeout).await
+    }
+
+    /// Emits the trigger pulse and converts the width of the echo pulse into a distance, assuming the speed of sound in
+    /// dry air at 20 °C. Fails with Timeout if the echo pulse did not end within the timeout, counted from the trigger pulse.
+    #[inline]
+    pub async fn measure_distance_async(&self, timeout: Duration) -> Result<Distance, PulseInModuleError> {
+        service::measure_distance(&self.socket, &self.mutex, self.config.trigger_pin, timeout).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl PulseIn {
+    /// Emits the trigger pulse and returns the width of the echo pulse. Fails with Timeout if the echo pulse did not end
+    /// within the timeout, counted from the trigger pulse.
+    #[inline]
+    pub fn measure(&self, timeout: Duration) -> Result<Duration, PulseInModuleError> {
+        block_on(service::measure(
+            &self.socket,
+            &self.mutex,
+            self.config.trigger_pin,
+            timeout,
+        ))
+    }
+
+    /// Emits the trigger pulse and converts the width of the echo pulse into a distance, assuming the speed of sound in
+    /// dry air at 20 °C. Fails with Timeout if the echo pulse did not end within the timeout, counted from the trigger pulse.
+    #[inline]
+    pub fn measure_distance(&self, timeout: Duration) -> Result<Distance, PulseInModuleError> {
+        block_on(service::measure_distance(
+            &self.socket,
+            &self.mutex,
+            self.config.trigger_pin,
+            timeout,
+        ))
+    }
+}
+
+impl Drop for PulseIn {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let trigger_pin = self.config.trigger_pin;
+
+        block_on(async move { _ = service::drop(&socket, trigger_pin).await })
+    }
+}
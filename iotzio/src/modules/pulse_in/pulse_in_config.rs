@@ -0,0 +1,45 @@
+use crate::peripherals::gpio::{GpioPin, Level, Pull};
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::time::Duration;
+    }
+    else {
+        use core::time::Duration;
+    }
+}
+
+/// Configuration of a triggered pulse measurement.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PulseInConfig {
+    /// Pin emitting the trigger pulse.
+    pub trigger_pin: GpioPin,
+    /// Level of the trigger pulse. The trigger pin rests at the opposite level.
+    pub trigger_level: Level,
+    /// Width of the trigger pulse.
+    pub trigger_pulse_width: Duration,
+    /// Pin receiving the echo pulse.
+    pub echo_pin: GpioPin,
+    /// Level of the echo pulse.
+    pub echo_level: Level,
+    /// Pull setting of the echo pin.
+    pub echo_pull_setting: Pull,
+}
+
+impl PulseInConfig {
+    /// Configuration for HC-SR04 style ultrasonic rangefinders: a 10 µs high trigger pulse, answered by a high echo pulse.
+    #[inline]
+    pub const fn hc_sr04(trigger_pin: GpioPin, echo_pin: GpioPin) -> PulseInConfig {
+        PulseInConfig {
+            trigger_pin,
+            trigger_level: Level::High,
+            trigger_pulse_width: Duration::from_micros(10),
+            echo_pin,
+            echo_level: Level::High,
+            echo_pull_setting: Pull::None,
+        }
+    }
+}
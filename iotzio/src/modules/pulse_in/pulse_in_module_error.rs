@@ -0,0 +1,40 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use crate::peripherals::gpio::GpioPin;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Pulse in module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PulseInModuleError {
+    /// Pin is used more than once by the pulse measurement.
+    #[error("Pin {pin} is used more than once by the pulse measurement.")]
+    DuplicatePin { pin: GpioPin },
+
+    /// No complete echo pulse was received within the timeout.
+    #[error("No complete echo pulse was received within the timeout.")]
+    Timeout,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for PulseInModuleError {
+    fn from(value: ModuleError) -> Self {
+        PulseInModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for PulseInModuleError {
+    fn from(value: FatalError) -> Self {
+        PulseInModuleError::FatalErrorWrapper { error: value }
+    }
+}
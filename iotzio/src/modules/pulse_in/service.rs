@@ -0,0 +1,78 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::pulse_in::{
+    Distance, PulseIn, PulseInConfig, PulseInModuleError, SPEED_OF_SOUND_METERS_PER_SECOND,
+};
+use crate::peripherals::gpio::GpioPin;
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub async fn new(socket: &Arc<Socket>, config: PulseInConfig) -> Result<PulseIn, PulseInModuleError> {
+    if config.echo_pin == config.trigger_pin {
+        return Err(PulseInModuleError::DuplicatePin { pin: config.echo_pin });
+    }
+
+    let command = Command::PulseIn_New { config };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::PulseIn_New { result } => result,
+        _ => Err(PulseInModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(PulseIn {
+        socket: socket.clone(),
+        mutex: Mutex::new(()),
+        config,
+    })
+}
+
+pub async fn drop(socket: &Socket, trigger_pin: GpioPin) -> Result<(), PulseInModuleError> {
+    let command = Command::PulseIn_Drop { trigger_pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::PulseIn_Drop { result } => result,
+        _ => Err(PulseInModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn measure(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    trigger_pin: GpioPin,
+    timeout: Duration,
+) -> Result<Duration, PulseInModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    // Trigger and echo are handled by a single command, so that USB latency does not delay the echo measurement.
+
+    let command = Command::PulseIn_Measure { trigger_pin, timeout };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::PulseIn_Measure { result } => result,
+        _ => Err(PulseInModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn measure_distance(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    trigger_pin: GpioPin,
+    timeout: Duration,
+) -> Result<Distance, PulseInModuleError> {
+    measure(socket, mutex, trigger_pin, timeout)
+        .await
+        .map(|x| Distance::from_echo_time(x, SPEED_OF_SOUND_METERS_PER_SECOND))
+}
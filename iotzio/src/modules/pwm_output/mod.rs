@@ -0,0 +1,12 @@
+mod pwm_output_module_error;
+
+pub use self::pwm_output_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod pwm_output;
+
+        pub use self::pwm_output::*;
+    }
+}
@@ -0,0 +1,146 @@
+use crate::modules::pwm_output::{service, PwmOutputModuleError};
+use crate::peripherals::gpio::GpioPin;
+use crate::peripherals::pwm::PwmPolarity;
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::{Arc, Mutex};
+
+/// Represents a PWM output on the Iotzio device.
+/// Two neighbouring pins (e.g. Pin 0 and Pin 1, Pin 2 and Pin 3, ...) share the same PWM slice and therefore the same frequency.
+/// The duty cycle ranges from 0 (always off) to 65535 (always on), independent of the chosen frequency.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct PwmOutput {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) pin: GpioPin,
+    pub(crate) frequency_hz: Mutex<u32>,
+    pub(crate) duty_cycle: Mutex<u16>,
+    pub(crate) polarity: Mutex<PwmPolarity>,
+}
+
+impl PwmOutput {
+    #[inline]
+    pub(crate) async fn new(
+        socket: &Arc<Socket>,
+        pin: GpioPin,
+        frequency_hz: u32,
+        duty_cycle: u16,
+        polarity: PwmPolarity,
+    ) -> Result<PwmOutput, PwmOutputModuleError> {
+        service::new(socket, pin, frequency_hz, duty_cycle, polarity).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl PwmOutput {
+    /// Returns used pin.
+    #[inline]
+    pub fn get_pin(&self) -> GpioPin {
+        self.pin
+    }
+
+    /// Returns current frequency in Hertz.
+    #[inline]
+    pub fn get_frequency_hz(&self) -> u32 {
+        *self.frequency_hz.lock().unwrap()
+    }
+
+    /// Returns current duty cycle.
+    #[inline]
+    pub fn get_duty_cycle(&self) -> u16 {
+        *self.duty_cycle.lock().unwrap()
+    }
+
+    /// Returns the maximum duty cycle value, which corresponds to 100%.
+    #[inline]
+    pub fn get_max_duty_cycle(&self) -> u16 {
+        u16::MAX
+    }
+
+    /// Returns current polarity.
+    #[inline]
+    pub fn get_polarity(&self) -> PwmPolarity {
+        *self.polarity.lock().unwrap()
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl PwmOutput {
+    /// Sets current duty cycle.
+    #[inline]
+    pub async fn set_duty_cycle_async(&self, duty_cycle: u16) -> Result<(), PwmOutputModuleError> {
+        service::set_duty_cycle(&self.socket, &self.duty_cycle, self.pin, duty_cycle).await
+    }
+
+    /// Sets current frequency in Hertz. Fails with PeripheralBlockedByAnotherModule if the PWM slice is shared with another module.
+    #[inline]
+    pub async fn set_frequency_async(&self, frequency_hz: u32) -> Result<(), PwmOutputModuleError> {
+        service::set_frequency(&self.socket, &self.frequency_hz, self.pin, frequency_hz).await
+    }
+
+    /// Sets current polarity.
+    #[inline]
+    pub async fn set_polarity_async(&self, polarity: PwmPolarity) -> Result<(), PwmOutputModuleError> {
+        service::set_polarity(&self.socket, &self.polarity, self.pin, polarity).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl PwmOutput {
+    /// Sets current duty cycle.
+    #[inline]
+    pub fn set_duty_cycle(&self, duty_cycle: u16) -> Result<(), PwmOutputModuleError> {
+        block_on(service::set_duty_cycle(
+            &self.socket,
+            &self.duty_cycle,
+            self.pin,
+            duty_cycle,
+        ))
+    }
+
+    /// Sets current frequency in Hertz. Fails with PeripheralBlockedByAnotherModule if the PWM slice is shared with another module.
+    #[inline]
+    pub fn set_frequency(&self, frequency_hz: u32) -> Result<(), PwmOutputModuleError> {
+        block_on(service::set_frequency(
+            &self.socket,
+            &self.frequency_hz,
+            self.pin,
+            frequency_hz,
+        ))
+    }
+
+    /// Sets current polarity.
+    #[inline]
+    pub fn set_polarity(&self, polarity: PwmPolarity) -> Result<(), PwmOutputModuleError> {
+        block_on(service::set_polarity(&self.socket, &self.polarity, self.pin, polarity))
+    }
+}
+
+impl Drop for PwmOutput {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin = self.pin;
+
+        block_on(async move { _ = service::drop(&socket, pin).await })
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::pwm::ErrorType for PwmOutput {
+    type Error = PwmOutputModuleError;
+}
+
+#[cfg(all(feature = "embedded-hal", not(target_family = "wasm")))]
+impl embedded_hal::pwm::SetDutyCycle for PwmOutput {
+    #[inline]
+    fn max_duty_cycle(&self) -> u16 {
+        u16::MAX
+    }
+
+    #[inline]
+    fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Self::Error> {
+        block_on(service::set_duty_cycle(&self.socket, &self.duty_cycle, self.pin, duty))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// PWM output module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PwmOutputModuleError {
+    /// Requested PWM frequency is too high.
+    #[error("Requested PWM frequency is too high.")]
+    FrequencyTooHigh,
+
+    /// Requested PWM frequency is too low.
+    #[error("Requested PWM frequency is too low.")]
+    FrequencyTooLow,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for PwmOutputModuleError {
+    fn from(value: ModuleError) -> Self {
+        PwmOutputModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for PwmOutputModuleError {
+    fn from(value: FatalError) -> Self {
+        PwmOutputModuleError::FatalErrorWrapper { error: value }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::pwm::Error for PwmOutputModuleError {
+    fn kind(&self) -> embedded_hal::pwm::ErrorKind {
+        embedded_hal::pwm::ErrorKind::Other
+    }
+}
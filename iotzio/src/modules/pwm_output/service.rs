@@ -0,0 +1,121 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::pwm_output::{PwmOutput, PwmOutputModuleError};
+use crate::peripherals::gpio::GpioPin;
+use crate::peripherals::pwm::PwmPolarity;
+use crate::socket::Socket;
+use std::ops::DerefMut;
+use std::sync::{Arc, Mutex};
+
+pub async fn new(
+    socket: &Arc<Socket>,
+    pin: GpioPin,
+    frequency_hz: u32,
+    duty_cycle: u16,
+    polarity: PwmPolarity,
+) -> Result<PwmOutput, PwmOutputModuleError> {
+    let command = Command::PwmOutput_New {
+        pin,
+        frequency_hz,
+        duty_cycle,
+        polarity,
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::PwmOutput_New { result } => result,
+        _ => Err(PwmOutputModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(PwmOutput {
+        socket: socket.clone(),
+        pin,
+        frequency_hz: frequency_hz.into(),
+        duty_cycle: duty_cycle.into(),
+        polarity: polarity.into(),
+    })
+}
+
+pub async fn drop(socket: &Socket, pin: GpioPin) -> Result<(), PwmOutputModuleError> {
+    let command = Command::PwmOutput_Drop { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::PwmOutput_Drop { result } => result,
+        _ => Err(PwmOutputModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn set_duty_cycle(
+    socket: &Socket,
+    duty_cycle_mutex: &Mutex<u16>,
+    pin: GpioPin,
+    duty_cycle: u16,
+) -> Result<(), PwmOutputModuleError> {
+    {
+        let command = Command::PwmOutput_SetDutyCycle { pin, duty_cycle };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::PwmOutput_SetDutyCycle { result } => result,
+            _ => Err(PwmOutputModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }
+    }
+    .map(|_| {
+        *duty_cycle_mutex.lock().unwrap().deref_mut() = duty_cycle;
+    })
+}
+
+pub async fn set_frequency(
+    socket: &Socket,
+    frequency_mutex: &Mutex<u32>,
+    pin: GpioPin,
+    frequency_hz: u32,
+) -> Result<(), PwmOutputModuleError> {
+    {
+        let command = Command::PwmOutput_SetFrequency { pin, frequency_hz };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::PwmOutput_SetFrequency { result } => result,
+            _ => Err(PwmOutputModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }
+    }
+    .map(|_| {
+        *frequency_mutex.lock().unwrap().deref_mut() = frequency_hz;
+    })
+}
+
+pub async fn set_polarity(
+    socket: &Socket,
+    polarity_mutex: &Mutex<PwmPolarity>,
+    pin: GpioPin,
+    polarity: PwmPolarity,
+) -> Result<(), PwmOutputModuleError> {
+    {
+        let command = Command::PwmOutput_SetPolarity { pin, polarity };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::PwmOutput_SetPolarity { result } => result,
+            _ => Err(PwmOutputModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }
+    }
+    .map(|_| {
+        *polarity_mutex.lock().unwrap().deref_mut() = polarity;
+    })
+}
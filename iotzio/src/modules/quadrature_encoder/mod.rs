@@ -0,0 +1,17 @@
+mod quadrature_encoder_module_error;
+mod quadrature_encoder_reading;
+
+pub use self::quadrature_encoder_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod quadrature_encoder;
+
+        pub use self::quadrature_encoder::*;
+        pub(crate) use self::quadrature_encoder_reading::*;
+    }
+    else {
+        pub use self::quadrature_encoder_reading::*;
+    }
+}
@@ -0,0 +1,129 @@
+use crate::modules::quadrature_encoder::{service, QuadratureEncoderModuleError};
+use crate::peripherals::gpio::{GpioPin, Pull};
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Represents a quadrature encoder on the Iotzio device. Both signals are decoded on the device, so that no step is
+/// missed between two readings. The position advances by four counts per encoder cycle and increases while signal A
+/// leads signal B.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct QuadratureEncoder {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) pin_a: GpioPin,
+    pub(crate) pin_b: GpioPin,
+    pub(crate) index_pin: Option<GpioPin>,
+    pub(crate) pull_setting: Pull,
+    pub(crate) velocity_sample: Mutex<(i64, Duration, u32)>,
+}
+
+impl QuadratureEncoder {
+    #[inline]
+    pub(crate) async fn new(
+        socket: &Arc<Socket>,
+        pin_a: GpioPin,
+        pin_b: GpioPin,
+        index_pin: Option<GpioPin>,
+        pull_setting: Pull,
+    ) -> Result<QuadratureEncoder, QuadratureEncoderModuleError> {
+        service::new(socket, pin_a, pin_b, index_pin, pull_setting).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl QuadratureEncoder {
+    /// Returns the pin of signal A.
+    #[inline]
+    pub fn get_pin_a(&self) -> GpioPin {
+        self.pin_a
+    }
+
+    /// Returns the pin of signal B.
+    #[inline]
+    pub fn get_pin_b(&self) -> GpioPin {
+        self.pin_b
+    }
+
+    /// Returns the index pin, if used.
+    #[inline]
+    pub fn get_index_pin(&self) -> Option<GpioPin> {
+        self.index_pin
+    }
+
+    /// Returns the pull setting of all used pins.
+    #[inline]
+    pub fn get_pull_setting(&self) -> Pull {
+        self.pull_setting
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl QuadratureEncoder {
+    /// Returns the current position in counts.
+    #[inline]
+    pub async fn get_position_async(&self) -> Result<i64, QuadratureEncoderModuleError> {
+        service::get_position(&self.socket, self.pin_a).await
+    }
+
+    /// Returns the position latched at the most recent rising edge of the index pin. Returns none without an index pin
+    /// or before the first index pulse.
+    #[inline]
+    pub async fn get_index_position_async(&self) -> Result<Option<i64>, QuadratureEncoderModuleError> {
+        service::get_index_position(&self.socket, self.pin_a).await
+    }
+
+    /// Returns the average velocity in counts per second since the previous velocity reading, setup or reset.
+    /// After a resilient Iotzio board reconnected, the encoder restarts at zero and the first velocity reading returns zero.
+    #[inline]
+    pub async fn get_velocity_async(&self) -> Result<f64, QuadratureEncoderModuleError> {
+        service::get_velocity(&self.socket, &self.velocity_sample, self.pin_a).await
+    }
+
+    /// Resets the position to zero and forgets the latched index position.
+    #[inline]
+    pub async fn reset_async(&self) -> Result<(), QuadratureEncoderModuleError> {
+        service::reset(&self.socket, &self.velocity_sample, self.pin_a).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl QuadratureEncoder {
+    /// Returns the current position in counts.
+    #[inline]
+    pub fn get_position(&self) -> Result<i64, QuadratureEncoderModuleError> {
+        block_on(service::get_position(&self.socket, self.pin_a))
+    }
+
+    /// Returns the position latched at the most recent rising edge of the index pin. Returns none without an index pin
+    /// or before the first index pulse.
+    #[inline]
+    pub fn get_index_position(&self) -> Result<Option<i64>, QuadratureEncoderModuleError> {
+        block_on(service::get_index_position(&self.socket, self.pin_a))
+    }
+
+    /// Returns the average velocity in counts per second since the previous velocity reading, setup or reset.
+    /// After a resilient Iotzio board reconnected, the encoder restarts at zero and the first velocity reading returns zero.
+    #[inline]
+    pub fn get_velocity(&self) -> Result<f64, QuadratureEncoderModuleError> {
+        block_on(service::get_velocity(&self.socket, &self.velocity_sample, self.pin_a))
+    }
+
+    /// Resets the position to zero and forgets the latched index position.
+    #[inline]
+    pub fn reset(&self) -> Result<(), QuadratureEncoderModuleError> {
+        block_on(service::reset(&self.socket, &self.velocity_sample, self.pin_a))
+    }
+}
+
+impl Drop for QuadratureEncoder {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin_a = self.pin_a;
+
+        block_on(async move { _ = service::drop(&socket, pin_a).await })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use crate::peripherals::gpio::GpioPin;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Quadrature encoder module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum QuadratureEncoderModuleError {
+    /// Pin B must be the pin directly following pin A.
+    #[error("Pin B must be the pin directly following pin A.")]
+    PinsNotAdjacent,
+
+    /// Pin is used more than once by the quadrature encoder.
+    #[error("Pin {pin} is used more than once by the quadrature encoder.")]
+    DuplicatePin { pin: GpioPin },
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for QuadratureEncoderModuleError {
+    fn from(value: ModuleError) -> Self {
+        QuadratureEncoderModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for QuadratureEncoderModuleError {
+    fn from(value: FatalError) -> Self {
+        QuadratureEncoderModuleError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::time::Duration;
+    }
+    else {
+        use core::time::Duration;
+    }
+}
+
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct QuadratureEncoderReading {
+    /// Position in counts, four counts per encoder cycle.
+    pub position: i64,
+    /// Position latched at the most recent rising edge of the index pin.
+    pub index_position: Option<i64>,
+    /// Device uptime at the time of reading.
+    pub timestamp: Duration,
+}
@@ -0,0 +1,144 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::quadrature_encoder::{QuadratureEncoder, QuadratureEncoderModuleError, QuadratureEncoderReading};
+use crate::peripherals::gpio::{GpioPin, Pull};
+use crate::socket::Socket;
+use std::ops::DerefMut;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub async fn new(
+    socket: &Arc<Socket>,
+    pin_a: GpioPin,
+    pin_b: GpioPin,
+    index_pin: Option<GpioPin>,
+    pull_setting: Pull,
+) -> Result<QuadratureEncoder, QuadratureEncoderModuleError> {
+    // The device decodes both signals with a single PIO state machine, which samples two consecutive pins.
+
+    if u8::from(pin_b) != u8::from(pin_a) + 1 {
+        return Err(QuadratureEncoderModuleError::PinsNotAdjacent);
+    }
+
+    if let Some(pin) = index_pin.filter(|x| *x == pin_a || *x == pin_b) {
+        return Err(QuadratureEncoderModuleError::DuplicatePin { pin });
+    }
+
+    let command = Command::QuadratureEncoder_New {
+        pin_a,
+        pin_b,
+        index_pin,
+        pull_setting,
+    };
+
+    let response = socket.send(command).await??;
+
+    let timestamp = match response {
+        Response::QuadratureEncoder_New { result } => result,
+        _ => Err(QuadratureEncoderModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(QuadratureEncoder {
+        socket: socket.clone(),
+        pin_a,
+        pin_b,
+        index_pin,
+        pull_setting,
+        velocity_sample: (0, timestamp, get_reconnect_count(socket)).into(),
+    })
+}
+
+pub async fn drop(socket: &Socket, pin_a: GpioPin) -> Result<(), QuadratureEncoderModuleError> {
+    let command = Command::QuadratureEncoder_Drop { pin_a };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::QuadratureEncoder_Drop { result } => result,
+        _ => Err(QuadratureEncoderModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn get_position(socket: &Socket, pin_a: GpioPin) -> Result<i64, QuadratureEncoderModuleError> {
+    read(socket, pin_a).await.map(|x| x.position)
+}
+
+pub async fn get_index_position(socket: &Socket, pin_a: GpioPin) -> Result<Option<i64>, QuadratureEncoderModuleError> {
+    read(socket, pin_a).await.map(|x| x.index_position)
+}
+
+pub async fn get_velocity(
+    socket: &Socket,
+    velocity_sample: &Mutex<(i64, Duration, u32)>,
+    pin_a: GpioPin,
+) -> Result<f64, QuadratureEncoderModuleError> {
+    let reading = read(socket, pin_a).await?;
+
+    let reconnect_count = get_reconnect_count(socket);
+
+    let mut sample = velocity_sample.lock().unwrap();
+
+    let (position, timestamp, sample_reconnect_count) = *sample;
+
+    *sample.deref_mut() = (reading.position, reading.timestamp, reconnect_count);
+
+    // The encoder was set up again since the previous sample, so its position and timestamp are not comparable.
+
+    if sample_reconnect_count != reconnect_count {
+        return Ok(0.0);
+    }
+
+    // Both timestamps are taken by the device, so USB latency does not distort the result.
+
+    match reading.timestamp.checked_sub(timestamp).filter(|x| !x.is_zero()) {
+        Some(elapsed) => Ok((reading.position - position) as f64 / elapsed.as_secs_f64()),
+        None => Ok(0.0),
+    }
+}
+
+pub async fn reset(
+    socket: &Socket,
+    velocity_sample: &Mutex<(i64, Duration, u32)>,
+    pin_a: GpioPin,
+) -> Result<(), QuadratureEncoderModuleError> {
+    let command = Command::QuadratureEncoder_Reset { pin_a };
+
+    let response = socket.send(command).await??;
+
+    let timestamp = match response {
+        Response::QuadratureEncoder_Reset { result } => result,
+        _ => Err(QuadratureEncoderModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    *velocity_sample.lock().unwrap().deref_mut() = (0, timestamp, get_reconnect_count(socket));
+
+    Ok(())
+}
+
+#[inline]
+async fn read(socket: &Socket, pin_a: GpioPin) -> Result<QuadratureEncoderReading, QuadratureEncoderModuleError> {
+    let command = Command::QuadratureEncoder_Read { pin_a };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::QuadratureEncoder_Read { result } => result,
+        _ => Err(QuadratureEncoderModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+fn get_reconnect_count(socket: &Socket) -> u32 {
+    socket
+        .reconnect_state
+        .as_ref()
+        .map_or(0, |x| x.reconnect_count.load(Ordering::Acquire))
+}
@@ -0,0 +1,14 @@
+mod servo_config;
+mod servo_module_error;
+
+pub use self::servo_config::*;
+pub use self::servo_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod servo;
+
+        pub use self::servo::*;
+    }
+}
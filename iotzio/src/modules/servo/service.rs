@@ -0,0 +1,165 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::servo::{Servo, ServoConfig, ServoModuleError};
+use crate::peripherals::gpio::GpioPin;
+use crate::socket::Socket;
+use std::ops::DerefMut;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub async fn new(socket: &Arc<Socket>, pin: GpioPin, config: ServoConfig) -> Result<Servo, ServoModuleError> {
+    if config.frequency_hz == 0 {
+        return Err(ServoModuleError::FrequencyTooLow);
+    }
+
+    if config.max_angle_deg == 0 {
+        return Err(ServoModuleError::InvalidMaxAngle);
+    }
+
+    let period = Duration::from_secs(1) / config.frequency_hz;
+
+    if config.min_pulse_width >= config.max_pulse_width || config.max_pulse_width >= period {
+        return Err(ServoModuleError::InvalidPulseWidthRange);
+    }
+
+    // No pulses are emitted until the first position is set, so that the servo does not jump on setup.
+
+    let command = Command::Servo_New {
+        pin,
+        config,
+        pulse_width: None,
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Servo_New { result } => result,
+        _ => Err(ServoModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(Servo {
+        socket: socket.clone(),
+        pin,
+        config,
+        pulse_width: None.into(),
+    })
+}
+
+pub async fn drop(socket: &Socket, pin: GpioPin) -> Result<(), ServoModuleError> {
+    let command = Command::Servo_Drop { pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Servo_Drop { result } => result,
+        _ => Err(ServoModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn set_pulse_width(
+    socket: &Socket,
+    pulse_width_mutex: &Mutex<Option<Duration>>,
+    pin: GpioPin,
+    config: &ServoConfig,
+    pulse_width: Duration,
+) -> Result<(), ServoModuleError> {
+    if pulse_width < config.min_pulse_width || pulse_width > config.max_pulse_width {
+        return Err(ServoModuleError::PulseWidthOutOfRange);
+    }
+
+    let command = Command::Servo_SetPulseWidth { pin, pulse_width };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Servo_SetPulseWidth { result } => result,
+        _ => Err(ServoModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    *pulse_width_mutex.lock().unwrap().deref_mut() = Some(pulse_width);
+
+    Ok(())
+}
+
+pub async fn set_angle(
+    socket: &Socket,
+    pulse_width_mutex: &Mutex<Option<Duration>>,
+    pin: GpioPin,
+    config: &ServoConfig,
+    angle_deg: f32,
+) -> Result<(), ServoModuleError> {
+    let pulse_width = angle_to_pulse_width(config, angle_deg)?;
+
+    set_pulse_width(socket, pulse_width_mutex, pin, config, pulse_width).await
+}
+
+pub async fn sweep_to_angle(
+    socket: &Socket,
+    pulse_width_mutex: &Mutex<Option<Duration>>,
+    pin: GpioPin,
+    config: &ServoConfig,
+    angle_deg: f32,
+    speed_deg_per_second: f32,
+) -> Result<(), ServoModuleError> {
+    let pulse_width = angle_to_pulse_width(config, angle_deg)?;
+
+    if !speed_deg_per_second.is_finite() || speed_deg_per_second <= 0.0 {
+        return Err(ServoModuleError::InvalidSweepSpeed);
+    }
+
+    // The device moves the pulse width by a fixed amount every period, so the speed is passed as pulse width change per second.
+
+    let pulse_width_per_second = Duration::try_from_secs_f64(
+        pulse_width_range(config).as_secs_f64() * speed_deg_per_second as f64 / config.max_angle_deg as f64,
+    )
+    .unwrap_or(Duration::MAX)
+    .max(Duration::from_nanos(1));
+
+    let command = Command::Servo_Sweep {
+        pin,
+        pulse_width,
+        pulse_width_per_second,
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Servo_Sweep { result } => result,
+        _ => Err(ServoModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    *pulse_width_mutex.lock().unwrap().deref_mut() = Some(pulse_width);
+
+    Ok(())
+}
+
+pub fn pulse_width_to_angle(config: &ServoConfig, pulse_width: Duration) -> f32 {
+    let offset = pulse_width.saturating_sub(config.min_pulse_width);
+
+    (offset.as_secs_f64() / pulse_width_range(config).as_secs_f64() * config.max_angle_deg as f64) as f32
+}
+
+#[inline]
+fn angle_to_pulse_width(config: &ServoConfig, angle_deg: f32) -> Result<Duration, ServoModuleError> {
+    if !(0.0..=config.max_angle_deg as f32).contains(&angle_deg) {
+        return Err(ServoModuleError::AngleOutOfRange {
+            max_angle_deg: config.max_angle_deg,
+        });
+    }
+
+    let offset = pulse_width_range(config).mul_f64(angle_deg as f64 / config.max_angle_deg as f64);
+
+    Ok(config.min_pulse_width + offset)
+}
+
+#[inline]
+fn pulse_width_range(config: &ServoConfig) -> Duration {
+    config.max_pulse_width - config.min_pulse_width
+}
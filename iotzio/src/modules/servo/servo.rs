@@ -0,0 +1,143 @@
+use crate::modules::servo::{service, ServoConfig, ServoModuleError};
+use crate::peripherals::gpio::GpioPin;
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Represents a hobby servo driven by PWM on the Iotzio device. Angles are mapped linearly onto the configured pulse
+/// width range. No pulses are emitted until the first position is set.
+/// Two neighbouring pins (e.g. Pin 0 and Pin 1, Pin 2 and Pin 3, ...) share the same PWM slice and therefore the same frequency.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct Servo {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) pin: GpioPin,
+    pub(crate) config: ServoConfig,
+    pub(crate) pulse_width: Mutex<Option<Duration>>,
+}
+
+impl Servo {
+    #[inline]
+    pub(crate) async fn new(
+        socket: &Arc<Socket>,
+        pin: GpioPin,
+        config: ServoConfig,
+    ) -> Result<Servo, ServoModuleError> {
+        service::new(socket, pin, config).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl Servo {
+    /// Returns used pin.
+    #[inline]
+    pub fn get_pin(&self) -> GpioPin {
+        self.pin
+    }
+
+    /// Returns the configuration of the servo.
+    #[inline]
+    pub fn get_config(&self) -> ServoConfig {
+        self.config
+    }
+
+    /// Returns the most recently set pulse width. Returns none if no position was set yet.
+    #[inline]
+    pub fn get_pulse_width(&self) -> Option<Duration> {
+        *self.pulse_width.lock().unwrap()
+    }
+
+    /// Returns the most recently set angle in degrees. Returns none if no position was set yet.
+    #[inline]
+    pub fn get_angle(&self) -> Option<f32> {
+        self.get_pulse_width()
+            .map(|x| service::pulse_width_to_angle(&self.config, x))
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl Servo {
+    /// Sets the pulse width, which must be within the configured range. Interrupts a running sweep.
+    #[inline]
+    pub async fn set_pulse_width_async(&self, pulse_width: Duration) -> Result<(), ServoModuleError> {
+        service::set_pulse_width(&self.socket, &self.pulse_width, self.pin, &self.config, pulse_width).await
+    }
+
+    /// Sets the angle in degrees, which must be between 0 and the configured maximum angle. Interrupts a running sweep.
+    #[inline]
+    pub async fn set_angle_async(&self, angle_deg: f32) -> Result<(), ServoModuleError> {
+        service::set_angle(&self.socket, &self.pulse_width, self.pin, &self.config, angle_deg).await
+    }
+
+    /// Moves to the given angle in degrees with limited speed. The movement is timed by the device and completes once the angle is reached.
+    /// Without a previously set position, the angle is set immediately.
+    #[inline]
+    pub async fn sweep_to_angle_async(
+        &self,
+        angle_deg: f32,
+        speed_deg_per_second: f32,
+    ) -> Result<(), ServoModuleError> {
+        service::sweep_to_angle(
+            &self.socket,
+            &self.pulse_width,
+            self.pin,
+            &self.config,
+            angle_deg,
+            speed_deg_per_second,
+        )
+        .await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl Servo {
+    /// Sets the pulse width, which must be within the configured range. Interrupts a running sweep.
+    #[inline]
+    pub fn set_pulse_width(&self, pulse_width: Duration) -> Result<(), ServoModuleError> {
+        block_on(service::set_pulse_width(
+            &self.socket,
+            &self.pulse_width,
+            self.pin,
+            &self.config,
+            pulse_width,
+        ))
+    }
+
+    /// Sets the angle in degrees, which must be between 0 and the configured maximum angle. Interrupts a running sweep.
+    #[inline]
+    pub fn set_angle(&self, angle_deg: f32) -> Result<(), ServoModuleError> {
+        block_on(service::set_angle(
+            &self.socket,
+            &self.pulse_width,
+            self.pin,
+            &self.config,
+            angle_deg,
+        ))
+    }
+
+    /// Moves to the given angle in degrees with limited speed. The movement is timed by the device and completes once the angle is reached.
+    /// Without a previously set position, the angle is set immediately.
+    #[inline]
+    pub fn sweep_to_angle(&self, angle_deg: f32, speed_deg_per_second: f32) -> Result<(), ServoModuleError> {
+        block_on(service::sweep_to_angle(
+            &self.socket,
+            &self.pulse_width,
+            self.pin,
+            &self.config,
+            angle_deg,
+            speed_deg_per_second,
+        ))
+    }
+}
+
+impl Drop for Servo {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let pin = self.pin;
+
+        block_on(async move { _ = service::drop(&socket, pin).await })
+    }
+}
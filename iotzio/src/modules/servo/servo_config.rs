@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::time::Duration;
+    }
+    else {
+        use core::time::Duration;
+    }
+}
+
+/// Configuration of a servo. The default fits most hobby servos: 50 Hz, 1 ms to 2 ms pulses for 0 to 180 degrees.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ServoConfig {
+    /// Frequency of the control pulses in Hertz.
+    pub frequency_hz: u32,
+    /// Pulse width at 0 degrees.
+    pub min_pulse_width: Duration,
+    /// Pulse width at the maximum angle.
+    pub max_pulse_width: Duration,
+    /// Maximum angle in degrees.
+    pub max_angle_deg: u16,
+}
+
+impl Default for ServoConfig {
+    fn default() -> Self {
+        ServoConfig {
+            frequency_hz: 50,
+            min_pulse_width: Duration::from_micros(1000),
+            max_pulse_width: Duration::from_micros(2000),
+            max_angle_deg: 180,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Servo module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ServoModuleError {
+    /// Requested PWM frequency is too high.
+    #[error("Requested PWM frequency is too high.")]
+    FrequencyTooHigh,
+
+    /// Requested PWM frequency is too low.
+    #[error("Requested PWM frequency is too low.")]
+    FrequencyTooLow,
+
+    /// Minimum pulse width must be shorter than maximum pulse width, which must fit into the PWM period.
+    #[error("Minimum pulse width must be shorter than maximum pulse width, which must fit into the PWM period.")]
+    InvalidPulseWidthRange,
+
+    /// Maximum angle must be greater than zero.
+    #[error("Maximum angle must be greater than zero.")]
+    InvalidMaxAngle,
+
+    /// Pulse width is outside the configured range.
+    #[error("Pulse width is outside the configured range.")]
+    PulseWidthOutOfRange,
+
+    /// Angle is outside the configured range.
+    #[error("Angle must be between 0 and {max_angle_deg} degrees.")]
+    AngleOutOfRange { max_angle_deg: u16 },
+
+    /// Sweep speed must be greater than zero.
+    #[error("Sweep speed must be greater than zero.")]
+    InvalidSweepSpeed,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for ServoModuleError {
+    fn from(value: ModuleError) -> Self {
+        ServoModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for ServoModuleError {
+    fn from(value: FatalError) -> Self {
+        ServoModuleError::FatalErrorWrapper { error: value }
+    }
+}
@@ -0,0 +1,26 @@
+mod spi0_miso_pin;
+mod spi0_mosi_pin;
+mod spi0_sck_pin;
+mod spi1_miso_pin;
+mod spi1_mosi_pin;
+mod spi1_sck_pin;
+mod spi_bus_module_error;
+mod spi_config;
+
+pub use self::spi0_miso_pin::*;
+pub use self::spi0_mosi_pin::*;
+pub use self::spi0_sck_pin::*;
+pub use self::spi1_miso_pin::*;
+pub use self::spi1_mosi_pin::*;
+pub use self::spi1_sck_pin::*;
+pub use self::spi_bus_module_error::*;
+pub use self::spi_config::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod spi_bus;
+
+        pub use self::spi_bus::*;
+    }
+}
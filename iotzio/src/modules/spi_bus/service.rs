@@ -0,0 +1,469 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::spi_bus::{SpiBus, SpiBusModuleError, SpiConfig};
+use crate::peripherals::spi::SpiBusNumber;
+use crate::peripherals::{BusBuffer, BUS_BUFFER_SIZE};
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::Arc;
+
+pub async fn new(socket: &Arc<Socket>, config: SpiConfig) -> Result<SpiBus, SpiBusModuleError> {
+    let identifier = match &config {
+        SpiConfig::Spi0 { .. } => SpiBusNumber::Spi0,
+        SpiConfig::Spi1 { .. } => SpiBusNumber::Spi1,
+    };
+
+    let command = Command::Spi_New { config };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Spi_New { result } => result,
+        _ => Err(SpiBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(SpiBus {
+        socket: socket.clone(),
+        mutex: Mutex::new(()),
+        bus_number: identifier,
+    })
+}
+
+pub async fn drop(socket: &Socket, identifier: SpiBusNumber) -> Result<(), SpiBusModuleError> {
+    let command = Command::Spi_Drop { identifier };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Spi_Drop { result } => result,
+        _ => Err(SpiBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+pub async fn read(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+) -> Result<(), SpiBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    read_inner(socket, identifier, buffer).await
+}
+
+#[inline]
+pub async fn write(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    identifier: SpiBusNumber,
+    bytes: &[u8],
+) -> Result<(), SpiBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    write_inner(socket, identifier, bytes).await
+}
+
+#[inline]
+pub async fn transfer(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+    bytes: &[u8],
+) -> Result<(), SpiBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    transfer_inner(socket, identifier, buffer, bytes).await
+}
+
+#[inline]
+pub async fn transfer_in_place(
+    socket: &Arc<Socket>,
+    mutex: &Mutex<()>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+) -> Result<(), SpiBusModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    transfer_in_place_inner(socket, identifier, buffer).await
+}
+
+async fn read_inner(
+    socket: &Arc<Socket>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+) -> Result<(), SpiBusModuleError> {
+    if buffer.len() <= BUS_BUFFER_SIZE {
+        let command = Command::Spi_ReadSingle {
+            identifier,
+            buffer_size: buffer.len() as u16,
+        };
+
+        let response = socket.send(command).await??;
+
+        let bus_buffer = match response {
+            Response::Spi_ReadSingle { result } => result,
+            _ => Err(SpiBusModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+
+        for (to, from) in buffer.iter_mut().zip(bus_buffer) {
+            *to = from;
+        }
+
+        Ok(())
+    } else {
+        let chunks = buffer.chunks_mut(BUS_BUFFER_SIZE).enumerate();
+        let chunks_count = chunks.len();
+
+        {
+            let command = Command::Spi_StartReadChunked {
+                identifier,
+                chunks_count: chunks_count as u32,
+            };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::Spi_StartReadChunked { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+
+        let chunked_auto_closeable = ChunkedAutoCloseable::new(socket, identifier, ChunkedModeType::Read);
+
+        for (chunk_index, chunk) in chunks {
+            let command = Command::Spi_ReadChunk {
+                identifier,
+                buffer_size: chunk.len() as u16,
+                chunk_index: chunk_index as u32,
+            };
+
+            let response = socket.send(command).await??;
+
+            let bus_buffer = match response {
+                Response::Spi_ReadChunk { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+
+            for (to, from) in chunk.iter_mut().zip(bus_buffer) {
+                *to = from;
+            }
+        }
+
+        chunked_auto_closeable.drop_async().await?;
+
+        Ok(())
+    }
+}
+
+async fn write_inner(socket: &Arc<Socket>, identifier: SpiBusNumber, bytes: &[u8]) -> Result<(), SpiBusModuleError> {
+    if bytes.len() <= BUS_BUFFER_SIZE {
+        let command = Command::Spi_WriteSingle {
+            identifier,
+            bytes: BusBuffer::from_slice(bytes).unwrap(),
+        };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::Spi_WriteSingle { result } => result,
+            _ => Err(SpiBusModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+
+        Ok(())
+    } else {
+        let chunks = bytes.chunks(BUS_BUFFER_SIZE).enumerate();
+        let chunks_count = chunks.len();
+
+        {
+            let command = Command::Spi_StartWriteChunked {
+                identifier,
+                chunks_count: chunks_count as u32,
+            };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::Spi_StartWriteChunked { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+
+        let chunked_auto_closeable = ChunkedAutoCloseable::new(socket, identifier, ChunkedModeType::Write);
+
+        for (chunk_index, chunk) in chunks {
+            let command = Command::Spi_WriteChunk {
+                identifier,
+                bytes: BusBuffer::from_slice(chunk).unwrap(),
+                chunk_index: chunk_index as u32,
+            };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::Spi_WriteChunk { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+
+        chunked_auto_closeable.drop_async().await?;
+
+        Ok(())
+    }
+}
+
+async fn transfer_inner(
+    socket: &Arc<Socket>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+    bytes: &[u8],
+) -> Result<(), SpiBusModuleError> {
+    // The device clocks max(bytes, buffer) words. Missing write bytes are sent as 0x00,
+    // surplus read bytes are discarded, as required by embedded-hal.
+
+    let length = buffer.len().max(bytes.len());
+
+    if length <= BUS_BUFFER_SIZE {
+        transfer_single(socket, identifier, buffer, bytes).await
+    } else {
+        let chunks_count = length.div_ceil(BUS_BUFFER_SIZE);
+
+        start_transfer_chunked(socket, identifier, chunks_count).await?;
+
+        let chunked_auto_closeable = ChunkedAutoCloseable::new(socket, identifier, ChunkedModeType::Transfer);
+
+        for chunk_index in 0..chunks_count {
+            let start = chunk_index * BUS_BUFFER_SIZE;
+
+            let write_range = start.min(bytes.len())..(start + BUS_BUFFER_SIZE).min(bytes.len());
+            let read_range = start.min(buffer.len())..(start + BUS_BUFFER_SIZE).min(buffer.len());
+
+            transfer_chunk(
+                socket,
+                identifier,
+                &mut buffer[read_range],
+                &bytes[write_range],
+                chunk_index,
+            )
+            .await?;
+        }
+
+        chunked_auto_closeable.drop_async().await?;
+
+        Ok(())
+    }
+}
+
+async fn transfer_in_place_inner(
+    socket: &Arc<Socket>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+) -> Result<(), SpiBusModuleError> {
+    if buffer.len() <= BUS_BUFFER_SIZE {
+        let bytes = BusBuffer::from_slice(buffer).unwrap();
+
+        transfer_single(socket, identifier, buffer, &bytes).await
+    } else {
+        let chunks = buffer.chunks_mut(BUS_BUFFER_SIZE).enumerate();
+
+        start_transfer_chunked(socket, identifier, chunks.len()).await?;
+
+        let chunked_auto_closeable = ChunkedAutoCloseable::new(socket, identifier, ChunkedModeType::Transfer);
+
+        for (chunk_index, chunk) in chunks {
+            let bytes = BusBuffer::from_slice(chunk).unwrap();
+
+            transfer_chunk(socket, identifier, chunk, &bytes, chunk_index).await?;
+        }
+
+        chunked_auto_closeable.drop_async().await?;
+
+        Ok(())
+    }
+}
+
+async fn transfer_single(
+    socket: &Arc<Socket>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+    bytes: &[u8],
+) -> Result<(), SpiBusModuleError> {
+    let command = Command::Spi_TransferSingle {
+        identifier,
+        bytes: BusBuffer::from_slice(bytes).unwrap(),
+        buffer_size: buffer.len() as u16,
+    };
+
+    let response = socket.send(command).await??;
+
+    let bus_buffer = match response {
+        Response::Spi_TransferSingle { result } => result,
+        _ => Err(SpiBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    for (to, from) in buffer.iter_mut().zip(bus_buffer) {
+        *to = from;
+    }
+
+    Ok(())
+}
+
+async fn start_transfer_chunked(
+    socket: &Arc<Socket>,
+    identifier: SpiBusNumber,
+    chunks_count: usize,
+) -> Result<(), SpiBusModuleError> {
+    let command = Command::Spi_StartTransferChunked {
+        identifier,
+        chunks_count: chunks_count as u32,
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Spi_StartTransferChunked { result } => result,
+        _ => Err(SpiBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+async fn transfer_chunk(
+    socket: &Arc<Socket>,
+    identifier: SpiBusNumber,
+    buffer: &mut [u8],
+    bytes: &[u8],
+    chunk_index: usize,
+) -> Result<(), SpiBusModuleError> {
+    let command = Command::Spi_TransferChunk {
+        identifier,
+        bytes: BusBuffer::from_slice(bytes).unwrap(),
+        buffer_size: buffer.len() as u16,
+        chunk_index: chunk_index as u32,
+    };
+
+    let response = socket.send(command).await??;
+
+    let bus_buffer = match response {
+        Response::Spi_TransferChunk { result } => result,
+        _ => Err(SpiBusModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    for (to, from) in buffer.iter_mut().zip(bus_buffer) {
+        *to = from;
+    }
+
+    Ok(())
+}
+
+#[derive(Copy, Clone)]
+enum ChunkedModeType {
+    Write,
+    Read,
+    Transfer,
+}
+
+struct ChunkedAutoCloseable {
+    socket: Option<Arc<Socket>>,
+    mode: ChunkedModeType,
+    bus: SpiBusNumber,
+}
+
+impl ChunkedAutoCloseable {
+    pub fn new(socket: &Arc<Socket>, identifier: SpiBusNumber, mode: ChunkedModeType) -> ChunkedAutoCloseable {
+        ChunkedAutoCloseable {
+            socket: Some(socket.clone()),
+            mode,
+            bus: identifier,
+        }
+    }
+
+    pub async fn drop_async(mut self) -> Result<(), SpiBusModuleError> {
+        match self.socket.take() {
+            None => Ok(()),
+            Some(socket) => drop_async_inner(socket, self.mode, self.bus).await,
+        }
+    }
+}
+
+impl Drop for ChunkedAutoCloseable {
+    fn drop(&mut self) {
+        match self.socket.take() {
+            None => {}
+            Some(socket) => {
+                let mode = self.mode;
+                let bus = self.bus;
+
+                block_on(async move { _ = drop_async_inner(socket, mode, bus).await })
+            }
+        }
+    }
+}
+
+async fn drop_async_inner(
+    socket: Arc<Socket>,
+    mode: ChunkedModeType,
+    identifier: SpiBusNumber,
+) -> Result<(), SpiBusModuleError> {
+    match mode {
+        ChunkedModeType::Write => {
+            let command = Command::Spi_StopWriteChunked { identifier };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::Spi_StopWriteChunked { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+        ChunkedModeType::Read => {
+            let command = Command::Spi_StopReadChunked { identifier };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::Spi_StopReadChunked { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+        ChunkedModeType::Transfer => {
+            let command = Command::Spi_StopTransferChunked { identifier };
+
+            let response = socket.send(command).await??;
+
+            match response {
+                Response::Spi_StopTransferChunked { result } => result,
+                _ => Err(SpiBusModuleError::from(FatalError::from(
+                    ProtocolError::ReceivedWrongResponse,
+                ))),
+            }?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for MISO using SPI0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spi0MisoPin {
+    /// Pin 0
+    Pin0,
+    /// Pin 4
+    Pin4,
+    /// Pin 16
+    Pin16,
+    /// Pin 20
+    Pin20,
+}
+
+impl TryFrom<u8> for Spi0MisoPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Spi0MisoPin::Pin0),
+            4 => Ok(Spi0MisoPin::Pin4),
+            16 => Ok(Spi0MisoPin::Pin16),
+            20 => Ok(Spi0MisoPin::Pin20),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Spi0MisoPin> for u8 {
+    fn from(pin: Spi0MisoPin) -> u8 {
+        match pin {
+            Spi0MisoPin::Pin0 => 0,
+            Spi0MisoPin::Pin4 => 4,
+            Spi0MisoPin::Pin16 => 16,
+            Spi0MisoPin::Pin20 => 20,
+        }
+    }
+}
+
+impl From<Spi0MisoPin> for GpioPin {
+    fn from(pin: Spi0MisoPin) -> GpioPin {
+        match pin {
+            Spi0MisoPin::Pin0 => GpioPin::Pin0,
+            Spi0MisoPin::Pin4 => GpioPin::Pin4,
+            Spi0MisoPin::Pin16 => GpioPin::Pin16,
+            Spi0MisoPin::Pin20 => GpioPin::Pin20,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for MOSI using SPI0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spi0MosiPin {
+    /// Pin 3
+    Pin3,
+    /// Pin 7
+    Pin7,
+    /// Pin 19
+    Pin19,
+}
+
+impl TryFrom<u8> for Spi0MosiPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            3 => Ok(Spi0MosiPin::Pin3),
+            7 => Ok(Spi0MosiPin::Pin7),
+            19 => Ok(Spi0MosiPin::Pin19),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Spi0MosiPin> for u8 {
+    fn from(pin: Spi0MosiPin) -> u8 {
+        match pin {
+            Spi0MosiPin::Pin3 => 3,
+            Spi0MosiPin::Pin7 => 7,
+            Spi0MosiPin::Pin19 => 19,
+        }
+    }
+}
+
+impl From<Spi0MosiPin> for GpioPin {
+    fn from(pin: Spi0MosiPin) -> GpioPin {
+        match pin {
+            Spi0MosiPin::Pin3 => GpioPin::Pin3,
+            Spi0MosiPin::Pin7 => GpioPin::Pin7,
+            Spi0MosiPin::Pin19 => GpioPin::Pin19,
+        }
+    }
+}
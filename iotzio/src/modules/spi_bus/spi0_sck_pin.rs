@@ -0,0 +1,54 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for SCK using SPI0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spi0SckPin {
+    /// Pin 2
+    Pin2,
+    /// Pin 6
+    Pin6,
+    /// Pin 18
+    Pin18,
+    /// Pin 22
+    Pin22,
+}
+
+impl TryFrom<u8> for Spi0SckPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            2 => Ok(Spi0SckPin::Pin2),
+            6 => Ok(Spi0SckPin::Pin6),
+            18 => Ok(Spi0SckPin::Pin18),
+            22 => Ok(Spi0SckPin::Pin22),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Spi0SckPin> for u8 {
+    fn from(pin: Spi0SckPin) -> u8 {
+        match pin {
+            Spi0SckPin::Pin2 => 2,
+            Spi0SckPin::Pin6 => 6,
+            Spi0SckPin::Pin18 => 18,
+            Spi0SckPin::Pin22 => 22,
+        }
+    }
+}
+
+impl From<Spi0SckPin> for GpioPin {
+    fn from(pin: Spi0SckPin) -> GpioPin {
+        match pin {
+            Spi0SckPin::Pin2 => GpioPin::Pin2,
+            Spi0SckPin::Pin6 => GpioPin::Pin6,
+            Spi0SckPin::Pin18 => GpioPin::Pin18,
+            Spi0SckPin::Pin22 => GpioPin::Pin22,
+        }
+    }
+}
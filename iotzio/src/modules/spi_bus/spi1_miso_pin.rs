@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for MISO using SPI1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spi1MisoPin {
+    /// Pin 8
+    Pin8,
+    /// Pin 12
+    Pin12,
+    /// Pin 28
+    Pin28,
+}
+
+impl TryFrom<u8> for Spi1MisoPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            8 => Ok(Spi1MisoPin::Pin8),
+            12 => Ok(Spi1MisoPin::Pin12),
+            28 => Ok(Spi1MisoPin::Pin28),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Spi1MisoPin> for u8 {
+    fn from(pin: Spi1MisoPin) -> u8 {
+        match pin {
+            Spi1MisoPin::Pin8 => 8,
+            Spi1MisoPin::Pin12 => 12,
+            Spi1MisoPin::Pin28 => 28,
+        }
+    }
+}
+
+impl From<Spi1MisoPin> for GpioPin {
+    fn from(pin: Spi1MisoPin) -> GpioPin {
+        match pin {
+            Spi1MisoPin::Pin8 => GpioPin::Pin8,
+            Spi1MisoPin::Pin12 => GpioPin::Pin12,
+            Spi1MisoPin::Pin28 => GpioPin::Pin28,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for MOSI using SPI1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spi1MosiPin {
+    /// Pin 11
+    Pin11,
+    /// Pin 15
+    Pin15,
+    /// Pin 27
+    Pin27,
+}
+
+impl TryFrom<u8> for Spi1MosiPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            11 => Ok(Spi1MosiPin::Pin11),
+            15 => Ok(Spi1MosiPin::Pin15),
+            27 => Ok(Spi1MosiPin::Pin27),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Spi1MosiPin> for u8 {
+    fn from(pin: Spi1MosiPin) -> u8 {
+        match pin {
+            Spi1MosiPin::Pin11 => 11,
+            Spi1MosiPin::Pin15 => 15,
+            Spi1MosiPin::Pin27 => 27,
+        }
+    }
+}
+
+impl From<Spi1MosiPin> for GpioPin {
+    fn from(pin: Spi1MosiPin) -> GpioPin {
+        match pin {
+            Spi1MosiPin::Pin11 => GpioPin::Pin11,
+            Spi1MosiPin::Pin15 => GpioPin::Pin15,
+            Spi1MosiPin::Pin27 => GpioPin::Pin27,
+        }
+    }
+}
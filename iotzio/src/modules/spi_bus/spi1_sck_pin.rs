@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for SCK using SPI1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Spi1SckPin {
+    /// Pin 10
+    Pin10,
+    /// Pin 14
+    Pin14,
+    /// Pin 26
+    Pin26,
+}
+
+impl TryFrom<u8> for Spi1SckPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            10 => Ok(Spi1SckPin::Pin10),
+            14 => Ok(Spi1SckPin::Pin14),
+            26 => Ok(Spi1SckPin::Pin26),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Spi1SckPin> for u8 {
+    fn from(pin: Spi1SckPin) -> u8 {
+        match pin {
+            Spi1SckPin::Pin10 => 10,
+            Spi1SckPin::Pin14 => 14,
+            Spi1SckPin::Pin26 => 26,
+        }
+    }
+}
+
+impl From<Spi1SckPin> for GpioPin {
+    fn from(pin: Spi1SckPin) -> GpioPin {
+        match pin {
+            Spi1SckPin::Pin10 => GpioPin::Pin10,
+            Spi1SckPin::Pin14 => GpioPin::Pin14,
+            Spi1SckPin::Pin26 => GpioPin::Pin26,
+        }
+    }
+}
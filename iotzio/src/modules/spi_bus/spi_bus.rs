@@ -0,0 +1,274 @@
+use crate::modules::spi_bus::{service, SpiBusModuleError, SpiConfig};
+use crate::peripherals::spi::SpiBusNumber;
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::Arc;
+
+/// Represents an SPI bus on the Iotzio device.
+/// With this module you can communicate directly to bus participants. Chip select is not handled by this module, use an output pin for that.
+/// Use this also if you want to create specialized SPI bus dependent modules.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct SpiBus {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) mutex: Mutex<()>,
+    pub(crate) bus_number: SpiBusNumber,
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl SpiBus {
+    /// The SPI bus number this instance is using.
+    #[inline]
+    pub fn bus_number(&self) -> SpiBusNumber {
+        self.bus_number
+    }
+}
+
+impl SpiBus {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, config: SpiConfig) -> Result<SpiBus, SpiBusModuleError> {
+        service::new(socket, config).await
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
+impl SpiBus {
+    /// Read into buffer while sending 0x00 bytes.
+    #[inline]
+    pub fn read(&self, buffer: &mut [u8]) -> Result<(), SpiBusModuleError> {
+        block_on(service::read(&self.socket, &self.mutex, self.bus_number, buffer))
+    }
+
+    /// Write from bytes, discarding received bytes.
+    #[inline]
+    pub fn write(&self, bytes: &[u8]) -> Result<(), SpiBusModuleError> {
+        block_on(service::write(&self.socket, &self.mutex, self.bus_number, bytes))
+    }
+
+    /// Write from bytes and simultaneously read into buffer. If the lengths differ, missing bytes are sent as 0x00 and surplus received bytes are discarded.
+    #[inline]
+    pub fn transfer(&self, buffer: &mut [u8], bytes: &[u8]) -> Result<(), SpiBusModuleError> {
+        block_on(service::transfer(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            buffer,
+            bytes,
+        ))
+    }
+
+    /// Write from buffer and simultaneously read into the same buffer.
+    #[inline]
+    pub fn transfer_in_place(&self, buffer: &mut [u8]) -> Result<(), SpiBusModuleError> {
+        block_on(service::transfer_in_place(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            buffer,
+        ))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "_ffi-blocking"))]
+#[uniffi::export]
+impl SpiBus {
+    /// Read into buffer while sending 0x00 bytes. Returns buffer.
+    #[inline]
+    pub fn read(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, SpiBusModuleError> {
+        block_on(service::read(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            buffer.as_mut_slice(),
+        ))
+        .map(|_| buffer)
+    }
+
+    /// Write from bytes, discarding received bytes.
+    #[inline]
+    pub fn write(&self, bytes: Vec<u8>) -> Result<(), SpiBusModuleError> {
+        block_on(service::write(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            bytes.as_slice(),
+        ))
+    }
+
+    /// Write from bytes and simultaneously read into buffer. If the lengths differ, missing bytes are sent as 0x00 and surplus received bytes are discarded. Returns buffer.
+    #[inline]
+    pub fn transfer(&self, mut buffer: Vec<u8>, bytes: Vec<u8>) -> Result<Vec<u8>, SpiBusModuleError> {
+        block_on(service::transfer(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            buffer.as_mut_slice(),
+            bytes.as_slice(),
+        ))
+        .map(|_| buffer)
+    }
+
+    /// Write from buffer and simultaneously read into the same buffer. Returns buffer.
+    #[inline]
+    pub fn transfer_in_place(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, SpiBusModuleError> {
+        block_on(service::transfer_in_place(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            buffer.as_mut_slice(),
+        ))
+        .map(|_| buffer)
+    }
+}
+
+#[cfg(not(feature = "_ffi-async"))]
+impl SpiBus {
+    /// Read into buffer while sending 0x00 bytes.
+    #[inline]
+    pub async fn read_async(&self, buffer: &mut [u8]) -> Result<(), SpiBusModuleError> {
+        service::read(&self.socket, &self.mutex, self.bus_number, buffer).await
+    }
+
+    /// Write from bytes, discarding received bytes.
+    #[inline]
+    pub async fn write_async(&self, bytes: &[u8]) -> Result<(), SpiBusModuleError> {
+        service::write(&self.socket, &self.mutex, self.bus_number, bytes).await
+    }
+
+    /// Write from bytes and simultaneously read into buffer. If the lengths differ, missing bytes are sent as 0x00 and surplus received bytes are discarded.
+    #[inline]
+    pub async fn transfer_async(&self, buffer: &mut [u8], bytes: &[u8]) -> Result<(), SpiBusModuleError> {
+        service::transfer(&self.socket, &self.mutex, self.bus_number, buffer, bytes).await
+    }
+
+    /// Write from buffer and simultaneously read into the same buffer.
+    #[inline]
+    pub async fn transfer_in_place_async(&self, buffer: &mut [u8]) -> Result<(), SpiBusModuleError> {
+        service::transfer_in_place(&self.socket, &self.mutex, self.bus_number, buffer).await
+    }
+}
+
+#[cfg(feature = "_ffi-async")]
+#[uniffi::export]
+impl SpiBus {
+    /// Read into buffer while sending 0x00 bytes. Returns buffer.
+    #[inline]
+    pub async fn read_async(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, SpiBusModuleError> {
+        service::read(&self.socket, &self.mutex, self.bus_number, buffer.as_mut_slice())
+            .await
+            .map(|_| buffer)
+    }
+
+    /// Write from bytes, discarding received bytes.
+    #[inline]
+    pub async fn write_async(&self, bytes: Vec<u8>) -> Result<(), SpiBusModuleError> {
+        service::write(&self.socket, &self.mutex, self.bus_number, bytes.as_slice()).await
+    }
+
+    /// Write from bytes and simultaneously read into buffer. If the lengths differ, missing bytes are sent as 0x00 and surplus received bytes are discarded. Returns buffer.
+    #[inline]
+    pub async fn transfer_async(&self, mut buffer: Vec<u8>, bytes: Vec<u8>) -> Result<Vec<u8>, SpiBusModuleError> {
+        service::transfer(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            buffer.as_mut_slice(),
+            bytes.as_slice(),
+        )
+        .await
+        .map(|_| buffer)
+    }
+
+    /// Write from buffer and simultaneously read into the same buffer. Returns buffer.
+    #[inline]
+    pub async fn transfer_in_place_async(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, SpiBusModuleError> {
+        service::transfer_in_place(&self.socket, &self.mutex, self.bus_number, buffer.as_mut_slice())
+            .await
+            .map(|_| buffer)
+    }
+}
+
+impl Drop for SpiBus {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let bus = self.bus_number;
+
+        block_on(async move { _ = service::drop(&socket, bus).await })
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::spi::ErrorType for SpiBus {
+    type Error = SpiBusModuleError;
+}
+
+#[cfg(all(feature = "embedded-hal", not(target_family = "wasm")))]
+impl embedded_hal::spi::SpiBus for SpiBus {
+    #[inline]
+    fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        block_on(service::read(&self.socket, &self.mutex, self.bus_number, words))
+    }
+
+    #[inline]
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        block_on(service::write(&self.socket, &self.mutex, self.bus_number, words))
+    }
+
+    #[inline]
+    fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        block_on(service::transfer(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            read,
+            write,
+        ))
+    }
+
+    #[inline]
+    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        block_on(service::transfer_in_place(
+            &self.socket,
+            &self.mutex,
+            self.bus_number,
+            words,
+        ))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        // Every command completes on the device before its response is sent.
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal_async::spi::SpiBus for SpiBus {
+    #[inline]
+    async fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        service::read(&self.socket, &self.mutex, self.bus_number, words).await
+    }
+
+    #[inline]
+    async fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        service::write(&self.socket, &self.mutex, self.bus_number, words).await
+    }
+
+    #[inline]
+    async fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        service::transfer(&self.socket, &self.mutex, self.bus_number, read, write).await
+    }
+
+    #[inline]
+    async fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        service::transfer_in_place(&self.socket, &self.mutex, self.bus_number, words).await
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        // Every command completes on the device before its response is sent.
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// SPI bus module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpiBusModuleError {
+    /// Requested SPI bus frequency is too high.
+    #[error("Requested SPI bus frequency is too high.")]
+    FrequencyTooHigh,
+
+    /// Requested SPI bus frequency is too low.
+    #[error("Requested SPI bus frequency is too low.")]
+    FrequencyTooLow,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for SpiBusModuleError {
+    fn from(value: ModuleError) -> Self {
+        SpiBusModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for SpiBusModuleError {
+    fn from(value: FatalError) -> Self {
+        SpiBusModuleError::FatalErrorWrapper { error: value }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_hal::spi::Error for SpiBusModuleError {
+    fn kind(&self) -> embedded_hal::spi::ErrorKind {
+        embedded_hal::spi::ErrorKind::Other
+    }
+}
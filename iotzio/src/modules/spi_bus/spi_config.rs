@@ -0,0 +1,55 @@
+use crate::modules::spi_bus::{Spi0MisoPin, Spi0MosiPin, Spi0SckPin, Spi1MisoPin, Spi1MosiPin, Spi1SckPin};
+use crate::peripherals::spi::{SpiBitOrder, SpiMode};
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::fmt;
+    }
+    else {
+        use core::fmt;
+    }
+}
+
+/// SPI bus configuration.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpiConfig {
+    /// Use SPI bus 0
+    Spi0 {
+        /// SCK pin to use
+        sck: Spi0SckPin,
+        /// MOSI pin to use
+        mosi: Spi0MosiPin,
+        /// MISO pin to use
+        miso: Spi0MisoPin,
+        /// Clock polarity and phase to use.
+        mode: SpiMode,
+        /// Bit order to use.
+        bit_order: SpiBitOrder,
+        /// Frequency to want to use in Hertz. If no value is passed, 1 MHz is used.
+        requested_frequency_hz: Option<u32>,
+    },
+    /// Use SPI bus 1
+    Spi1 {
+        /// SCK pin to use
+        sck: Spi1SckPin,
+        /// MOSI pin to use
+        mosi: Spi1MosiPin,
+        /// MISO pin to use
+        miso: Spi1MisoPin,
+        /// Clock polarity and phase to use.
+        mode: SpiMode,
+        /// Bit order to use.
+        bit_order: SpiBitOrder,
+        /// Frequency to want to use in Hertz. If no value is passed, 1 MHz is used.
+        requested_frequency_hz: Option<u32>,
+    },
+}
+
+impl fmt::Display for SpiConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,19 @@
+mod stepper_config;
+mod stepper_module_error;
+mod stepper_status;
+
+pub use self::stepper_config::*;
+pub use self::stepper_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod stepper;
+
+        pub use self::stepper::*;
+        pub(crate) use self::stepper_status::*;
+    }
+    else {
+        pub use self::stepper_status::*;
+    }
+}
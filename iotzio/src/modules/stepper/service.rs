@@ -0,0 +1,205 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::stepper::{
+    Stepper, StepperConfig, StepperModuleError, StepperStatus, STEPPER_MAX_SPEED_STEPS_PER_SECOND,
+};
+use crate::peripherals::gpio::GpioPin;
+use crate::socket::Socket;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+pub async fn new(socket: &Arc<Socket>, config: StepperConfig) -> Result<Stepper, StepperModuleError> {
+    if config.dir_pin == config.step_pin {
+        return Err(StepperModuleError::DuplicatePin { pin: config.dir_pin });
+    }
+
+    if let Some(pin) = config
+        .enable_pin
+        .filter(|x| *x == config.step_pin || *x == config.dir_pin)
+    {
+        return Err(StepperModuleError::DuplicatePin { pin });
+    }
+
+    if !(1..=STEPPER_MAX_SPEED_STEPS_PER_SECOND).contains(&config.max_speed_steps_per_second) {
+        return Err(StepperModuleError::MaxSpeedOutOfRange {
+            max: STEPPER_MAX_SPEED_STEPS_PER_SECOND,
+        });
+    }
+
+    if config.acceleration_steps_per_second_squared == 0 {
+        return Err(StepperModuleError::InvalidAcceleration);
+    }
+
+    let command = Command::Stepper_New {
+        config,
+        position: 0,
+        enabled: true,
+    };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_New { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(Stepper {
+        socket: socket.clone(),
+        config,
+        enabled: AtomicBool::new(true),
+    })
+}
+
+pub async fn drop(socket: &Socket, step_pin: GpioPin) -> Result<(), StepperModuleError> {
+    let command = Command::Stepper_Drop { step_pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_Drop { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn move_to(socket: &Socket, step_pin: GpioPin, position: i64) -> Result<(), StepperModuleError> {
+    check_position_known(socket, step_pin)?;
+
+    let command = Command::Stepper_MoveTo { step_pin, position };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_MoveTo { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+    .map(|_| ())
+}
+
+pub async fn move_by(socket: &Socket, step_pin: GpioPin, steps: i64) -> Result<(), StepperModuleError> {
+    // Unlike an absolute move, a relative move does not depend on the position being known. It stays allowed after
+    // reconnecting, so that the stepper can be moved to its home position before the position is set again.
+
+    // The device adds the steps to its own target, so that a running move is extended instead of restarted.
+
+    let command = Command::Stepper_MoveBy { step_pin, steps };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_MoveBy { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+    .map(|_| ())
+}
+
+pub async fn stop(socket: &Socket, step_pin: GpioPin) -> Result<Option<i64>, StepperModuleError> {
+    let command = Command::Stepper_Stop { step_pin };
+
+    let response = socket.send(command).await??;
+
+    let position = match response {
+        Response::Stepper_Stop { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    // The stepper is stopped, even if its position is unknown.
+
+    Ok(check_position_known(socket, step_pin).ok().map(|_| position))
+}
+
+pub async fn emergency_stop(socket: &Socket, step_pin: GpioPin) -> Result<Option<i64>, StepperModuleError> {
+    let command = Command::Stepper_EmergencyStop { step_pin };
+
+    let response = socket.send(command).await??;
+
+    let position = match response {
+        Response::Stepper_EmergencyStop { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    // The stepper is stopped, even if its position is unknown.
+
+    Ok(check_position_known(socket, step_pin).ok().map(|_| position))
+}
+
+pub async fn get_position(socket: &Socket, step_pin: GpioPin) -> Result<i64, StepperModuleError> {
+    check_position_known(socket, step_pin)?;
+
+    get_status(socket, step_pin).await.map(|x| x.position)
+}
+
+pub async fn is_moving(socket: &Socket, step_pin: GpioPin) -> Result<bool, StepperModuleError> {
+    get_status(socket, step_pin).await.map(|x| x.moving)
+}
+
+pub async fn set_position(socket: &Socket, step_pin: GpioPin, position: i64) -> Result<(), StepperModuleError> {
+    let command = Command::Stepper_SetPosition { step_pin, position };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_SetPosition { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn set_enabled(
+    socket: &Socket,
+    enabled_state: &AtomicBool,
+    step_pin: GpioPin,
+    enabled: bool,
+) -> Result<(), StepperModuleError> {
+    let command = Command::Stepper_SetEnabled { step_pin, enabled };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_SetEnabled { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    enabled_state.store(enabled, Ordering::Relaxed);
+
+    Ok(())
+}
+
+#[inline]
+async fn get_status(socket: &Socket, step_pin: GpioPin) -> Result<StepperStatus, StepperModuleError> {
+    let command = Command::Stepper_GetStatus { step_pin };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Stepper_GetStatus { result } => result,
+        _ => Err(StepperModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+#[inline]
+fn check_position_known(socket: &Socket, step_pin: GpioPin) -> Result<(), StepperModuleError> {
+    match socket
+        .reconnect_state
+        .as_ref()
+        .is_some_and(|x| x.is_stepper_position_unknown(step_pin))
+    {
+        true => Err(StepperModuleError::PositionUnknown),
+        false => Ok(()),
+    }
+}
@@ -0,0 +1,166 @@
+use crate::modules::stepper::{service, StepperConfig, StepperModuleError};
+use crate::socket::Socket;
+use async_std::task::block_on;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Represents a stepper motor driver with STEP and DIR inputs on the Iotzio device. Step pulses are generated by the
+/// device, which accelerates and decelerates along a trapezoidal speed profile. Positions are counted in steps, starting
+/// at zero after setup. After a resilient Iotzio board reconnected, the position is unknown until it is set again.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct Stepper {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) config: StepperConfig,
+    pub(crate) enabled: AtomicBool,
+}
+
+impl Stepper {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, config: StepperConfig) -> Result<Stepper, StepperModuleError> {
+        service::new(socket, config).await
+    }
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl Stepper {
+    /// Returns the configuration of the stepper.
+    #[inline]
+    pub fn get_config(&self) -> StepperConfig {
+        self.config
+    }
+
+    /// Returns whether the driver is enabled.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg_attr(feature = "_ffi-async", uniffi::export)]
+impl Stepper {
+    /// Moves to the given position and completes once it is reached. A running move is redirected to the new target,
+    /// which interrupts its pending call. Fails while the position is unknown.
+    #[inline]
+    pub async fn move_to_async(&self, position: i64) -> Result<(), StepperModuleError> {
+        service::move_to(&self.socket, self.config.step_pin, position).await
+    }
+
+    /// Moves by the given number of steps relative to the current target and completes once the new target is reached.
+    /// A running move is extended, which interrupts its pending call. Also allowed while the position is unknown.
+    #[inline]
+    pub async fn move_by_async(&self, steps: i64) -> Result<(), StepperModuleError> {
+        service::move_by(&self.socket, self.config.step_pin, steps).await
+    }
+
+    /// Decelerates to standstill and returns the position where the stepper stopped, or none while the position is
+    /// unknown. A pending move is interrupted.
+    #[inline]
+    pub async fn stop_async(&self) -> Result<Option<i64>, StepperModuleError> {
+        service::stop(&self.socket, self.config.step_pin).await
+    }
+
+    /// Stops generating step pulses immediately without deceleration and returns the position where the stepper
+    /// stopped, or none while the position is unknown. A pending move is interrupted. At high speeds, the motor may lose
+    /// steps.
+    #[inline]
+    pub async fn emergency_stop_async(&self) -> Result<Option<i64>, StepperModuleError> {
+        service::emergency_stop(&self.socket, self.config.step_pin).await
+    }
+
+    /// Returns the current position in steps.
+    #[inline]
+    pub async fn get_position_async(&self) -> Result<i64, StepperModuleError> {
+        service::get_position(&self.socket, self.config.step_pin).await
+    }
+
+    /// Returns whether the stepper is moving.
+    #[inline]
+    pub async fn is_moving_async(&self) -> Result<bool, StepperModuleError> {
+        service::is_moving(&self.socket, self.config.step_pin).await
+    }
+
+    /// Redefines the current position, e.g. after homing. Fails while the stepper is moving.
+    #[inline]
+    pub async fn set_position_async(&self, position: i64) -> Result<(), StepperModuleError> {
+        service::set_position(&self.socket, self.config.step_pin, position).await
+    }
+
+    /// Enables or disables the driver via the enable pin. Disabling stops a running move immediately.
+    #[inline]
+    pub async fn set_enabled_async(&self, enabled: bool) -> Result<(), StepperModuleError> {
+        service::set_enabled(&self.socket, &self.enabled, self.config.step_pin, enabled).await
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[cfg_attr(feature = "_ffi-blocking", uniffi::export)]
+impl Stepper {
+    /// Moves to the given position and completes once it is reached. A running move is redirected to the new target,
+    /// which interrupts its pending call. Fails while the position is unknown.
+    #[inline]
+    pub fn move_to(&self, position: i64) -> Result<(), StepperModuleError> {
+        block_on(service::move_to(&self.socket, self.config.step_pin, position))
+    }
+
+    /// Moves by the given number of steps relative to the current target and completes once the new target is reached.
+    /// A running move is extended, which interrupts its pending call. Also allowed while the position is unknown.
+    #[inline]
+    pub fn move_by(&self, steps: i64) -> Result<(), StepperModuleError> {
+        block_on(service::move_by(&self.socket, self.config.step_pin, steps))
+    }
+
+    /// Decelerates to standstill and returns the position where the stepper stopped, or none while the position is
+    /// unknown. A pending move is interrupted.
+    #[inline]
+    pub fn stop(&self) -> Result<Option<i64>, StepperModuleError> {
+        block_on(service::stop(&self.socket, self.config.step_pin))
+    }
+
+    /// Stops generating step pulses immediately without deceleration and returns the position where the stepper
+    /// stopped, or none while the position is unknown. A pending move is interrupted. At high speeds, the motor may lose
+    /// steps.
+    #[inline]
+    pub fn emergency_stop(&self) -> Result<Option<i64>, StepperModuleError> {
+        block_on(service::emergency_stop(&self.socket, self.config.step_pin))
+    }
+
+    /// Returns the current position in steps.
+    #[inline]
+    pub fn get_position(&self) -> Result<i64, StepperModuleError> {
+        block_on(service::get_position(&self.socket, self.config.step_pin))
+    }
+
+    /// Returns whether the stepper is moving.
+    #[inline]
+    pub fn is_moving(&self) -> Result<bool, StepperModuleError> {
+        block_on(service::is_moving(&self.socket, self.config.step_pin))
+    }
+
+    /// Redefines the current position, e.g. after homing. Fails while the stepper is moving.
+    #[inline]
+    pub fn set_position(&self, position: i64) -> Result<(), StepperModuleError> {
+        block_on(service::set_position(&self.socket, self.config.step_pin, position))
+    }
+
+    /// Enables or disables the driver via the enable pin. Disabling stops a running move immediately.
+    #[inline]
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), StepperModuleError> {
+        block_on(service::set_enabled(
+            &self.socket,
+            &self.enabled,
+            self.config.step_pin,
+            enabled,
+        ))
+    }
+}
+
+impl Drop for Stepper {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let step_pin = self.config.step_pin;
+
+        block_on(async move { _ = service::drop(&socket, step_pin).await })
+    }
+}
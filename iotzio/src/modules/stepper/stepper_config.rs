@@ -0,0 +1,22 @@
+use crate::peripherals::gpio::GpioPin;
+use serde::{Deserialize, Serialize};
+
+/// Highest step rate the device generates.
+pub const STEPPER_MAX_SPEED_STEPS_PER_SECOND: u32 = 100_000;
+
+/// Configuration of a stepper motor driver with STEP and DIR inputs, e.g. A4988 or TMC2209.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Record))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StepperConfig {
+    /// Pin connected to STEP. A step is performed on each rising edge.
+    pub step_pin: GpioPin,
+    /// Pin connected to DIR. High moves towards increasing positions.
+    pub dir_pin: GpioPin,
+    /// Pin connected to the active low ENABLE input. If no value is passed, the driver is expected to be always enabled.
+    pub enable_pin: Option<GpioPin>,
+    /// Speed limit in steps per second.
+    pub max_speed_steps_per_second: u32,
+    /// Acceleration and deceleration in steps per second squared.
+    pub acceleration_steps_per_second_squared: u32,
+}
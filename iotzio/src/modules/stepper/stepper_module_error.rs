@@ -0,0 +1,56 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use crate::peripherals::gpio::GpioPin;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// Stepper module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum StepperModuleError {
+    /// Pin is used more than once by the stepper.
+    #[error("Pin {pin} is used more than once by the stepper.")]
+    DuplicatePin { pin: GpioPin },
+
+    /// Maximum speed must be between 1 and the highest step rate of the device.
+    #[error("Maximum speed must be between 1 and {max} steps per second.")]
+    MaxSpeedOutOfRange { max: u32 },
+
+    /// Acceleration must be greater than zero.
+    #[error("Acceleration must be greater than zero.")]
+    InvalidAcceleration,
+
+    /// Stepper driver is disabled.
+    #[error("Stepper driver is disabled.")]
+    Disabled,
+
+    /// Stepper is moving.
+    #[error("Stepper is moving.")]
+    Moving,
+
+    /// Position is unknown since the Iotzio board was reconnected. Set the position again, e.g. after homing.
+    #[error("Position is unknown since the Iotzio board was reconnected. Set the position again, e.g. after homing.")]
+    PositionUnknown,
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<ModuleError> for StepperModuleError {
+    fn from(value: ModuleError) -> Self {
+        StepperModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for StepperModuleError {
+    fn from(value: FatalError) -> Self {
+        StepperModuleError::FatalErrorWrapper { error: value }
+    }
+}
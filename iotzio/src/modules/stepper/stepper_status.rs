@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StepperStatus {
+    /// Current position in steps.
+    pub position: i64,
+    /// Whether step pulses are currently generated.
+    pub moving: bool,
+}
@@ -0,0 +1,32 @@
+mod uart0_cts_pin;
+mod uart0_rts_pin;
+mod uart0_rx_pin;
+mod uart0_tx_pin;
+mod uart1_cts_pin;
+mod uart1_rts_pin;
+mod uart1_rx_pin;
+mod uart1_tx_pin;
+mod uart_config;
+mod uart_module_error;
+
+pub use self::uart0_cts_pin::*;
+pub use self::uart0_rts_pin::*;
+pub use self::uart0_rx_pin::*;
+pub use self::uart0_tx_pin::*;
+pub use self::uart1_cts_pin::*;
+pub use self::uart1_rts_pin::*;
+pub use self::uart1_rx_pin::*;
+pub use self::uart1_tx_pin::*;
+pub use self::uart_config::*;
+pub use self::uart_module_error::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        mod service;
+        mod uart;
+        mod uart_stream;
+
+        pub use self::uart::*;
+        pub use self::uart_stream::*;
+    }
+}
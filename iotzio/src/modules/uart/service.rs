@@ -0,0 +1,170 @@
+use crate::communication::{Command, FatalError, ProtocolError, Response};
+use crate::modules::uart::{Uart, UartConfig, UartModuleError};
+use crate::peripherals::uart::UartNumber;
+use crate::peripherals::{BusBuffer, BUS_BUFFER_SIZE};
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use std::sync::Arc;
+
+pub async fn new(socket: &Arc<Socket>, config: UartConfig) -> Result<Uart, UartModuleError> {
+    let identifier = match &config {
+        UartConfig::Uart0 { .. } => UartNumber::Uart0,
+        UartConfig::Uart1 { .. } => UartNumber::Uart1,
+    };
+
+    let command = Command::Uart_New { config };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Uart_New { result } => result,
+        _ => Err(UartModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    Ok(Uart {
+        socket: socket.clone(),
+        read_mutex: Mutex::new(()),
+        received: Default::default(),
+        cancelled_reads: async_std::channel::unbounded(),
+        write_mutex: Mutex::new(()),
+        uart_number: identifier,
+    })
+}
+
+pub async fn drop(socket: &Socket, identifier: UartNumber) -> Result<(), UartModuleError> {
+    let command = Command::Uart_Drop { identifier };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Uart_Drop { result } => result,
+        _ => Err(UartModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
+
+pub async fn read(uart: &Uart, buffer: &mut [u8]) -> Result<usize, UartModuleError> {
+    if buffer.is_empty() {
+        return Ok(0);
+    }
+
+    let _mutex_guard = uart.read_mutex.lock().await;
+
+    // Cancelling a read returns the bytes the device already took for it, including those of a read answered but not
+    // received anymore. They are returned ahead of newly received bytes.
+
+    uart.socket.send_pending_cancellations().await;
+
+    take_cancelled_reads(uart);
+
+    if let Some(length) = take_received(uart, buffer) {
+        return Ok(length);
+    }
+
+    let command = Command::Uart_Read {
+        identifier: uart.uart_number,
+        buffer_size: buffer.len().min(BUS_BUFFER_SIZE) as u16,
+    };
+
+    // The read waits until a byte is received. If the future is dropped before, the read is aborted on the device, so
+    // that the next read does not wait behind it.
+
+    let cancel_command = Command::Uart_CancelRead {
+        identifier: uart.uart_number,
+    };
+
+    let response = uart
+        .socket
+        .send_cancellable_with_response(command, cancel_command, &uart.cancelled_reads.0)
+        .await??;
+
+    let bus_buffer = match response {
+        Response::Uart_Read { result } => result,
+        _ => Err(UartModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }?;
+
+    let length = bus_buffer.len().min(buffer.len());
+
+    buffer[..length].copy_from_slice(&bus_buffer[..length]);
+
+    Ok(length)
+}
+
+/// Put back bytes read but not consumed by the caller. They are returned by the next read.
+pub fn unread(uart: &Uart, bytes: &[u8]) {
+    uart.received.lock().unwrap().splice(0..0, bytes.iter().copied());
+}
+
+#[inline]
+fn take_cancelled_reads(uart: &Uart) {
+    // A failed cancellation took no bytes.
+
+    while let Ok(response) = uart.cancelled_reads.1.try_recv() {
+        if let Response::Uart_CancelRead { result: Ok(bus_buffer) } = response {
+            uart.received.lock().unwrap().extend_from_slice(&bus_buffer);
+        }
+    }
+}
+
+#[inline]
+fn take_received(uart: &Uart, buffer: &mut [u8]) -> Option<usize> {
+    let mut received = uart.received.lock().unwrap();
+
+    if received.is_empty() {
+        return None;
+    }
+
+    let length = received.len().min(buffer.len());
+
+    buffer[..length].copy_from_slice(&received[..length]);
+    received.drain(..length);
+
+    Some(length)
+}
+
+pub async fn write(
+    socket: &Socket,
+    mutex: &Mutex<()>,
+    identifier: UartNumber,
+    bytes: &[u8],
+) -> Result<(), UartModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    for chunk in bytes.chunks(BUS_BUFFER_SIZE) {
+        let command = Command::Uart_Write {
+            identifier,
+            bytes: BusBuffer::from_slice(chunk).unwrap(),
+        };
+
+        let response = socket.send(command).await??;
+
+        match response {
+            Response::Uart_Write { result } => result,
+            _ => Err(UartModuleError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            ))),
+        }?;
+    }
+
+    Ok(())
+}
+
+pub async fn flush(socket: &Socket, mutex: &Mutex<()>, identifier: UartNumber) -> Result<(), UartModuleError> {
+    let _mutex_guard = mutex.lock().await;
+
+    let command = Command::Uart_Flush { identifier };
+
+    let response = socket.send(command).await??;
+
+    match response {
+        Response::Uart_Flush { result } => result,
+        _ => Err(UartModuleError::from(FatalError::from(
+            ProtocolError::ReceivedWrongResponse,
+        ))),
+    }
+}
@@ -0,0 +1,203 @@
+use crate::communication::Response;
+use crate::modules::uart::{service, UartConfig, UartModuleError, UartStream};
+use crate::peripherals::uart::UartNumber;
+use crate::socket::Socket;
+use async_std::sync::Mutex;
+use async_std::task::block_on;
+use std::sync::Arc;
+
+/// Represents an UART on the Iotzio device.
+/// Received bytes are buffered on the device until they are read.
+/// Bytes already taken by a read whose future is dropped are kept and returned by the next read.
+/// Reading and writing can take place at the same time.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Object))]
+#[derive(Debug)]
+pub struct Uart {
+    pub(crate) socket: Arc<Socket>,
+    pub(crate) read_mutex: Mutex<()>,
+    pub(crate) received: std::sync::Mutex<Vec<u8>>,
+    pub(crate) cancelled_reads: (
+        async_std::channel::Sender<Response>,
+        async_std::channel::Receiver<Response>,
+    ),
+    pub(crate) write_mutex: Mutex<()>,
+    pub(crate) uart_number: UartNumber,
+}
+
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), uniffi::export)]
+impl Uart {
+    /// The UART number this instance is using.
+    #[inline]
+    pub fn uart_number(&self) -> UartNumber {
+        self.uart_number
+    }
+}
+
+impl Uart {
+    #[inline]
+    pub(crate) async fn new(socket: &Arc<Socket>, config: UartConfig) -> Result<Uart, UartModuleError> {
+        service::new(socket, config).await
+    }
+
+    /// Returns an adapter implementing the futures AsyncRead and AsyncWrite traits.
+    #[inline]
+    pub fn stream(&self) -> UartStream<'_> {
+        UartStream::new(self)
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), not(feature = "_ffi-blocking")))]
+impl Uart {
+    /// Wait until at least one byte was received, then read up to buffer length bytes. Returns the number of bytes read.
+    #[inline]
+    pub fn read(&self, buffer: &mut [u8]) -> Result<usize, UartModuleError> {
+        block_on(service::read(self, buffer))
+    }
+
+    /// Write all bytes.
+    #[inline]
+    pub fn write(&self, bytes: &[u8]) -> Result<(), UartModuleError> {
+        block_on(service::write(&self.socket, &self.write_mutex, self.uart_number, bytes))
+    }
+
+    /// Wait until all written bytes have been transmitted.
+    #[inline]
+    pub fn flush(&self) -> Result<(), UartModuleError> {
+        block_on(service::flush(&self.socket, &self.write_mutex, self.uart_number))
+    }
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "_ffi-blocking"))]
+#[uniffi::export]
+impl Uart {
+    /// Wait until at least one byte was received, then read up to buffer length bytes. Returns buffer truncated to the number of bytes read.
+    #[inline]
+    pub fn read(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, UartModuleError> {
+        block_on(service::read(self, buffer.as_mut_slice())).map(|x| {
+            buffer.truncate(x);
+            buffer
+        })
+    }
+
+    /// Write all bytes.
+    #[inline]
+    pub fn write(&self, bytes: Vec<u8>) -> Result<(), UartModuleError> {
+        block_on(service::write(
+            &self.socket,
+            &self.write_mutex,
+            self.uart_number,
+            bytes.as_slice(),
+        ))
+    }
+
+    /// Wait until all written bytes have been transmitted.
+    #[inline]
+    pub fn flush(&self) -> Result<(), UartModuleError> {
+        block_on(service::flush(&self.socket, &self.write_mutex, self.uart_number))
+    }
+}
+
+#[cfg(not(feature = "_ffi-async"))]
+impl Uart {
+    /// Wait until at least one byte was received, then read up to buffer length bytes. Returns the number of bytes read.
+    #[inline]
+    pub async fn read_async(&self, buffer: &mut [u8]) -> Result<usize, UartModuleError> {
+        service::read(self, buffer).await
+    }
+
+    /// Write all bytes.
+    #[inline]
+    pub async fn write_async(&self, bytes: &[u8]) -> Result<(), UartModuleError> {
+        service::write(&self.socket, &self.write_mutex, self.uart_number, bytes).await
+    }
+
+    /// Wait until all written bytes have been transmitted.
+    #[inline]
+    pub async fn flush_async(&self) -> Result<(), UartModuleError> {
+        service::flush(&self.socket, &self.write_mutex, self.uart_number).await
+    }
+}
+
+#[cfg(feature = "_ffi-async")]
+#[uniffi::export]
+impl Uart {
+    /// Wait until at least one byte was received, then read up to buffer length bytes. Returns buffer truncated to the number of bytes read.
+    #[inline]
+    pub async fn read_async(&self, mut buffer: Vec<u8>) -> Result<Vec<u8>, UartModuleError> {
+        service::read(self, buffer.as_mut_slice()).await.map(|x| {
+            buffer.truncate(x);
+            buffer
+        })
+    }
+
+    /// Write all bytes.
+    #[inline]
+    pub async fn write_async(&self, bytes: Vec<u8>) -> Result<(), UartModuleError> {
+        service::write(&self.socket, &self.write_mutex, self.uart_number, bytes.as_slice()).await
+    }
+
+    /// Wait until all written bytes have been transmitted.
+    #[inline]
+    pub async fn flush_async(&self) -> Result<(), UartModuleError> {
+        service::flush(&self.socket, &self.write_mutex, self.uart_number).await
+    }
+}
+
+impl Drop for Uart {
+    #[inline]
+    fn drop(&mut self) {
+        let socket = self.socket.clone();
+        let uart = self.uart_number;
+
+        block_on(async move { _ = service::drop(&socket, uart).await })
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_io::ErrorType for Uart {
+    type Error = UartModuleError;
+}
+
+#[cfg(all(feature = "embedded-hal", not(target_family = "wasm")))]
+impl embedded_io::Read for Uart {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        block_on(service::read(self, buf))
+    }
+}
+
+#[cfg(all(feature = "embedded-hal", not(target_family = "wasm")))]
+impl embedded_io::Write for Uart {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        block_on(service::write(&self.socket, &self.write_mutex, self.uart_number, buf)).map(|_| buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        block_on(service::flush(&self.socket, &self.write_mutex, self.uart_number))
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_io_async::Read for Uart {
+    #[inline]
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        service::read(self, buf).await
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_io_async::Write for Uart {
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        service::write(&self.socket, &self.write_mutex, self.uart_number, buf)
+            .await
+            .map(|_| buf.len())
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        service::flush(&self.socket, &self.write_mutex, self.uart_number).await
+    }
+}
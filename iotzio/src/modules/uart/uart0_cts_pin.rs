@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for CTS using UART0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart0CtsPin {
+    /// Pin 2
+    Pin2,
+    /// Pin 14
+    Pin14,
+    /// Pin 18
+    Pin18,
+}
+
+impl TryFrom<u8> for Uart0CtsPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            2 => Ok(Uart0CtsPin::Pin2),
+            14 => Ok(Uart0CtsPin::Pin14),
+            18 => Ok(Uart0CtsPin::Pin18),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart0CtsPin> for u8 {
+    fn from(pin: Uart0CtsPin) -> u8 {
+        match pin {
+            Uart0CtsPin::Pin2 => 2,
+            Uart0CtsPin::Pin14 => 14,
+            Uart0CtsPin::Pin18 => 18,
+        }
+    }
+}
+
+impl From<Uart0CtsPin> for GpioPin {
+    fn from(pin: Uart0CtsPin) -> GpioPin {
+        match pin {
+            Uart0CtsPin::Pin2 => GpioPin::Pin2,
+            Uart0CtsPin::Pin14 => GpioPin::Pin14,
+            Uart0CtsPin::Pin18 => GpioPin::Pin18,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for RTS using UART0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart0RtsPin {
+    /// Pin 3
+    Pin3,
+    /// Pin 15
+    Pin15,
+    /// Pin 19
+    Pin19,
+}
+
+impl TryFrom<u8> for Uart0RtsPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            3 => Ok(Uart0RtsPin::Pin3),
+            15 => Ok(Uart0RtsPin::Pin15),
+            19 => Ok(Uart0RtsPin::Pin19),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart0RtsPin> for u8 {
+    fn from(pin: Uart0RtsPin) -> u8 {
+        match pin {
+            Uart0RtsPin::Pin3 => 3,
+            Uart0RtsPin::Pin15 => 15,
+            Uart0RtsPin::Pin19 => 19,
+        }
+    }
+}
+
+impl From<Uart0RtsPin> for GpioPin {
+    fn from(pin: Uart0RtsPin) -> GpioPin {
+        match pin {
+            Uart0RtsPin::Pin3 => GpioPin::Pin3,
+            Uart0RtsPin::Pin15 => GpioPin::Pin15,
+            Uart0RtsPin::Pin19 => GpioPin::Pin19,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for RX using UART0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart0RxPin {
+    /// Pin 1
+    Pin1,
+    /// Pin 13
+    Pin13,
+    /// Pin 17
+    Pin17,
+}
+
+impl TryFrom<u8> for Uart0RxPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Uart0RxPin::Pin1),
+            13 => Ok(Uart0RxPin::Pin13),
+            17 => Ok(Uart0RxPin::Pin17),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart0RxPin> for u8 {
+    fn from(pin: Uart0RxPin) -> u8 {
+        match pin {
+            Uart0RxPin::Pin1 => 1,
+            Uart0RxPin::Pin13 => 13,
+            Uart0RxPin::Pin17 => 17,
+        }
+    }
+}
+
+impl From<Uart0RxPin> for GpioPin {
+    fn from(pin: Uart0RxPin) -> GpioPin {
+        match pin {
+            Uart0RxPin::Pin1 => GpioPin::Pin1,
+            Uart0RxPin::Pin13 => GpioPin::Pin13,
+            Uart0RxPin::Pin17 => GpioPin::Pin17,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for TX using UART0.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart0TxPin {
+    /// Pin 0
+    Pin0,
+    /// Pin 12
+    Pin12,
+    /// Pin 16
+    Pin16,
+    /// Pin 28
+    Pin28,
+}
+
+impl TryFrom<u8> for Uart0TxPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Uart0TxPin::Pin0),
+            12 => Ok(Uart0TxPin::Pin12),
+            16 => Ok(Uart0TxPin::Pin16),
+            28 => Ok(Uart0TxPin::Pin28),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart0TxPin> for u8 {
+    fn from(pin: Uart0TxPin) -> u8 {
+        match pin {
+            Uart0TxPin::Pin0 => 0,
+            Uart0TxPin::Pin12 => 12,
+            Uart0TxPin::Pin16 => 16,
+            Uart0TxPin::Pin28 => 28,
+        }
+    }
+}
+
+impl From<Uart0TxPin> for GpioPin {
+    fn from(pin: Uart0TxPin) -> GpioPin {
+        match pin {
+            Uart0TxPin::Pin0 => GpioPin::Pin0,
+            Uart0TxPin::Pin12 => GpioPin::Pin12,
+            Uart0TxPin::Pin16 => GpioPin::Pin16,
+            Uart0TxPin::Pin28 => GpioPin::Pin28,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for CTS using UART1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart1CtsPin {
+    /// Pin 6
+    Pin6,
+    /// Pin 10
+    Pin10,
+    /// Pin 22
+    Pin22,
+    /// Pin 26
+    Pin26,
+}
+
+impl TryFrom<u8> for Uart1CtsPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            6 => Ok(Uart1CtsPin::Pin6),
+            10 => Ok(Uart1CtsPin::Pin10),
+            22 => Ok(Uart1CtsPin::Pin22),
+            26 => Ok(Uart1CtsPin::Pin26),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart1CtsPin> for u8 {
+    fn from(pin: Uart1CtsPin) -> u8 {
+        match pin {
+            Uart1CtsPin::Pin6 => 6,
+            Uart1CtsPin::Pin10 => 10,
+            Uart1CtsPin::Pin22 => 22,
+            Uart1CtsPin::Pin26 => 26,
+        }
+    }
+}
+
+impl From<Uart1CtsPin> for GpioPin {
+    fn from(pin: Uart1CtsPin) -> GpioPin {
+        match pin {
+            Uart1CtsPin::Pin6 => GpioPin::Pin6,
+            Uart1CtsPin::Pin10 => GpioPin::Pin10,
+            Uart1CtsPin::Pin22 => GpioPin::Pin22,
+            Uart1CtsPin::Pin26 => GpioPin::Pin26,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for RTS using UART1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart1RtsPin {
+    /// Pin 7
+    Pin7,
+    /// Pin 11
+    Pin11,
+    /// Pin 27
+    Pin27,
+}
+
+impl TryFrom<u8> for Uart1RtsPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            7 => Ok(Uart1RtsPin::Pin7),
+            11 => Ok(Uart1RtsPin::Pin11),
+            27 => Ok(Uart1RtsPin::Pin27),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart1RtsPin> for u8 {
+    fn from(pin: Uart1RtsPin) -> u8 {
+        match pin {
+            Uart1RtsPin::Pin7 => 7,
+            Uart1RtsPin::Pin11 => 11,
+            Uart1RtsPin::Pin27 => 27,
+        }
+    }
+}
+
+impl From<Uart1RtsPin> for GpioPin {
+    fn from(pin: Uart1RtsPin) -> GpioPin {
+        match pin {
+            Uart1RtsPin::Pin7 => GpioPin::Pin7,
+            Uart1RtsPin::Pin11 => GpioPin::Pin11,
+            Uart1RtsPin::Pin27 => GpioPin::Pin27,
+        }
+    }
+}
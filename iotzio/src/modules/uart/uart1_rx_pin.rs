@@ -0,0 +1,54 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for RX using UART1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart1RxPin {
+    /// Pin 5
+    Pin5,
+    /// Pin 9
+    Pin9,
+    /// Pin 21
+    Pin21,
+    /// Pin 25
+    Pin25,
+}
+
+impl TryFrom<u8> for Uart1RxPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            5 => Ok(Uart1RxPin::Pin5),
+            9 => Ok(Uart1RxPin::Pin9),
+            21 => Ok(Uart1RxPin::Pin21),
+            25 => Ok(Uart1RxPin::Pin25),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart1RxPin> for u8 {
+    fn from(pin: Uart1RxPin) -> u8 {
+        match pin {
+            Uart1RxPin::Pin5 => 5,
+            Uart1RxPin::Pin9 => 9,
+            Uart1RxPin::Pin21 => 21,
+            Uart1RxPin::Pin25 => 25,
+        }
+    }
+}
+
+impl From<Uart1RxPin> for GpioPin {
+    fn from(pin: Uart1RxPin) -> GpioPin {
+        match pin {
+            Uart1RxPin::Pin5 => GpioPin::Pin5,
+            Uart1RxPin::Pin9 => GpioPin::Pin9,
+            Uart1RxPin::Pin21 => GpioPin::Pin21,
+            Uart1RxPin::Pin25 => GpioPin::Pin25,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::peripherals::gpio::GpioPin;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of a pin suitable for TX using UART1.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Uart1TxPin {
+    /// Pin 4
+    Pin4,
+    /// Pin 8
+    Pin8,
+    /// Pin 20
+    Pin20,
+}
+
+impl TryFrom<u8> for Uart1TxPin {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            4 => Ok(Uart1TxPin::Pin4),
+            8 => Ok(Uart1TxPin::Pin8),
+            20 => Ok(Uart1TxPin::Pin20),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Uart1TxPin> for u8 {
+    fn from(pin: Uart1TxPin) -> u8 {
+        match pin {
+            Uart1TxPin::Pin4 => 4,
+            Uart1TxPin::Pin8 => 8,
+            Uart1TxPin::Pin20 => 20,
+        }
+    }
+}
+
+impl From<Uart1TxPin> for GpioPin {
+    fn from(pin: Uart1TxPin) -> GpioPin {
+        match pin {
+            Uart1TxPin::Pin4 => GpioPin::Pin4,
+            Uart1TxPin::Pin8 => GpioPin::Pin8,
+            Uart1TxPin::Pin20 => GpioPin::Pin20,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::modules::uart::{
+    Uart0CtsPin, Uart0RtsPin, Uart0RxPin, Uart0TxPin, Uart1CtsPin, Uart1RtsPin, Uart1RxPin, Uart1TxPin,
+};
+use crate::peripherals::uart::{UartDataBits, UartParity, UartStopBits};
+use serde::{Deserialize, Serialize};
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "_host")] {
+        use std::fmt;
+    }
+    else {
+        use core::fmt;
+    }
+}
+
+/// UART configuration.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UartConfig {
+    /// Use UART 0
+    Uart0 {
+        /// TX pin to use
+        tx: Uart0TxPin,
+        /// RX pin to use
+        rx: Uart0RxPin,
+        /// RTS pin to use. If no value is passed, RTS flow control is disabled.
+        rts: Option<Uart0RtsPin>,
+        /// CTS pin to use. If no value is passed, CTS flow control is disabled.
+        cts: Option<Uart0CtsPin>,
+        /// Baud rate to use.
+        baud_rate: u32,
+        /// Number of data bits per frame.
+        data_bits: UartDataBits,
+        /// Parity bit per frame.
+        parity: UartParity,
+        /// Number of stop bits per frame.
+        stop_bits: UartStopBits,
+    },
+    /// Use UART 1
+    Uart1 {
+        /// TX pin to use
+        tx: Uart1TxPin,
+        /// RX pin to use
+        rx: Uart1RxPin,
+        /// RTS pin to use. If no value is passed, RTS flow control is disabled.
+        rts: Option<Uart1RtsPin>,
+        /// CTS pin to use. If no value is passed, CTS flow control is disabled.
+        cts: Option<Uart1CtsPin>,
+        /// Baud rate to use.
+        baud_rate: u32,
+        /// Number of data bits per frame.
+        data_bits: UartDataBits,
+        /// Parity bit per frame.
+        parity: UartParity,
+        /// Number of stop bits per frame.
+        stop_bits: UartStopBits,
+    },
+}
+
+impl fmt::Display for UartConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::communication::FatalError;
+use crate::modules::ModuleError;
+use crate::peripherals::uart::UartError;
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// UART module error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UartModuleError {
+    /// Requested UART baud rate is too high.
+    #[error("Requested UART baud rate is too high.")]
+    BaudRateTooHigh,
+
+    /// Requested UART baud rate is too low.
+    #[error("Requested UART baud rate is too low.")]
+    BaudRateTooLow,
+
+    /// UART error.
+    #[error("{error}")]
+    UartErrorWrapper { error: UartError },
+
+    /// Module error.
+    #[error("{error}")]
+    ModuleErrorWrapper { error: ModuleError },
+
+    /// Fatal error.
+    #[error("{error}")]
+    FatalErrorWrapper { error: FatalError },
+}
+
+impl From<UartError> for UartModuleError {
+    fn from(value: UartError) -> Self {
+        UartModuleError::UartErrorWrapper { error: value }
+    }
+}
+
+impl From<ModuleError> for UartModuleError {
+    fn from(value: ModuleError) -> Self {
+        UartModuleError::ModuleErrorWrapper { error: value }
+    }
+}
+
+impl From<FatalError> for UartModuleError {
+    fn from(value: FatalError) -> Self {
+        UartModuleError::FatalErrorWrapper { error: value }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl embedded_io::Error for UartModuleError {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self {
+            UartModuleError::UartErrorWrapper {
+                error: UartError::Break | UartError::Parity | UartError::Framing,
+            } => embedded_io::ErrorKind::InvalidData,
+            _ => embedded_io::ErrorKind::Other,
+        }
+    }
+}
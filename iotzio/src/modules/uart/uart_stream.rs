@@ -0,0 +1,137 @@
+use crate::modules::uart::{service, Uart, UartModuleError};
+use crate::peripherals::BUS_BUFFER_SIZE;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+cfg_if::cfg_if! {
+    if #[cfg(target_family = "wasm")] {
+        type UartFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, UartModuleError>> + 'a>>;
+    }
+    else {
+        type UartFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, UartModuleError>> + Send + 'a>>;
+    }
+}
+
+/// Adapter implementing the futures AsyncRead and AsyncWrite traits for an UART.
+pub struct UartStream<'a> {
+    uart: &'a Uart,
+    read_future: Option<UartFuture<'a, Vec<u8>>>,
+    write_future: Option<UartFuture<'a, usize>>,
+    flush_future: Option<UartFuture<'a, ()>>,
+}
+
+impl Debug for UartStream<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UartStream")
+            .field("uart", &self.uart)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> UartStream<'a> {
+    pub(crate) fn new(uart: &'a Uart) -> UartStream<'a> {
+        UartStream {
+            uart,
+            read_future: None,
+            write_future: None,
+            flush_future: None,
+        }
+    }
+}
+
+impl async_std::io::Read for UartStream<'_> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        let uart = this.uart;
+        let buffer_size = buf.len().min(BUS_BUFFER_SIZE);
+
+        let future = this.read_future.get_or_insert_with(|| {
+            Box::pin(async move {
+                let mut buffer = vec![0u8; buffer_size];
+
+                let length = service::read(uart, &mut buffer).await?;
+
+                buffer.truncate(length);
+
+                Ok(buffer)
+            })
+        });
+
+        match future.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                this.read_future = None;
+
+                let bytes = result.map_err(io::Error::other)?;
+
+                // The caller may have passed a smaller buffer than the pending read was started with. The remaining
+                // bytes are kept by the UART, so that they are not lost if the stream is dropped.
+
+                let length = buf.len().min(bytes.len());
+
+                buf[..length].copy_from_slice(&bytes[..length]);
+                service::unread(this.uart, &bytes[length..]);
+
+                Poll::Ready(Ok(length))
+            }
+        }
+    }
+}
+
+impl async_std::io::Write for UartStream<'_> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        let uart = this.uart;
+
+        let future = this.write_future.get_or_insert_with(|| {
+            let bytes = buf[..buf.len().min(BUS_BUFFER_SIZE)].to_vec();
+
+            Box::pin(async move {
+                service::write(&uart.socket, &uart.write_mutex, uart.uart_number, &bytes).await?;
+
+                Ok(bytes.len())
+            })
+        });
+
+        match future.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                this.write_future = None;
+
+                Poll::Ready(result.map_err(io::Error::other))
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        let uart = this.uart;
+
+        let future = this.flush_future.get_or_insert_with(|| {
+            Box::pin(async move { service::flush(&uart.socket, &uart.write_mutex, uart.uart_number).await })
+        });
+
+        match future.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(result) => {
+                this.flush_future = None;
+
+                Poll::Ready(result.map_err(io::Error::other))
+            }
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
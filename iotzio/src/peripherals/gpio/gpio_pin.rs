@@ -1,6 +1,9 @@
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
+/// Number of pins available for GPIO.
+pub const GPIO_PIN_COUNT: usize = 27;
+
 /// Number of a pin suitable for GPIO and PWM.
 #[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
 #[cfg_attr(feature = "_defmt", derive(defmt::Format))]
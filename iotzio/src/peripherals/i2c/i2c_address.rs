@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// I2C target address including its addressing mode.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum I2cAddress {
+    /// 7-bit address, valid range is 0x00 to 0x7F.
+    SevenBit { address: u16 },
+    /// 10-bit address, valid range is 0x000 to 0x3FF.
+    TenBit { address: u16 },
+}
@@ -38,4 +38,14 @@ pub enum I2cError {
     /// Target i2c address is reserved.
     #[error("Target i2c address {address} is reserved.")]
     AddressReserved { address: u16 },
+
+    /// Bus operation did not complete within the configured timeout, e.g. due to a target holding SCL or SDA low.
+    #[error(
+        "Bus operation did not complete within the configured timeout, e.g. due to a target holding SCL or SDA low."
+    )]
+    Timeout,
+
+    /// Bus recovery failed, SDA is still held low after nine SCL clocks.
+    #[error("Bus recovery failed, SDA is still held low after nine SCL clocks.")]
+    RecoveryFailed,
 }
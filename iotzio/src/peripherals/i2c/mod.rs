@@ -1,5 +1,7 @@
+mod i2c_address;
 mod i2c_bus_number;
 mod i2c_error;
 
+pub use self::i2c_address::*;
 pub use self::i2c_bus_number::*;
 pub use self::i2c_error::*;
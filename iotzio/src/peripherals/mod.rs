@@ -2,6 +2,9 @@
 
 pub mod gpio;
 pub mod i2c;
+pub mod pwm;
+pub mod spi;
+pub mod uart;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "_host")] {
@@ -0,0 +1,3 @@
+mod pwm_polarity;
+
+pub use self::pwm_polarity::*;
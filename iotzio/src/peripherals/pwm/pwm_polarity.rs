@@ -0,0 +1,13 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Polarity of a PWM output.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PwmPolarity {
+    /// Output is high for the duty cycle portion of the period.
+    Normal,
+    /// Output is low for the duty cycle portion of the period.
+    Inverted,
+}
@@ -0,0 +1,7 @@
+mod spi_bit_order;
+mod spi_bus_number;
+mod spi_mode;
+
+pub use self::spi_bit_order::*;
+pub use self::spi_bus_number::*;
+pub use self::spi_mode::*;
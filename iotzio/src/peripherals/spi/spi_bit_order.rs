@@ -0,0 +1,13 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Order in which the bits of a byte are shifted out and in on the SPI bus.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpiBitOrder {
+    /// Most significant bit first.
+    MsbFirst,
+    /// Least significant bit first.
+    LsbFirst,
+}
@@ -0,0 +1,34 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// SPI bus number.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpiBusNumber {
+    /// SPI Bus 0
+    Spi0,
+    /// SPI Bus 1
+    Spi1,
+}
+
+impl TryFrom<u8> for SpiBusNumber {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SpiBusNumber::Spi0),
+            1 => Ok(SpiBusNumber::Spi1),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<SpiBusNumber> for u8 {
+    fn from(bus_number: SpiBusNumber) -> u8 {
+        match bus_number {
+            SpiBusNumber::Spi0 => 0,
+            SpiBusNumber::Spi1 => 1,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// SPI mode, a combination of clock polarity (CPOL) and clock phase (CPHA).
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SpiMode {
+    /// Clock idle low, data captured on the first (rising) transition. CPOL = 0, CPHA = 0.
+    Mode0,
+    /// Clock idle low, data captured on the second (falling) transition. CPOL = 0, CPHA = 1.
+    Mode1,
+    /// Clock idle high, data captured on the first (falling) transition. CPOL = 1, CPHA = 0.
+    Mode2,
+    /// Clock idle high, data captured on the second (rising) transition. CPOL = 1, CPHA = 1.
+    Mode3,
+}
+
+#[cfg(feature = "embedded-hal")]
+impl From<embedded_hal::spi::Mode> for SpiMode {
+    fn from(value: embedded_hal::spi::Mode) -> Self {
+        match (value.polarity, value.phase) {
+            (embedded_hal::spi::Polarity::IdleLow, embedded_hal::spi::Phase::CaptureOnFirstTransition) => {
+                SpiMode::Mode0
+            }
+            (embedded_hal::spi::Polarity::IdleLow, embedded_hal::spi::Phase::CaptureOnSecondTransition) => {
+                SpiMode::Mode1
+            }
+            (embedded_hal::spi::Polarity::IdleHigh, embedded_hal::spi::Phase::CaptureOnFirstTransition) => {
+                SpiMode::Mode2
+            }
+            (embedded_hal::spi::Polarity::IdleHigh, embedded_hal::spi::Phase::CaptureOnSecondTransition) => {
+                SpiMode::Mode3
+            }
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl From<SpiMode> for embedded_hal::spi::Mode {
+    fn from(value: SpiMode) -> Self {
+        match value {
+            SpiMode::Mode0 => embedded_hal::spi::MODE_0,
+            SpiMode::Mode1 => embedded_hal::spi::MODE_1,
+            SpiMode::Mode2 => embedded_hal::spi::MODE_2,
+            SpiMode::Mode3 => embedded_hal::spi::MODE_3,
+        }
+    }
+}
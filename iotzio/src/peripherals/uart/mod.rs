@@ -0,0 +1,11 @@
+mod uart_data_bits;
+mod uart_error;
+mod uart_number;
+mod uart_parity;
+mod uart_stop_bits;
+
+pub use self::uart_data_bits::*;
+pub use self::uart_error::*;
+pub use self::uart_number::*;
+pub use self::uart_parity::*;
+pub use self::uart_stop_bits::*;
@@ -0,0 +1,17 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of data bits per UART frame.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UartDataBits {
+    /// 5 data bits.
+    Five,
+    /// 6 data bits.
+    Six,
+    /// 7 data bits.
+    Seven,
+    /// 8 data bits.
+    Eight,
+}
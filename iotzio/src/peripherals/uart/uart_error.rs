@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use thiserror_no_std::Error;
+
+/// UART error.
+#[non_exhaustive]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Error))]
+#[derive(Serialize, Deserialize, Error, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UartError {
+    /// Receive buffer on the device overflowed, received data was lost.
+    #[error("Receive buffer on the device overflowed, received data was lost.")]
+    Overrun,
+
+    /// A break condition was detected on the receive line.
+    #[error("A break condition was detected on the receive line.")]
+    Break,
+
+    /// A received frame had an invalid parity bit.
+    #[error("A received frame had an invalid parity bit.")]
+    Parity,
+
+    /// A received frame had no valid stop bit.
+    #[error("A received frame had no valid stop bit.")]
+    Framing,
+}
@@ -0,0 +1,34 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// UART number.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UartNumber {
+    /// UART 0
+    Uart0,
+    /// UART 1
+    Uart1,
+}
+
+impl TryFrom<u8> for UartNumber {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(UartNumber::Uart0),
+            1 => Ok(UartNumber::Uart1),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<UartNumber> for u8 {
+    fn from(number: UartNumber) -> u8 {
+        match number {
+            UartNumber::Uart0 => 0,
+            UartNumber::Uart1 => 1,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Number of stop bits per UART frame.
+#[cfg_attr(any(feature = "_ffi-blocking", feature = "_ffi-async"), derive(uniffi::Enum))]
+#[cfg_attr(feature = "_defmt", derive(defmt::Format))]
+#[derive(Serialize, Deserialize, Display, Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UartStopBits {
+    /// 1 stop bit.
+    One,
+    /// 2 stop bits.
+    Two,
+}
@@ -0,0 +1,13 @@
+#![forbid(unsafe_code)]
+
+mod service;
+mod simulated_board;
+mod virtual_i2c_device;
+mod virtual_pin_bank;
+mod virtual_register_map;
+
+pub(crate) use self::service::*;
+pub use self::simulated_board::*;
+pub use self::virtual_i2c_device::*;
+pub use self::virtual_pin_bank::*;
+pub use self::virtual_register_map::*;
@@ -0,0 +1,2037 @@
+use crate::communication::{
+    BoardInfo, Command, DeviceEvent, DeviceReport, FatalError, HostReport, ProtocolError, Response, BATCH_HEADER_SIZE,
+    BATCH_MAX_COUNT, HOST_REPORT_HEADER_SIZE, IOTZIO_PROTOCOL_VERSION, PROTOCOL_INFO_BUFFER_SIZE,
+    PROTOCOL_INFO_REPORT_ID,
+};
+use crate::modules::counter::{CounterEdge, CounterMeasurement};
+use crate::modules::i2c_bus::{I2cBusModuleError, I2cConfig, I2cTransactionChunkEnd, I2cTransactionOperation};
+use crate::modules::input_pin::{InputPinModuleError, InterruptTrigger, SignalTypeRequest, SignalTypeResponse};
+use crate::modules::input_port::InputPortModuleError;
+use crate::modules::led_strip::{LedColor, LedStripModuleError, LedStripType, LED_STRIP_MAX_LED_COUNT};
+use crate::modules::output_port::OutputPortModuleError;
+use crate::modules::pulse_in::{PulseInConfig, PulseInModuleError};
+use crate::modules::quadrature_encoder::QuadratureEncoderReading;
+use crate::modules::servo::{ServoConfig, ServoModuleError};
+use crate::modules::stepper::{StepperConfig, StepperModuleError, StepperStatus};
+use crate::modules::ModuleError;
+use crate::peripherals::gpio::{GpioPin, Level, Pull};
+use crate::peripherals::i2c::{I2cAddress, I2cBusNumber, I2cError};
+use crate::peripherals::BusBuffer;
+use crate::simulator::{SimulatedBoardState, VirtualI2cDevice};
+use async_std::channel::Sender;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+
+const REPORT_ID: u8 = 0x01;
+
+const REPORT_SIZE: usize = 1024;
+
+// Vendor defined collection with a single 1024 byte input report and a single 1024 byte output report, both using report ID 1.
+const HID_DESCRIPTOR: [u8; 28] = [
+    0x06, 0x00, 0xFF, // Usage Page (Vendor Defined 0xFF00)
+    0x09, 0x01, // Usage (0x01)
+    0xA1, 0x01, // Collection (Application)
+    0x85, REPORT_ID, // Report ID (1)
+    0x15, 0x00, // Logical Minimum (0)
+    0x26, 0xFF, 0x00, // Logical Maximum (255)
+    0x75, 0x08, // Report Size (8)
+    0x96, 0x00, 0x04, // Report Count (1024)
+    0x09, 0x01, // Usage (0x01)
+    0x81, 0x02, // Input (Data, Variable, Absolute)
+    0x09, 0x01, // Usage (0x01)
+    0x91, 0x02, // Output (Data, Variable, Absolute)
+    0xC0, // End Collection
+];
+
+#[derive(Debug)]
+pub enum SimulatedReport {
+    ProtocolInfo,
+    DeviceReport(Box<DeviceReport>),
+    DeviceReportBatch(Vec<DeviceReport>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum VirtualPinMode {
+    Input { pull: Pull },
+    Output { level: Level },
+    PortOutput { level: Level },
+    PortInput { pull: Pull },
+    Counter { edge: CounterEdge },
+    Encoder { pull: Pull },
+    LedStrip,
+    Servo { frequency_hz: u32 },
+    Stepper,
+    PulseInTrigger { config: PulseInConfig },
+    PulseInEcho { pull: Pull },
+}
+
+#[derive(Debug)]
+pub(crate) struct PendingSignal {
+    identifier: u32,
+    pin: GpioPin,
+    request: SignalTypeRequest,
+    pulse_start: Option<(Level, Instant)>,
+}
+
+#[derive(Debug)]
+pub(crate) struct PendingPulseMeasure {
+    identifier: u32,
+    echo_pin: GpioPin,
+    echo_level: Level,
+    pulse_start: Option<Instant>,
+    pending: Arc<Mutex<bool>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct PinSubscription {
+    pin: GpioPin,
+    trigger: InterruptTrigger,
+    subscription: u32,
+}
+
+#[derive(Debug)]
+pub(crate) struct PendingCount {
+    identifier: u32,
+    pin: GpioPin,
+    edge: CounterEdge,
+    deadline: Instant,
+    count: Arc<Mutex<Option<u64>>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct VirtualEncoder {
+    pin_a: GpioPin,
+    pin_b: GpioPin,
+    index_pin: Option<GpioPin>,
+    position: i64,
+    index_position: Option<i64>,
+}
+
+#[derive(Debug)]
+pub(crate) struct VirtualLedStrip {
+    pin: GpioPin,
+    led_type: LedStripType,
+    brightness: u8,
+    frame: Vec<u8>,
+    pending_write: Option<(u32, Vec<Vec<u8>>)>,
+}
+
+#[derive(Debug)]
+pub(crate) struct VirtualServo {
+    pin: GpioPin,
+    config: ServoConfig,
+    pulse_width: Option<Duration>,
+    sweep: Option<VirtualServoSweep>,
+}
+
+#[derive(Debug)]
+pub(crate) struct VirtualServoSweep {
+    identifier: u32,
+    pulse_width: Duration,
+    pulse_width_per_second: Duration,
+    start: Instant,
+    pending: Arc<Mutex<bool>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct VirtualStepper {
+    config: StepperConfig,
+    motion: Arc<Mutex<VirtualStepperMotion>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct VirtualStepperMotion {
+    position: f64,
+    velocity: f64,
+    target: Option<i64>,
+    stopping: bool,
+    enabled: bool,
+    pending_move: Option<(u32, bool)>,
+    pending_stops: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub(crate) struct PendingPortChange {
+    identifier: u32,
+    pin_mask: u32,
+    levels: u32,
+}
+
+#[derive(Debug)]
+pub(crate) struct I2cChunkedState {
+    address: I2cAddress,
+    pending_write: Vec<u8>,
+}
+
+pub(crate) fn handle_output_report(board: &mut SimulatedBoardState, buffer: &[u8]) {
+    if buffer.first() == Some(&PROTOCOL_INFO_REPORT_ID) {
+        send(board, SimulatedReport::ProtocolInfo);
+        return;
+    }
+
+    if buffer.len() < HOST_REPORT_HEADER_SIZE {
+        let error = FatalError::DeviceProtocolError {
+            error: ProtocolError::PacketTooSmall,
+        };
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::FatalError { error })),
+        );
+        return;
+    }
+
+    let identifier = u32::from_le_bytes([buffer[1], buffer[2], buffer[3], buffer[4]]);
+
+    let (command, remaining) = match postcard::take_from_bytes::<Command>(&buffer[HOST_REPORT_HEADER_SIZE..]) {
+        Ok(x) => x,
+        Err(x) => {
+            let error = FatalError::DeviceProtocolError {
+                error: ProtocolError::from(x),
+            };
+
+            send(
+                board,
+                SimulatedReport::DeviceReport(Box::new(DeviceReport::FatalError { error })),
+            );
+            return;
+        }
+    };
+
+    if let Command::Batch { count } = command {
+        handle_batch(board, identifier, count, remaining);
+        return;
+    }
+
+    // Commands waiting for a signal are answered later, when the awaited signal occurs.
+
+    if let Some(result) = handle_command(board, identifier, command) {
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+fn handle_batch(board: &mut SimulatedBoardState, identifier: u32, count: u8, mut remaining: &[u8]) {
+    let mut device_reports = Vec::with_capacity(count as usize + 1);
+
+    for _ in 0..count {
+        let host_report = match postcard::take_from_bytes::<HostReport>(remaining) {
+            Ok((x, y)) => {
+                remaining = y;
+                x
+            }
+            Err(x) => {
+                let error = FatalError::DeviceProtocolError {
+                    error: ProtocolError::from(x),
+                };
+
+                send(
+                    board,
+                    SimulatedReport::DeviceReport(Box::new(DeviceReport::FatalError { error })),
+                );
+                return;
+            }
+        };
+
+        if let Some(result) = handle_command(board, host_report.identifier, host_report.command) {
+            device_reports.push(DeviceReport::Response {
+                identifier: host_report.identifier,
+                result,
+            });
+        }
+    }
+
+    // The batch is acknowledged after the responses of its commands.
+
+    device_reports.push(DeviceReport::Response {
+        identifier,
+        result: Ok(Response::Batch { result: Ok(()) }),
+    });
+
+    // Responses are packed into as few input reports as possible, like the host packs its commands.
+
+    let capacity = REPORT_SIZE - BATCH_HEADER_SIZE;
+
+    let mut batch = Vec::new();
+    let mut batch_size = 0usize;
+
+    for device_report in device_reports {
+        let size = postcard::to_allocvec(&device_report)
+            .map(|x| x.len())
+            .unwrap_or(REPORT_SIZE);
+
+        if !batch.is_empty() && (batch.len() == BATCH_MAX_COUNT || batch_size + size > capacity) {
+            send(board, SimulatedReport::DeviceReportBatch(std::mem::take(&mut batch)));
+            batch_size = 0;
+        }
+
+        batch_size += size;
+        batch.push(device_report);
+    }
+
+    send(board, SimulatedReport::DeviceReportBatch(batch));
+}
+
+pub(crate) fn write_input_report(report: SimulatedReport, buffer: &mut [u8]) -> Result<usize, String> {
+    buffer.fill(0x00);
+
+    match report {
+        SimulatedReport::ProtocolInfo => {
+            if buffer.len() < PROTOCOL_INFO_BUFFER_SIZE {
+                return Err("Buffer too small for protocol info report.".to_string());
+            }
+
+            buffer[0] = PROTOCOL_INFO_REPORT_ID;
+            buffer[1..3].copy_from_slice(&IOTZIO_PROTOCOL_VERSION.to_le_bytes());
+            buffer[3..5].copy_from_slice(&(HID_DESCRIPTOR.len() as u16).to_le_bytes());
+            buffer[5..5 + HID_DESCRIPTOR.len()].copy_from_slice(&HID_DESCRIPTOR);
+
+            Ok(PROTOCOL_INFO_BUFFER_SIZE)
+        }
+        SimulatedReport::DeviceReport(device_report) => {
+            buffer[0] = REPORT_ID;
+
+            let length = postcard::to_slice(&device_report, &mut buffer[1..])
+                .map_err(|x| format!("Error serializing device report: {0}", x))?
+                .len();
+
+            Ok(length + 1)
+        }
+        SimulatedReport::DeviceReportBatch(device_reports) => {
+            buffer[0] = REPORT_ID;
+
+            let mut length = postcard::to_slice(
+                &DeviceReport::Batch {
+                    count: device_reports.len() as u8,
+                },
+                &mut buffer[1..],
+            )
+            .map_err(|x| format!("Error serializing device report: {0}", x))?
+            .len();
+
+            for device_report in device_reports {
+                length += postcard::to_slice(&device_report, &mut buffer[1 + length..])
+                    .map_err(|x| format!("Error serializing device report: {0}", x))?
+                    .len();
+            }
+
+            Ok(length + 1)
+        }
+    }
+}
+
+pub(crate) fn set_input_level(board: &mut SimulatedBoardState, pin: GpioPin, level: Level) {
+    let previous = get_input_level(board, pin);
+
+    board.input_levels.insert(pin, level);
+
+    update_pending_signals(board, pin, previous, level);
+    update_pending_port_changes(board);
+    update_pin_subscriptions(board, pin, previous, level);
+    update_pending_counts(board, pin, previous, level);
+    update_encoders(board, pin, previous, level);
+    update_pending_pulse_measures(board, pin, previous, level);
+}
+
+pub(crate) fn release_input_level(board: &mut SimulatedBoardState, pin: GpioPin) {
+    let previous = get_input_level(board, pin);
+
+    board.input_levels.remove(&pin);
+
+    let level = get_input_level(board, pin);
+
+    update_pending_signals(board, pin, previous, level);
+    update_pending_port_changes(board);
+    update_pin_subscriptions(board, pin, previous, level);
+    update_pending_counts(board, pin, previous, level);
+    update_encoders(board, pin, previous, level);
+    update_pending_pulse_measures(board, pin, previous, level);
+}
+
+pub(crate) fn get_output_level(board: &SimulatedBoardState, pin: GpioPin) -> Option<Level> {
+    match board.pins.get(&pin) {
+        Some(VirtualPinMode::Output { level }) | Some(VirtualPinMode::PortOutput { level }) => Some(*level),
+        Some(VirtualPinMode::PulseInTrigger { config }) => Some(!config.trigger_level),
+        _ => None,
+    }
+}
+
+pub(crate) fn get_led_strip_colors(board: &SimulatedBoardState, pin: GpioPin) -> Option<Vec<LedColor>> {
+    let led_strip = board.led_strips.iter().find(|x| x.pin == pin)?;
+
+    let scale = |value: u8| (value as u16 * led_strip.brightness as u16 / u8::MAX as u16) as u8;
+
+    let colors = led_strip
+        .frame
+        .chunks(led_strip.led_type.bytes_per_led())
+        .map(|x| LedColor {
+            red: scale(x[1]),
+            green: scale(x[0]),
+            blue: scale(x[2]),
+            white: x.get(3).copied().map(scale).unwrap_or_default(),
+        })
+        .collect();
+
+    Some(colors)
+}
+
+pub(crate) fn get_servo_pulse_width(board: &SimulatedBoardState, pin: GpioPin) -> Option<Duration> {
+    board.servos.iter().find(|x| x.pin == pin).and_then(servo_pulse_width)
+}
+
+#[inline]
+fn send(board: &SimulatedBoardState, report: SimulatedReport) {
+    if let Some(connection) = &board.connection {
+        _ = connection.try_send(report);
+    }
+}
+
+fn get_input_level(board: &SimulatedBoardState, pin: GpioPin) -> Level {
+    match (board.input_levels.get(&pin), board.pins.get(&pin)) {
+        (Some(level), _) => *level,
+        (None, Some(VirtualPinMode::Input { pull: Pull::Up }))
+        | (None, Some(VirtualPinMode::PortInput { pull: Pull::Up }))
+        | (None, Some(VirtualPinMode::Encoder { pull: Pull::Up }))
+        | (None, Some(VirtualPinMode::PulseInEcho { pull: Pull::Up })) => Level::High,
+        _ => Level::Low,
+    }
+}
+
+fn update_pending_signals(board: &mut SimulatedBoardState, pin: GpioPin, previous: Level, level: Level) {
+    let now = Instant::now();
+
+    let mut completed = Vec::new();
+
+    for pending in board.pending_signals.iter_mut().filter(|x| x.pin == pin) {
+        let response = match (pending.request, previous, level, pending.pulse_start) {
+            (SignalTypeRequest::Low, _, Level::Low, _) => Some(SignalTypeResponse::Low),
+            (SignalTypeRequest::High, _, Level::High, _) => Some(SignalTypeResponse::High),
+            (SignalTypeRequest::FallingEdge, Level::High, Level::Low, _) => Some(SignalTypeResponse::FallingEdge),
+            (SignalTypeRequest::RisingEdge, Level::Low, Level::High, _) => Some(SignalTypeResponse::RisingEdge),
+            (SignalTypeRequest::AnyEdge, x, y, _) if x != y => Some(SignalTypeResponse::AnyEdge),
+            (SignalTypeRequest::HighPulse | SignalTypeRequest::LowPulse | SignalTypeRequest::AnyPulse, x, y, _)
+                if x == y =>
+            {
+                None
+            }
+            (SignalTypeRequest::HighPulse, _, Level::Low, Some((Level::High, start))) => {
+                Some(SignalTypeResponse::HighPulse(now - start))
+            }
+            (SignalTypeRequest::LowPulse, _, Level::High, Some((Level::Low, start))) => {
+                Some(SignalTypeResponse::LowPulse(now - start))
+            }
+            (SignalTypeRequest::AnyPulse, _, _, Some((_, start))) => Some(SignalTypeResponse::AnyPulse(now - start)),
+            (SignalTypeRequest::HighPulse, _, Level::High, None)
+            | (SignalTypeRequest::LowPulse, _, Level::Low, None)
+            | (SignalTypeRequest::AnyPulse, _, _, None) => {
+                pending.pulse_start = Some((level, now));
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(response) = response {
+            completed.push((pending.identifier, response));
+        }
+    }
+
+    board
+        .pending_signals
+        .retain(|x| !completed.iter().any(|(identifier, _)| *identifier == x.identifier));
+
+    for (identifier, signal_type) in completed {
+        let result = Ok(Response::InputPin_WaitForSignal {
+            result: Ok(signal_type),
+        });
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+fn update_pending_pulse_measures(board: &mut SimulatedBoardState, pin: GpioPin, previous: Level, level: Level) {
+    if previous == level {
+        return;
+    }
+
+    let now = Instant::now();
+
+    let mut completed = Vec::new();
+
+    for pending in board.pending_pulse_measures.iter_mut().filter(|x| x.echo_pin == pin) {
+        match (level == pending.echo_level, pending.pulse_start) {
+            (true, None) => pending.pulse_start = Some(now),
+            (false, Some(start)) if std::mem::take(&mut *pending.pending.lock().unwrap()) => {
+                completed.push((pending.identifier, now - start));
+            }
+            _ => {}
+        }
+    }
+
+    // Measurements answered by their timeout are gone as well.
+
+    board.pending_pulse_measures.retain(|x| *x.pending.lock().unwrap());
+
+    for (identifier, pulse_width) in completed {
+        let result = Ok(Response::PulseIn_Measure {
+            result: Ok(pulse_width),
+        });
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+fn update_pin_subscriptions(board: &mut SimulatedBoardState, pin: GpioPin, previous: Level, level: Level) {
+    if previous == level {
+        return;
+    }
+
+    let triggered: Vec<u32> = board
+        .pin_subscriptions
+        .iter()
+        .filter(|x| x.pin == pin)
+        .filter(|x| {
+            matches!(
+                (x.trigger, level),
+                (InterruptTrigger::LevelLow | InterruptTrigger::EdgeLow, Level::Low)
+                    | (InterruptTrigger::LevelHigh | InterruptTrigger::EdgeHigh, Level::High)
+                    | (InterruptTrigger::AnyEdge, _)
+            )
+        })
+        .map(|x| x.subscription)
+        .collect();
+
+    for subscription in triggered {
+        send_pin_event(board, subscription, level);
+    }
+}
+
+fn update_pending_counts(board: &mut SimulatedBoardState, pin: GpioPin, previous: Level, level: Level) {
+    let now = Instant::now();
+
+    board.pending_counts.retain(|x| x.deadline > now);
+
+    for pending in board.pending_counts.iter().filter(|x| x.pin == pin) {
+        if matches!(
+            (pending.edge, previous, level),
+            (CounterEdge::Rising, Level::Low, Level::High) | (CounterEdge::Falling, Level::High, Level::Low)
+        ) {
+            if let Some(count) = pending.count.lock().unwrap().as_mut() {
+                *count += 1;
+            }
+        }
+    }
+}
+
+fn update_encoders(board: &mut SimulatedBoardState, pin: GpioPin, previous: Level, level: Level) {
+    if previous == level {
+        return;
+    }
+
+    // Signal A leading signal B counts up: 00 -> 10 -> 11 -> 01 -> 00.
+
+    let steps: Vec<(usize, i64)> = board
+        .encoders
+        .iter()
+        .enumerate()
+        .filter_map(|(index, x)| {
+            let same_level = get_input_level(board, x.pin_a) == get_input_level(board, x.pin_b);
+
+            match (pin == x.pin_a, pin == x.pin_b, same_level) {
+                (true, _, false) | (_, true, true) => Some((index, 1)),
+                (true, _, true) | (_, true, false) => Some((index, -1)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    for (index, step) in steps {
+        board.encoders[index].position += step;
+    }
+
+    if level == Level::High {
+        for encoder in board.encoders.iter_mut().filter(|x| x.index_pin == Some(pin)) {
+            encoder.index_position = Some(encoder.position);
+        }
+    }
+}
+
+fn interrupt_pending_counts(board: &mut SimulatedBoardState, pin: GpioPin) {
+    let interrupted: Vec<u32> = board
+        .pending_counts
+        .iter()
+        .filter(|x| x.pin == pin)
+        .map(|x| {
+            // The measurement thread does not answer anymore.
+
+            *x.count.lock().unwrap() = None;
+
+            x.identifier
+        })
+        .collect();
+
+    board.pending_counts.retain(|x| x.pin != pin);
+
+    for identifier in interrupted {
+        let result = Err(ModuleError::ModuleCommandInterrupted);
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+fn send_pin_event(board: &SimulatedBoardState, subscription: u32, level: Level) {
+    let event = DeviceEvent::InputPin_Triggered {
+        level,
+        timestamp: board.power_on.elapsed(),
+    };
+
+    send(
+        board,
+        SimulatedReport::DeviceReport(Box::new(DeviceReport::Event { subscription, event })),
+    );
+}
+
+fn update_pending_port_changes(board: &mut SimulatedBoardState) {
+    let completed: Vec<(u32, u32)> = board
+        .pending_port_changes
+        .iter()
+        .filter_map(|x| {
+            let levels = get_port_levels(board, x.pin_mask);
+
+            (levels != x.levels).then_some((x.identifier, levels))
+        })
+        .collect();
+
+    board
+        .pending_port_changes
+        .retain(|x| !completed.iter().any(|(identifier, _)| *identifier == x.identifier));
+
+    for (identifier, levels) in completed {
+        let result = Ok(Response::InputPort_WaitForChange { result: Ok(levels) });
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+fn get_port_levels(board: &SimulatedBoardState, pin_mask: u32) -> u32 {
+    mask_pins(pin_mask)
+        .into_iter()
+        .filter(|x| get_input_level(board, *x) == Level::High)
+        .fold(0, |levels, x| levels | gpio_bit(x))
+}
+
+fn handle_command(
+    board: &mut SimulatedBoardState,
+    identifier: u32,
+    command: Command,
+) -> Option<Result<Response, ModuleError>> {
+    let result = match command {
+        Command::Initialize => Ok(Response::Initialize {
+            board_info: BoardInfo {
+                version: board.version,
+                protocol_version: IOTZIO_PROTOCOL_VERSION,
+                serial_number: board.serial_number.clone(),
+            },
+        }),
+        Command::InputPin_New { pin, pull_setting, .. } => {
+            new_pin(board, pin, VirtualPinMode::Input { pull: pull_setting })
+                .map(|_| Response::InputPin_New { result: Ok(()) })
+        }
+        Command::InputPin_CancelWaitForSignal { pin } => match board.pins.get(&pin) {
+            Some(VirtualPinMode::Input { .. }) => {
+                interrupt_pending_signals(board, pin);
+
+                Ok(Response::InputPin_CancelWaitForSignal { result: Ok(()) })
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::InputPin_Drop { pin } => drop_pin(board, pin).map(|_| Response::InputPin_Drop { result: Ok(()) }),
+        Command::InputPin_Subscribe {
+            pin,
+            trigger,
+            subscription,
+        } => match board.pins.get(&pin) {
+            Some(VirtualPinMode::Input { .. }) => match board.pin_subscriptions.iter().any(|x| x.pin == pin) {
+                true => Ok(Response::InputPin_Subscribe {
+                    result: Err(InputPinModuleError::AlreadySubscribed),
+                }),
+                false => {
+                    board.pin_subscriptions.push(PinSubscription {
+                        pin,
+                        trigger,
+                        subscription,
+                    });
+
+                    // Level triggers fire right away, if the pin already is at that level.
+
+                    let level = get_input_level(board, pin);
+
+                    if matches!(
+                        (trigger, level),
+                        (InterruptTrigger::LevelLow, Level::Low) | (InterruptTrigger::LevelHigh, Level::High)
+                    ) {
+                        send_pin_event(board, subscription, level);
+                    }
+
+                    Ok(Response::InputPin_Subscribe { result: Ok(()) })
+                }
+            },
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::InputPin_Unsubscribe { pin } => match board.pins.get(&pin) {
+            Some(VirtualPinMode::Input { .. }) => {
+                board.pin_subscriptions.retain(|x| x.pin != pin);
+
+                Ok(Response::InputPin_Unsubscribe { result: Ok(()) })
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::InputPin_GetLevel { pin } => match board.pins.get(&pin) {
+            Some(VirtualPinMode::Input { .. }) => Ok(Response::InputPin_GetLevel {
+                result: Ok(get_input_level(board, pin)),
+            }),
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::InputPin_WaitForSignal { pin, signal_type } => match board.pins.get(&pin) {
+            Some(VirtualPinMode::Input { .. }) => {
+                let level = get_input_level(board, pin);
+
+                match (signal_type, level) {
+                    (SignalTypeRequest::Low, Level::Low) => Ok(Response::InputPin_WaitForSignal {
+                        result: Ok(SignalTypeResponse::Low),
+                    }),
+                    (SignalTypeRequest::High, Level::High) => Ok(Response::InputPin_WaitForSignal {
+                        result: Ok(SignalTypeResponse::High),
+                    }),
+                    _ => {
+                        board.pending_signals.push(PendingSignal {
+                            identifier,
+                            pin,
+                            request: signal_type,
+                            pulse_start: None,
+                        });
+
+                        return None;
+                    }
+                }
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::OutputPin_New { pin, initial_level, .. } => {
+            new_pin(board, pin, VirtualPinMode::Output { level: initial_level })
+                .map(|_| Response::OutputPin_New { result: Ok(()) })
+        }
+        Command::OutputPin_Drop { pin } => drop_pin(board, pin).map(|_| Response::OutputPin_Drop { result: Ok(()) }),
+        Command::OutputPin_SetLevel { pin, level } => match board.pins.get_mut(&pin) {
+            Some(VirtualPinMode::Output { level: x }) => {
+                *x = level;
+
+                Ok(Response::OutputPin_SetLevel { result: Ok(()) })
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::I2c_New { config } => {
+            let bus_number = match config {
+                I2cConfig::I2c0 { .. } => I2cBusNumber::I2c0,
+                I2cConfig::I2c1 { .. } => I2cBusNumber::I2c1,
+            };
+
+            match board.i2c_buses.contains_key(&bus_number) {
+                true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                false => {
+                    board.i2c_buses.insert(bus_number, None);
+
+                    Ok(Response::I2c_New { result: Ok(()) })
+                }
+            }
+        }
+        Command::I2c_Drop { identifier } => match board.i2c_buses.remove(&identifier) {
+            Some(_) => Ok(Response::I2c_Drop { result: Ok(()) }),
+            None => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::I2c_ReadSingle {
+            identifier,
+            address,
+            buffer_size,
+        } => i2c_bus(board, identifier).map(|_| Response::I2c_ReadSingle {
+            result: i2c_read(board, identifier, address, buffer_size),
+        }),
+        Command::I2c_WriteSingle {
+            identifier,
+            address,
+            bytes,
+        } => i2c_bus(board, identifier).map(|_| Response::I2c_WriteSingle {
+            result: i2c_write(board, identifier, address, &bytes),
+        }),
+        Command::I2c_WriteReadSingle {
+            identifier,
+            address,
+            bytes,
+            buffer_size,
+        } => i2c_bus(board, identifier).map(|_| Response::I2c_WriteReadSingle {
+            result: i2c_write(board, identifier, address, &bytes)
+                .and_then(|_| i2c_read(board, identifier, address, buffer_size)),
+        }),
+        Command::I2c_StartReadChunked {
+            identifier, address, ..
+        } => i2c_start_chunked(board, identifier, address).map(|_| Response::I2c_StartReadChunked { result: Ok(()) }),
+        Command::I2c_ReadChunk {
+            identifier,
+            buffer_size,
+            ..
+        } => i2c_chunked_read(board, identifier, buffer_size).map(|result| Response::I2c_ReadChunk { result }),
+        Command::I2c_StopReadChunked { identifier } => {
+            i2c_stop_chunked(board, identifier).map(|result| Response::I2c_StopReadChunked { result })
+        }
+        Command::I2c_StartWriteChunked {
+            identifier, address, ..
+        } => i2c_start_chunked(board, identifier, address).map(|_| Response::I2c_StartWriteChunked { result: Ok(()) }),
+        Command::I2c_WriteChunk { identifier, bytes, .. } => {
+            i2c_chunked_write(board, identifier, &bytes).map(|_| Response::I2c_WriteChunk { result: Ok(()) })
+        }
+        Command::I2c_StopWriteChunked { identifier } => {
+            i2c_stop_chunked(board, identifier).map(|result| Response::I2c_StopWriteChunked { result })
+        }
+        Command::I2c_Transaction {
+            identifier,
+            address,
+            operations,
+            bytes,
+        } => i2c_bus(board, identifier).map(|_| Response::I2c_Transaction {
+            result: i2c_transaction(board, identifier, address, &operations, &bytes),
+        }),
+        Command::I2c_StartTransactionChunked { identifier, address } => i2c_start_chunked(board, identifier, address)
+            .map(|_| Response::I2c_StartTransactionChunked { result: Ok(()) }),
+        Command::I2c_TransactionReadChunk {
+            identifier,
+            buffer_size,
+            ..
+        } => {
+            i2c_chunked_read(board, identifier, buffer_size).map(|result| Response::I2c_TransactionReadChunk { result })
+        }
+        Command::I2c_TransactionWriteChunk { identifier, bytes, end } => {
+            i2c_transaction_write_chunk(board, identifier, &bytes, end)
+                .map(|result| Response::I2c_TransactionWriteChunk { result })
+        }
+        Command::I2c_StopTransactionChunked { identifier } => {
+            i2c_stop_chunked(board, identifier).map(|result| Response::I2c_StopTransactionChunked { result })
+        }
+        Command::I2c_Scan { identifier } => i2c_bus(board, identifier).map(|_| {
+            let addresses = (0x08u16..=0x77)
+                .filter(|x| {
+                    board
+                        .i2c_devices
+                        .iter()
+                        .any(|(bus, address, _)| *bus == identifier && *address == I2cAddress::SevenBit { address: *x })
+                })
+                .map(|x| x as u8)
+                .collect::<BusBuffer>();
+
+            Response::I2c_Scan { result: Ok(addresses) }
+        }),
+        Command::I2c_Recover { identifier } => {
+            i2c_bus(board, identifier).map(|_| Response::I2c_Recover { result: Ok(()) })
+        }
+        Command::OutputPort_New { pins } => {
+            match pins
+                .iter()
+                .enumerate()
+                .find(|(index, x)| pins[..*index].iter().any(|y| y.pin == x.pin))
+            {
+                Some((_, x)) => Ok(Response::OutputPort_New {
+                    result: Err(OutputPortModuleError::DuplicatePin { pin: x.pin }),
+                }),
+                None => match pins.iter().any(|x| board.pins.contains_key(&x.pin)) {
+                    true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                    false => {
+                        for x in pins.iter() {
+                            board
+                                .pins
+                                .insert(x.pin, VirtualPinMode::PortOutput { level: x.initial_level });
+                        }
+
+                        Ok(Response::OutputPort_New { result: Ok(()) })
+                    }
+                },
+            }
+        }
+        Command::OutputPort_Drop { pin_mask } => port_output_pins(board, pin_mask).map(|pins| {
+            for pin in pins {
+                board.pins.remove(&pin);
+            }
+
+            Response::OutputPort_Drop { result: Ok(()) }
+        }),
+        Command::OutputPort_SetLevels { pin_mask, mask, value } => port_output_pins(board, pin_mask).map(|pins| {
+            for pin in pins.into_iter().filter(|x| mask & gpio_bit(*x) != 0) {
+                let level = match value & gpio_bit(pin) != 0 {
+                    true => Level::High,
+                    false => Level::Low,
+                };
+
+                board.pins.insert(pin, VirtualPinMode::PortOutput { level });
+            }
+
+            Response::OutputPort_SetLevels { result: Ok(()) }
+        }),
+        Command::InputPort_New { pins } => {
+            match pins
+                .iter()
+                .enumerate()
+                .find(|(index, x)| pins[..*index].iter().any(|y| y.pin == x.pin))
+            {
+                Some((_, x)) => Ok(Response::InputPort_New {
+                    result: Err(InputPortModuleError::DuplicatePin { pin: x.pin }),
+                }),
+                None => match pins.iter().any(|x| board.pins.contains_key(&x.pin)) {
+                    true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                    false => {
+                        for x in pins.iter() {
+                            board
+                                .pins
+                                .insert(x.pin, VirtualPinMode::PortInput { pull: x.pull_setting });
+                        }
+
+                        Ok(Response::InputPort_New { result: Ok(()) })
+                    }
+                },
+            }
+        }
+        Command::InputPort_Drop { pin_mask } => port_input_pins(board, pin_mask).map(|pins| {
+            for pin in pins {
+                board.pins.remove(&pin);
+            }
+
+            interrupt_pending_port_changes(board, pin_mask);
+
+            Response::InputPort_Drop { result: Ok(()) }
+        }),
+        Command::InputPort_GetLevels { pin_mask } => {
+            port_input_pins(board, pin_mask).map(|_| Response::InputPort_GetLevels {
+                result: Ok(get_port_levels(board, pin_mask)),
+            })
+        }
+        Command::InputPort_WaitForChange { pin_mask, levels } => match port_input_pins(board, pin_mask) {
+            Ok(_) => {
+                let current = get_port_levels(board, pin_mask);
+
+                match current != levels & pin_mask {
+                    true => Ok(Response::InputPort_WaitForChange { result: Ok(current) }),
+                    false => {
+                        board.pending_port_changes.push(PendingPortChange {
+                            identifier,
+                            pin_mask,
+                            levels: current,
+                        });
+
+                        return None;
+                    }
+                }
+            }
+            Err(x) => Err(x),
+        },
+        Command::InputPort_CancelWaitForChange { pin_mask } => port_input_pins(board, pin_mask).map(|_| {
+            interrupt_pending_port_changes(board, pin_mask);
+
+            Response::InputPort_CancelWaitForChange { result: Ok(()) }
+        }),
+        Command::Counter_New { pin, edge } => {
+            // A counter occupies the whole PWM slice of its pin.
+
+            let slice = pwm_slice(pin.into());
+
+            match board.pins.iter().any(|(x, mode)| {
+                matches!(mode, VirtualPinMode::Counter { .. } | VirtualPinMode::Servo { .. }) && pwm_slice(*x) == slice
+            }) {
+                true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                false => new_pin(board, pin.into(), VirtualPinMode::Counter { edge })
+                    .map(|_| Response::Counter_New { result: Ok(()) }),
+            }
+        }
+        Command::Counter_Drop { pin } => match board.pins.get(&pin.into()) {
+            Some(VirtualPinMode::Counter { .. }) => {
+                interrupt_pending_counts(board, pin.into());
+
+                drop_pin(board, pin.into()).map(|_| Response::Counter_Drop { result: Ok(()) })
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::Counter_Measure { pin, gate_time } => match board.pins.get(&pin.into()) {
+            Some(VirtualPinMode::Counter { edge }) => {
+                let count = Arc::new(Mutex::new(Some(0)));
+
+                board.pending_counts.push(PendingCount {
+                    identifier,
+                    pin: pin.into(),
+                    edge: *edge,
+                    deadline: Instant::now() + gate_time,
+                    count: count.clone(),
+                });
+
+                // The response is sent, once the gate closes.
+
+                if let Some(connection) = board.connection.clone() {
+                    std::thread::spawn(move || {
+                        std::thread::sleep(gate_time);
+
+                        if let Some(count) = *count.lock().unwrap() {
+                            let result = Ok(Response::Counter_Measure {
+                                result: Ok(CounterMeasurement { count, gate_time }),
+                            });
+
+                            _ = connection.try_send(SimulatedReport::DeviceReport(Box::new(DeviceReport::Response {
+                                identifier,
+                                result,
+                            })));
+                        }
+                    });
+                }
+
+                return None;
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::QuadratureEncoder_New {
+            pin_a,
+            pin_b,
+            index_pin,
+            pull_setting,
+        } => {
+            let pins = [Some(pin_a), Some(pin_b), index_pin];
+
+            match pins.iter().flatten().any(|x| board.pins.contains_key(x)) {
+                true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                false => {
+                    for pin in pins.iter().flatten() {
+                        board.pins.insert(*pin, VirtualPinMode::Encoder { pull: pull_setting });
+                    }
+
+                    board.encoders.push(VirtualEncoder {
+                        pin_a,
+                        pin_b,
+                        index_pin,
+                        position: 0,
+                        index_position: None,
+                    });
+
+                    Ok(Response::QuadratureEncoder_New {
+                        result: Ok(board.power_on.elapsed()),
+                    })
+                }
+            }
+        }
+        Command::QuadratureEncoder_Drop { pin_a } => encoder(board, pin_a).map(|index| {
+            let encoder = board.encoders.remove(index);
+
+            for pin in [Some(encoder.pin_a), Some(encoder.pin_b), encoder.index_pin]
+                .iter()
+                .flatten()
+            {
+                board.pins.remove(pin);
+            }
+
+            Response::QuadratureEncoder_Drop { result: Ok(()) }
+        }),
+        Command::QuadratureEncoder_Read { pin_a } => encoder(board, pin_a).map(|index| {
+            let encoder = &board.encoders[index];
+
+            Response::QuadratureEncoder_Read {
+                result: Ok(QuadratureEncoderReading {
+                    position: encoder.position,
+                    index_position: encoder.index_position,
+                    timestamp: board.power_on.elapsed(),
+                }),
+            }
+        }),
+        Command::QuadratureEncoder_Reset { pin_a } => encoder(board, pin_a).map(|index| {
+            board.encoders[index].position = 0;
+            board.encoders[index].index_position = None;
+
+            Response::QuadratureEncoder_Reset {
+                result: Ok(board.power_on.elapsed()),
+            }
+        }),
+        Command::LedStrip_New {
+            pin,
+            led_type,
+            led_count,
+            brightness,
+        } => match (1..=LED_STRIP_MAX_LED_COUNT).contains(&led_count) {
+            true => new_pin(board, pin, VirtualPinMode::LedStrip).map(|_| {
+                board.led_strips.push(VirtualLedStrip {
+                    pin,
+                    led_type,
+                    brightness,
+                    frame: vec![0; led_count as usize * led_type.bytes_per_led()],
+                    pending_write: None,
+                });
+
+                Response::LedStrip_New { result: Ok(()) }
+            }),
+            false => Ok(Response::LedStrip_New {
+                result: Err(LedStripModuleError::LedCountOutOfRange {
+                    max: LED_STRIP_MAX_LED_COUNT,
+                }),
+            }),
+        },
+        Command::LedStrip_Drop { pin } => led_strip(board, pin).and_then(|index| {
+            board.led_strips.remove(index);
+
+            drop_pin(board, pin).map(|_| Response::LedStrip_Drop { result: Ok(()) })
+        }),
+        Command::LedStrip_WriteSingle { pin, bytes } => {
+            led_strip(board, pin).map(|index| Response::LedStrip_WriteSingle {
+                result: led_strip_show(&mut board.led_strips[index], &bytes),
+            })
+        }
+        Command::LedStrip_StartWriteChunked { pin, chunks_count } => led_strip(board, pin).map(|index| {
+            board.led_strips[index].pending_write = Some((chunks_count, Vec::new()));
+
+            Response::LedStrip_StartWriteChunked { result: Ok(()) }
+        }),
+        Command::LedStrip_WriteChunk { pin, bytes, .. } => {
+            led_strip(board, pin).and_then(|index| match &mut board.led_strips[index].pending_write {
+                Some((_, chunks)) => {
+                    chunks.push(bytes.to_vec());
+
+                    Ok(Response::LedStrip_WriteChunk { result: Ok(()) })
+                }
+                None => Err(ModuleError::ModuleCommandInterrupted),
+            })
+        }
+        Command::LedStrip_StopWriteChunked { pin } => led_strip(board, pin).map(|index| {
+            let led_strip = &mut board.led_strips[index];
+
+            // Like the device, an incomplete write is discarded instead of showing partially updated colors.
+
+            let result = match led_strip.pending_write.take() {
+                Some((chunks_count, chunks)) if chunks.len() == chunks_count as usize => {
+                    led_strip_show(led_strip, &chunks.concat())
+                }
+                _ => Ok(()),
+            };
+
+            Response::LedStrip_StopWriteChunked { result }
+        }),
+        Command::LedStrip_SetBrightness { pin, brightness } => led_strip(board, pin).map(|index| {
+            board.led_strips[index].brightness = brightness;
+
+            Response::LedStrip_SetBrightness { result: Ok(()) }
+        }),
+        Command::Servo_New {
+            pin,
+            config,
+            pulse_width,
+        } => {
+            // Servos share the PWM slice only with servos of the same frequency.
+
+            let slice = pwm_slice(pin);
+
+            let blocked = board.pins.iter().any(|(x, mode)| match mode {
+                VirtualPinMode::Counter { .. } => pwm_slice(*x) == slice,
+                VirtualPinMode::Servo { frequency_hz } => {
+                    pwm_slice(*x) == slice && *frequency_hz != config.frequency_hz
+                }
+                _ => false,
+            });
+
+            match (config.frequency_hz, blocked) {
+                (0, _) => Ok(Response::Servo_New {
+                    result: Err(ServoModuleError::FrequencyTooLow),
+                }),
+                (_, true) => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                (frequency_hz, false) => new_pin(board, pin, VirtualPinMode::Servo { frequency_hz }).map(|_| {
+                    board.servos.push(VirtualServo {
+                        pin,
+                        config,
+                        pulse_width,
+                        sweep: None,
+                    });
+
+                    Response::Servo_New { result: Ok(()) }
+                }),
+            }
+        }
+        Command::Servo_Drop { pin } => servo(board, pin).and_then(|index| {
+            interrupt_servo_sweep(board, index);
+
+            board.servos.remove(index);
+
+            drop_pin(board, pin).map(|_| Response::Servo_Drop { result: Ok(()) })
+        }),
+        Command::Servo_SetPulseWidth { pin, pulse_width } => servo(board, pin).map(|index| {
+            let config = board.servos[index].config;
+
+            let result = match (config.min_pulse_width..=config.max_pulse_width).contains(&pulse_width) {
+                true => {
+                    interrupt_servo_sweep(board, index);
+
+                    board.servos[index].pulse_width = Some(pulse_width);
+
+                    Ok(())
+                }
+                false => Err(ServoModuleError::PulseWidthOutOfRange),
+            };
+
+            Response::Servo_SetPulseWidth { result }
+        }),
+        Command::Servo_Sweep {
+            pin,
+            pulse_width,
+            pulse_width_per_second,
+        } => match servo(board, pin) {
+            Ok(index) => {
+                let config = board.servos[index].config;
+
+                if !(config.min_pulse_width..=config.max_pulse_width).contains(&pulse_width) {
+                    return Some(Ok(Response::Servo_Sweep {
+                        result: Err(ServoModuleError::PulseWidthOutOfRange),
+                    }));
+                }
+
+                interrupt_servo_sweep(board, index);
+
+                let servo = &mut board.servos[index];
+
+                // Without a known position, the target is set immediately.
+
+                let current = match servo.pulse_width {
+                    Some(x) if !pulse_width_per_second.is_zero() => x,
+                    _ => {
+                        servo.pulse_width = Some(pulse_width);
+
+                        return Some(Ok(Response::Servo_Sweep { result: Ok(()) }));
+                    }
+                };
+
+                let distance = current.abs_diff(pulse_width);
+                let duration = distance.div_f64(pulse_width_per_second.as_secs_f64());
+
+                let pending = Arc::new(Mutex::new(true));
+
+                servo.sweep = Some(VirtualServoSweep {
+                    identifier,
+                    pulse_width,
+                    pulse_width_per_second,
+                    start: Instant::now(),
+                    pending: pending.clone(),
+                });
+
+                // The response is sent, once the target is reached.
+
+                if let Some(connection) = board.connection.clone() {
+                    std::thread::spawn(move || {
+                        std::thread::sleep(duration);
+
+                        if std::mem::take(&mut *pending.lock().unwrap()) {
+                            let result = Ok(Response::Servo_Sweep { result: Ok(()) });
+
+                            _ = connection.try_send(SimulatedReport::DeviceReport(Box::new(DeviceReport::Response {
+                                identifier,
+                                result,
+                            })));
+                        }
+                    });
+                }
+
+                return None;
+            }
+            Err(x) => Err(x),
+        },
+        Command::Stepper_New {
+            config,
+            position,
+            enabled,
+        } => {
+            let pins = [Some(config.step_pin), Some(config.dir_pin), config.enable_pin];
+
+            match pins.iter().flatten().any(|x| board.pins.contains_key(x)) {
+                true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+                false => {
+                    for pin in pins.iter().flatten() {
+                        board.pins.insert(*pin, VirtualPinMode::Stepper);
+                    }
+
+                    let motion = Arc::new(Mutex::new(VirtualStepperMotion {
+                        position: position as f64,
+                        velocity: 0.0,
+                        target: None,
+                        stopping: false,
+                        enabled,
+                        pending_move: None,
+                        pending_stops: Vec::new(),
+                    }));
+
+                    // Step generation runs on its own, until the stepper is dropped.
+
+                    if let Some(connection) = board.connection.clone() {
+                        let motion = Arc::downgrade(&motion);
+
+                        std::thread::spawn(move || run_stepper(config, motion, connection));
+                    }
+
+                    board.steppers.push(VirtualStepper { config, motion });
+
+                    Ok(Response::Stepper_New { result: Ok(()) })
+                }
+            }
+        }
+        Command::Stepper_Drop { step_pin } => stepper(board, step_pin).map(|index| {
+            let stepper = board.steppers.remove(index);
+
+            let responses = stepper_halt(&mut stepper.motion.lock().unwrap());
+            send_responses(board, responses);
+
+            for pin in [
+                Some(stepper.config.step_pin),
+                Some(stepper.config.dir_pin),
+                stepper.config.enable_pin,
+            ]
+            .iter()
+            .flatten()
+            {
+                board.pins.remove(pin);
+            }
+
+            Response::Stepper_Drop { result: Ok(()) }
+        }),
+        Command::Stepper_MoveTo { step_pin, position } => {
+            return stepper_move(board, identifier, step_pin, |_| position, false);
+        }
+        Command::Stepper_MoveBy { step_pin, steps } => {
+            return stepper_move(board, identifier, step_pin, |x| x + steps, true);
+        }
+        Command::Stepper_Stop { step_pin } => match stepper(board, step_pin) {
+            Ok(index) => {
+                let motion = board.steppers[index].motion.clone();
+                let mut motion = motion.lock().unwrap();
+
+                if motion.target.is_none() && motion.velocity == 0.0 {
+                    return Some(Ok(Response::Stepper_Stop {
+                        result: Ok(motion.position.round() as i64),
+                    }));
+                }
+
+                // The response is sent, once the stepper decelerated to standstill.
+
+                motion.stopping = true;
+                motion.pending_stops.push(identifier);
+
+                return None;
+            }
+            Err(x) => Err(x),
+        },
+        Command::Stepper_EmergencyStop { step_pin } => stepper(board, step_pin).map(|index| {
+            let motion = board.steppers[index].motion.clone();
+            let mut motion = motion.lock().unwrap();
+
+            let responses = stepper_halt(&mut motion);
+            send_responses(board, responses);
+
+            Response::Stepper_EmergencyStop {
+                result: Ok(motion.position.round() as i64),
+            }
+        }),
+        Command::Stepper_GetStatus { step_pin } => stepper(board, step_pin).map(|index| {
+            let motion = board.steppers[index].motion.lock().unwrap();
+
+            Response::Stepper_GetStatus {
+                result: Ok(StepperStatus {
+                    position: motion.position.round() as i64,
+                    moving: motion.target.is_some() || motion.velocity != 0.0,
+                }),
+            }
+        }),
+        Command::Stepper_SetPosition { step_pin, position } => stepper(board, step_pin).map(|index| {
+            let mut motion = board.steppers[index].motion.lock().unwrap();
+
+            let result = match motion.target.is_some() || motion.velocity != 0.0 {
+                true => Err(StepperModuleError::Moving),
+                false => {
+                    motion.position = position as f64;
+
+                    Ok(())
+                }
+            };
+
+            Response::Stepper_SetPosition { result }
+        }),
+        Command::Stepper_SetEnabled { step_pin, enabled } => stepper(board, step_pin).map(|index| {
+            let motion = board.steppers[index].motion.clone();
+            let mut motion = motion.lock().unwrap();
+
+            // Disabling the driver releases the motor, so that a running move ends at once.
+
+            if !enabled {
+                let responses = stepper_halt(&mut motion);
+                send_responses(board, responses);
+            }
+
+            motion.enabled = enabled;
+
+            Response::Stepper_SetEnabled { result: Ok(()) }
+        }),
+        Command::PulseIn_New { config } => match [config.trigger_pin, config.echo_pin]
+            .iter()
+            .any(|x| board.pins.contains_key(x))
+        {
+            true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+            false => {
+                board
+                    .pins
+                    .insert(config.trigger_pin, VirtualPinMode::PulseInTrigger { config });
+                board.pins.insert(
+                    config.echo_pin,
+                    VirtualPinMode::PulseInEcho {
+                        pull: config.echo_pull_setting,
+                    },
+                );
+
+                Ok(Response::PulseIn_New { result: Ok(()) })
+            }
+        },
+        Command::PulseIn_Drop { trigger_pin } => match board.pins.get(&trigger_pin) {
+            Some(VirtualPinMode::PulseInTrigger { config }) => {
+                let config = *config;
+
+                let interrupted: Vec<u32> = board
+                    .pending_pulse_measures
+                    .iter()
+                    .filter(|x| x.echo_pin == config.echo_pin && std::mem::take(&mut *x.pending.lock().unwrap()))
+                    .map(|x| x.identifier)
+                    .collect();
+
+                board.pending_pulse_measures.retain(|x| x.echo_pin != config.echo_pin);
+
+                send_responses(
+                    board,
+                    interrupted
+                        .into_iter()
+                        .map(|x| (x, Err(ModuleError::ModuleCommandInterrupted)))
+                        .collect(),
+                );
+
+                board.pins.remove(&config.trigger_pin);
+                board.pins.remove(&config.echo_pin);
+
+                Ok(Response::PulseIn_Drop { result: Ok(()) })
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        Command::PulseIn_Measure { trigger_pin, timeout } => match board.pins.get(&trigger_pin) {
+            Some(VirtualPinMode::PulseInTrigger { config }) => {
+                let pending = Arc::new(Mutex::new(true));
+
+                // Only an echo pulse starting after the trigger pulse is measured.
+
+                board.pending_pulse_measures.push(PendingPulseMeasure {
+                    identifier,
+                    echo_pin: config.echo_pin,
+                    echo_level: config.echo_level,
+                    pulse_start: None,
+                    pending: pending.clone(),
+                });
+
+                if let Some(connection) = board.connection.clone() {
+                    std::thread::spawn(move || {
+                        std::thread::sleep(timeout);
+
+                        if std::mem::take(&mut *pending.lock().unwrap()) {
+                            let result = Ok(Response::PulseIn_Measure {
+                                result: Err(PulseInModuleError::Timeout),
+                            });
+
+                            _ = connection.try_send(SimulatedReport::DeviceReport(Box::new(DeviceReport::Response {
+                                identifier,
+                                result,
+                            })));
+                        }
+                    });
+                }
+
+                return None;
+            }
+            _ => Err(ModuleError::ModuleInstanceNotFound),
+        },
+        _ => Err(ModuleError::UnknownCommand),
+    };
+
+    Some(result)
+}
+
+#[inline]
+fn gpio_bit(pin: GpioPin) -> u32 {
+    1 << u8::from(pin)
+}
+
+#[inline]
+fn encoder(board: &SimulatedBoardState, pin_a: GpioPin) -> Result<usize, ModuleError> {
+    board
+        .encoders
+        .iter()
+        .position(|x| x.pin_a == pin_a)
+        .ok_or(ModuleError::ModuleInstanceNotFound)
+}
+
+#[inline]
+fn led_strip(board: &SimulatedBoardState, pin: GpioPin) -> Result<usize, ModuleError> {
+    board
+        .led_strips
+        .iter()
+        .position(|x| x.pin == pin)
+        .ok_or(ModuleError::ModuleInstanceNotFound)
+}
+
+fn led_strip_show(led_strip: &mut VirtualLedStrip, bytes: &[u8]) -> Result<(), LedStripModuleError> {
+    match led_strip.frame.get_mut(..bytes.len()) {
+        Some(frame) => {
+            frame.copy_from_slice(bytes);
+
+            Ok(())
+        }
+        None => Err(LedStripModuleError::TooManyColors {
+            led_count: (led_strip.frame.len() / led_strip.led_type.bytes_per_led()) as u16,
+        }),
+    }
+}
+
+#[inline]
+fn servo(board: &SimulatedBoardState, pin: GpioPin) -> Result<usize, ModuleError> {
+    board
+        .servos
+        .iter()
+        .position(|x| x.pin == pin)
+        .ok_or(ModuleError::ModuleInstanceNotFound)
+}
+
+/// Returns the pulse width currently emitted by the servo, following a running sweep.
+fn servo_pulse_width(servo: &VirtualServo) -> Option<Duration> {
+    let pulse_width = servo.pulse_width?;
+
+    match &servo.sweep {
+        Some(sweep) => {
+            let moved = sweep
+                .pulse_width_per_second
+                .mul_f64(sweep.start.elapsed().as_secs_f64());
+
+            match pulse_width < sweep.pulse_width {
+                true => Some((pulse_width + moved).min(sweep.pulse_width)),
+                false => Some(pulse_width.saturating_sub(moved).max(sweep.pulse_width)),
+            }
+        }
+        None => Some(pulse_width),
+    }
+}
+
+/// Stops a running sweep at the current pulse width. A sweep that did not reach its target yet is answered as interrupted.
+fn interrupt_servo_sweep(board: &mut SimulatedBoardState, index: usize) {
+    let pulse_width = servo_pulse_width(&board.servos[index]);
+
+    let servo = &mut board.servos[index];
+
+    servo.pulse_width = pulse_width;
+
+    if let Some(sweep) = servo.sweep.take() {
+        if std::mem::take(&mut *sweep.pending.lock().unwrap()) {
+            let identifier = sweep.identifier;
+            let result = Err(ModuleError::ModuleCommandInterrupted);
+
+            send(
+                board,
+                SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+            );
+        }
+    }
+}
+
+#[inline]
+fn stepper(board: &SimulatedBoardState, step_pin: GpioPin) -> Result<usize, ModuleError> {
+    board
+        .steppers
+        .iter()
+        .position(|x| x.config.step_pin == step_pin)
+        .ok_or(ModuleError::ModuleInstanceNotFound)
+}
+
+fn stepper_move(
+    board: &mut SimulatedBoardState,
+    identifier: u32,
+    step_pin: GpioPin,
+    target: impl FnOnce(i64) -> i64,
+    relative: bool,
+) -> Option<Result<Response, ModuleError>> {
+    let index = match stepper(board, step_pin) {
+        Ok(x) => x,
+        Err(x) => return Some(Err(x)),
+    };
+
+    let motion = board.steppers[index].motion.clone();
+    let mut motion = motion.lock().unwrap();
+
+    if !motion.enabled {
+        return Some(Ok(stepper_move_response(relative, Err(StepperModuleError::Disabled))));
+    }
+
+    let target = target(motion.target.unwrap_or(motion.position.round() as i64));
+
+    // A new move replaces the running move or stop.
+
+    let mut responses: Vec<(u32, Result<Response, ModuleError>)> = motion
+        .pending_stops
+        .drain(..)
+        .map(|x| (x, Err(ModuleError::ModuleCommandInterrupted)))
+        .collect();
+
+    if let Some((previous, _)) = motion.pending_move.take() {
+        responses.push((previous, Err(ModuleError::ModuleCommandInterrupted)));
+    }
+
+    send_responses(board, responses);
+
+    motion.stopping = false;
+
+    if motion.velocity == 0.0 && motion.position == target as f64 {
+        motion.target = None;
+
+        return Some(Ok(stepper_move_response(relative, Ok(target))));
+    }
+
+    motion.target = Some(target);
+    motion.pending_move = Some((identifier, relative));
+
+    None
+}
+
+#[inline]
+fn stepper_move_response(relative: bool, result: Result<i64, StepperModuleError>) -> Response {
+    match relative {
+        true => Response::Stepper_MoveBy { result },
+        false => Response::Stepper_MoveTo { result },
+    }
+}
+
+/// Stops at once and answers all pending moves and stops.
+fn stepper_halt(motion: &mut VirtualStepperMotion) -> Vec<(u32, Result<Response, ModuleError>)> {
+    motion.velocity = 0.0;
+    motion.target = None;
+    motion.stopping = false;
+
+    let position = motion.position.round() as i64;
+
+    motion.position = position as f64;
+
+    let mut responses: Vec<(u32, Result<Response, ModuleError>)> = motion
+        .pending_stops
+        .drain(..)
+        .map(|x| (x, Ok(Response::Stepper_Stop { result: Ok(position) })))
+        .collect();
+
+    if let Some((identifier, _)) = motion.pending_move.take() {
+        responses.push((identifier, Err(ModuleError::ModuleCommandInterrupted)));
+    }
+
+    responses
+}
+
+/// Advances the trapezoidal speed profile by the given time.
+fn stepper_tick(
+    config: &StepperConfig,
+    motion: &mut VirtualStepperMotion,
+    elapsed: f64,
+) -> Vec<(u32, Result<Response, ModuleError>)> {
+    let acceleration = config.acceleration_steps_per_second_squared as f64;
+    let max_speed = config.max_speed_steps_per_second as f64;
+
+    let decelerate = match (motion.target, motion.stopping) {
+        (Some(target), false) => {
+            let remaining = target as f64 - motion.position;
+            let braking_distance = motion.velocity * motion.velocity / (2.0 * acceleration);
+
+            motion.velocity * remaining < 0.0 || remaining.abs() <= braking_distance
+        }
+        _ => true,
+    };
+
+    motion.velocity = match decelerate {
+        true => motion.velocity.signum() * (motion.velocity.abs() - acceleration * elapsed).max(0.0),
+        false => {
+            let direction = (motion.target.unwrap_or_default() as f64 - motion.position).signum();
+
+            (motion.velocity + direction * acceleration * elapsed).clamp(-max_speed, max_speed)
+        }
+    };
+
+    let previous = motion.position;
+
+    motion.position += motion.velocity * elapsed;
+
+    match (motion.target, motion.stopping) {
+        (Some(target), false) => {
+            // The target is reached, once the position passes it.
+
+            if (target as f64 - previous).signum() == (target as f64 - motion.position).signum()
+                && motion.position != target as f64
+            {
+                return Vec::new();
+            }
+
+            motion.position = target as f64;
+            motion.velocity = 0.0;
+            motion.target = None;
+
+            motion
+                .pending_move
+                .take()
+                .map(|(identifier, relative)| (identifier, Ok(stepper_move_response(relative, Ok(target)))))
+                .into_iter()
+                .collect()
+        }
+        _ if motion.velocity == 0.0 => stepper_halt(motion),
+        _ => Vec::new(),
+    }
+}
+
+fn run_stepper(config: StepperConfig, motion: Weak<Mutex<VirtualStepperMotion>>, connection: Sender<SimulatedReport>) {
+    let mut last_tick = Instant::now();
+
+    loop {
+        std::thread::sleep(Duration::from_millis(1));
+
+        let Some(motion) = motion.upgrade() else {
+            return;
+        };
+
+        let now = Instant::now();
+
+        let responses = stepper_tick(&config, &mut motion.lock().unwrap(), (now - last_tick).as_secs_f64());
+
+        last_tick = now;
+
+        for (identifier, result) in responses {
+            _ = connection.try_send(SimulatedReport::DeviceReport(Box::new(DeviceReport::Response {
+                identifier,
+                result,
+            })));
+        }
+    }
+}
+
+fn send_responses(board: &SimulatedBoardState, responses: Vec<(u32, Result<Response, ModuleError>)>) {
+    for (identifier, result) in responses {
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+#[inline]
+fn pwm_slice(pin: GpioPin) -> u8 {
+    (u8::from(pin) >> 1) & 0x07
+}
+
+#[inline]
+fn mask_pins(pin_mask: u32) -> Vec<GpioPin> {
+    (0..u32::BITS as u8)
+        .filter(|x| pin_mask & (1 << x) != 0)
+        .filter_map(|x| GpioPin::try_from(x).ok())
+        .collect()
+}
+
+/// Returns the pins of the output port occupying exactly the given pins.
+fn port_output_pins(board: &SimulatedBoardState, pin_mask: u32) -> Result<Vec<GpioPin>, ModuleError> {
+    let pins = mask_pins(pin_mask);
+
+    let found = pins
+        .iter()
+        .all(|x| matches!(board.pins.get(x), Some(VirtualPinMode::PortOutput { .. })));
+
+    match !pins.is_empty() && found {
+        true => Ok(pins),
+        false => Err(ModuleError::ModuleInstanceNotFound),
+    }
+}
+
+/// Returns the pins of the input port occupying exactly the given pins.
+fn port_input_pins(board: &SimulatedBoardState, pin_mask: u32) -> Result<Vec<GpioPin>, ModuleError> {
+    let pins = mask_pins(pin_mask);
+
+    let found = pins
+        .iter()
+        .all(|x| matches!(board.pins.get(x), Some(VirtualPinMode::PortInput { .. })));
+
+    match !pins.is_empty() && found {
+        true => Ok(pins),
+        false => Err(ModuleError::ModuleInstanceNotFound),
+    }
+}
+
+fn interrupt_pending_port_changes(board: &mut SimulatedBoardState, pin_mask: u32) {
+    let interrupted: Vec<u32> = board
+        .pending_port_changes
+        .iter()
+        .filter(|x| x.pin_mask == pin_mask)
+        .map(|x| x.identifier)
+        .collect();
+
+    board.pending_port_changes.retain(|x| x.pin_mask != pin_mask);
+
+    for identifier in interrupted {
+        let result = Err(ModuleError::ModuleCommandInterrupted);
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+fn new_pin(board: &mut SimulatedBoardState, pin: GpioPin, mode: VirtualPinMode) -> Result<(), ModuleError> {
+    match board.pins.contains_key(&pin) {
+        true => Err(ModuleError::PeripheralBlockedByAnotherModule),
+        false => {
+            board.pins.insert(pin, mode);
+
+            Ok(())
+        }
+    }
+}
+
+fn drop_pin(board: &mut SimulatedBoardState, pin: GpioPin) -> Result<(), ModuleError> {
+    board.pins.remove(&pin).ok_or(ModuleError::ModuleInstanceNotFound)?;
+
+    // Waits still pending on a dropped pin are interrupted.
+
+    interrupt_pending_signals(board, pin);
+
+    board.pin_subscriptions.retain(|x| x.pin != pin);
+
+    Ok(())
+}
+
+fn interrupt_pending_signals(board: &mut SimulatedBoardState, pin: GpioPin) {
+    let interrupted: Vec<u32> = board
+        .pending_signals
+        .iter()
+        .filter(|x| x.pin == pin)
+        .map(|x| x.identifier)
+        .collect();
+
+    board.pending_signals.retain(|x| x.pin != pin);
+
+    for identifier in interrupted {
+        let result = Err(ModuleError::ModuleCommandInterrupted);
+
+        send(
+            board,
+            SimulatedReport::DeviceReport(Box::new(DeviceReport::Response { identifier, result })),
+        );
+    }
+}
+
+#[inline]
+fn i2c_bus(board: &SimulatedBoardState, bus_number: I2cBusNumber) -> Result<(), ModuleError> {
+    match board.i2c_buses.contains_key(&bus_number) {
+        true => Ok(()),
+        false => Err(ModuleError::ModuleInstanceNotFound),
+    }
+}
+
+#[inline]
+fn i2c_chunked_state(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+) -> Result<&mut Option<I2cChunkedState>, ModuleError> {
+    board
+        .i2c_buses
+        .get_mut(&bus_number)
+        .ok_or(ModuleError::ModuleInstanceNotFound)
+}
+
+#[inline]
+fn i2c_device(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    address: I2cAddress,
+) -> Result<&mut Box<dyn VirtualI2cDevice>, I2cBusModuleError> {
+    match address {
+        I2cAddress::SevenBit { address } if address > 0x7F => {
+            return Err(I2cBusModuleError::from(I2cError::AddressOutOfRange { address }))
+        }
+        I2cAddress::TenBit { address } if address > 0x3FF => {
+            return Err(I2cBusModuleError::from(I2cError::AddressOutOfRange { address }))
+        }
+        _ => {}
+    }
+
+    board
+        .i2c_devices
+        .iter_mut()
+        .find(|(bus, x, _)| *bus == bus_number && *x == address)
+        .map(|(_, _, device)| device)
+        .ok_or(I2cBusModuleError::from(I2cError::AbortNoAcknowledge))
+}
+
+fn i2c_read(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    address: I2cAddress,
+    buffer_size: u16,
+) -> Result<BusBuffer, I2cBusModuleError> {
+    let mut buffer = BusBuffer::new();
+
+    buffer
+        .resize(buffer_size as usize, 0x00)
+        .map_err(|_| I2cBusModuleError::from(I2cError::InvalidReadBufferLength))?;
+
+    i2c_device(board, bus_number, address)?.read(&mut buffer)?;
+
+    Ok(buffer)
+}
+
+fn i2c_write(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    address: I2cAddress,
+    bytes: &[u8],
+) -> Result<(), I2cBusModuleError> {
+    i2c_device(board, bus_number, address)?.write(bytes)?;
+
+    Ok(())
+}
+
+fn i2c_transaction(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    address: I2cAddress,
+    operations: &[I2cTransactionOperation],
+    bytes: &[u8],
+) -> Result<BusBuffer, I2cBusModuleError> {
+    let mut received = BusBuffer::new();
+    let mut pending_write: Option<Vec<u8>> = None;
+    let mut bytes = bytes.iter();
+
+    for operation in operations {
+        match operation {
+            I2cTransactionOperation::Write { bytes_count } => {
+                pending_write
+                    .get_or_insert_with(Vec::new)
+                    .extend(bytes.by_ref().take(*bytes_count as usize));
+            }
+            I2cTransactionOperation::Read { buffer_size } => {
+                if let Some(x) = pending_write.take() {
+                    i2c_write(board, bus_number, address, &x)?;
+                }
+
+                let buffer = i2c_read(board, bus_number, address, *buffer_size)?;
+
+                received
+                    .extend_from_slice(&buffer)
+                    .map_err(|_| I2cBusModuleError::from(I2cError::InvalidReadBufferLength))?;
+            }
+        }
+    }
+
+    if let Some(x) = pending_write {
+        i2c_write(board, bus_number, address, &x)?;
+    }
+
+    Ok(received)
+}
+
+fn i2c_start_chunked(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    address: I2cAddress,
+) -> Result<(), ModuleError> {
+    *i2c_chunked_state(board, bus_number)? = Some(I2cChunkedState {
+        address,
+        pending_write: Vec::new(),
+    });
+
+    Ok(())
+}
+
+fn i2c_chunked_write(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    bytes: &[u8],
+) -> Result<(), ModuleError> {
+    // Written chunks are collected and passed to the device at once, as a chunk boundary is not visible on the bus.
+
+    match i2c_chunked_state(board, bus_number)? {
+        Some(chunked_state) => {
+            chunked_state.pending_write.extend_from_slice(bytes);
+
+            Ok(())
+        }
+        None => Err(ModuleError::ModuleCommandInterrupted),
+    }
+}
+
+fn i2c_transaction_write_chunk(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    bytes: &[u8],
+    end: I2cTransactionChunkEnd,
+) -> Result<Result<(), I2cBusModuleError>, ModuleError> {
+    i2c_chunked_write(board, bus_number, bytes)?;
+
+    if end == I2cTransactionChunkEnd::Continue {
+        return Ok(Ok(()));
+    }
+
+    let (address, pending_write) = match i2c_chunked_state(board, bus_number)? {
+        Some(chunked_state) => (chunked_state.address, std::mem::take(&mut chunked_state.pending_write)),
+        None => return Err(ModuleError::ModuleCommandInterrupted),
+    };
+
+    Ok(i2c_write(board, bus_number, address, &pending_write))
+}
+
+fn i2c_chunked_read(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+    buffer_size: u16,
+) -> Result<Result<BusBuffer, I2cBusModuleError>, ModuleError> {
+    let (address, pending_write) = match i2c_chunked_state(board, bus_number)? {
+        Some(chunked_state) => (chunked_state.address, std::mem::take(&mut chunked_state.pending_write)),
+        None => return Err(ModuleError::ModuleCommandInterrupted),
+    };
+
+    if !pending_write.is_empty() {
+        if let Err(x) = i2c_write(board, bus_number, address, &pending_write) {
+            return Ok(Err(x));
+        }
+    }
+
+    Ok(i2c_read(board, bus_number, address, buffer_size))
+}
+
+fn i2c_stop_chunked(
+    board: &mut SimulatedBoardState,
+    bus_number: I2cBusNumber,
+) -> Result<Result<(), I2cBusModuleError>, ModuleError> {
+    match i2c_chunked_state(board, bus_number)?.take() {
+        Some(chunked_state) if !chunked_state.pending_write.is_empty() => Ok(i2c_write(
+            board,
+            bus_number,
+            chunked_state.address,
+            &chunked_state.pending_write,
+        )),
+        _ => Ok(Ok(())),
+    }
+}
@@ -0,0 +1,211 @@
+use crate::communication::Version;
+use crate::peripherals::gpio::{GpioPin, Level};
+use crate::peripherals::i2c::{I2cAddress, I2cBusNumber};
+use crate::simulator::{
+    I2cChunkedState, PendingCount, PendingPortChange, PendingPulseMeasure, PendingSignal, PinSubscription,
+    SimulatedReport, VirtualEncoder, VirtualI2cDevice, VirtualLedStrip, VirtualPinBank, VirtualPinMode, VirtualServo,
+    VirtualStepper,
+};
+use async_std::channel::{unbounded, Receiver, Sender};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+static CONNECTED_BOARDS: Mutex<Vec<SimulatedBoard>> = Mutex::new(Vec::new());
+
+static WATCHERS: Mutex<Vec<Sender<SimulatedBoardEvent>>> = Mutex::new(Vec::new());
+
+static RUNTIME_IDENTIFIER_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Simulated Iotzio board speaking the real protocol in-process. While connected, it is listed by the IotzioManager like real hardware.
+/// Clones share the same board.
+#[derive(Clone, Debug)]
+pub struct SimulatedBoard {
+    pub(crate) inner: Arc<Mutex<SimulatedBoardState>>,
+}
+
+#[derive(Debug)]
+pub(crate) enum SimulatedBoardEvent {
+    Connected(SimulatedBoard),
+    Disconnected(u64),
+}
+
+#[derive(Debug)]
+pub(crate) struct SimulatedBoardState {
+    pub(crate) serial_number: String,
+    pub(crate) version: Version,
+    pub(crate) runtime_identifier: u64,
+    pub(crate) connection: Option<Sender<SimulatedReport>>,
+    pub(crate) connection_generation: u32,
+    pub(crate) pins: HashMap<GpioPin, VirtualPinMode>,
+    pub(crate) input_levels: HashMap<GpioPin, Level>,
+    pub(crate) pending_signals: Vec<PendingSignal>,
+    pub(crate) pending_port_changes: Vec<PendingPortChange>,
+    pub(crate) pin_subscriptions: Vec<PinSubscription>,
+    pub(crate) pending_counts: Vec<PendingCount>,
+    pub(crate) encoders: Vec<VirtualEncoder>,
+    pub(crate) led_strips: Vec<VirtualLedStrip>,
+    pub(crate) servos: Vec<VirtualServo>,
+    pub(crate) steppers: Vec<VirtualStepper>,
+    pub(crate) pending_pulse_measures: Vec<PendingPulseMeasure>,
+    pub(crate) power_on: Instant,
+    pub(crate) i2c_devices: Vec<(I2cBusNumber, I2cAddress, Box<dyn VirtualI2cDevice>)>,
+    pub(crate) i2c_buses: HashMap<I2cBusNumber, Option<I2cChunkedState>>,
+}
+
+impl SimulatedBoard {
+    /// Create a new simulated board with the given serial number. The board is not connected yet.
+    pub fn new(serial_number: &str) -> SimulatedBoard {
+        let version = Version {
+            major: env!("CARGO_PKG_VERSION_MAJOR").parse().unwrap(),
+            minor: env!("CARGO_PKG_VERSION_MINOR").parse().unwrap(),
+            patch: env!("CARGO_PKG_VERSION_PATCH").parse().unwrap(),
+        };
+
+        // The highest bit keeps simulated runtime identifiers apart from the hashed identifiers of real boards.
+
+        let runtime_identifier = (1u64 << 63) | RUNTIME_IDENTIFIER_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let state = SimulatedBoardState {
+            serial_number: serial_number.to_string(),
+            version,
+            runtime_identifier,
+            connection: None,
+            connection_generation: 0,
+            pins: HashMap::new(),
+            input_levels: HashMap::new(),
+            pending_signals: Vec::new(),
+            pending_port_changes: Vec::new(),
+            pin_subscriptions: Vec::new(),
+            pending_counts: Vec::new(),
+            encoders: Vec::new(),
+            led_strips: Vec::new(),
+            servos: Vec::new(),
+            steppers: Vec::new(),
+            pending_pulse_measures: Vec::new(),
+            power_on: Instant::now(),
+            i2c_devices: Vec::new(),
+            i2c_buses: HashMap::new(),
+        };
+
+        SimulatedBoard {
+            inner: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// The serial number of the simulated board.
+    #[inline]
+    pub fn serial_number(&self) -> String {
+        self.inner.lock().unwrap().serial_number.clone()
+    }
+
+    /// The semantic version reported by the simulated board.
+    #[inline]
+    pub fn version(&self) -> Version {
+        self.inner.lock().unwrap().version
+    }
+
+    /// The runtime identifier of the simulated board.
+    #[inline]
+    pub fn runtime_identifier(&self) -> u64 {
+        self.inner.lock().unwrap().runtime_identifier
+    }
+
+    /// Returns the virtual pin bank of the simulated board.
+    #[inline]
+    pub fn pin_bank(&self) -> VirtualPinBank {
+        VirtualPinBank {
+            board: self.inner.clone(),
+        }
+    }
+
+    /// Attach a virtual device to the given I2C bus. The device answers to the given address.
+    #[inline]
+    pub fn add_i2c_device(
+        &self,
+        bus_number: I2cBusNumber,
+        address: I2cAddress,
+        device: impl VirtualI2cDevice + 'static,
+    ) {
+        self.inner
+            .lock()
+            .unwrap()
+            .i2c_devices
+            .push((bus_number, address, Box::new(device)));
+    }
+
+    /// Connect the simulated board, so that it is listed by the IotzioManager.
+    pub fn connect(&self) {
+        let mut boards = CONNECTED_BOARDS.lock().unwrap();
+
+        if !boards.iter().any(|x| Arc::ptr_eq(&x.inner, &self.inner)) {
+            boards.push(self.clone());
+
+            notify_watchers(|| SimulatedBoardEvent::Connected(self.clone()));
+        }
+    }
+
+    /// Disconnect the simulated board. An opened Iotzio instance of this board fails with a fatal error afterwards.
+    /// All modules set up on the board are released.
+    pub fn disconnect(&self) {
+        let runtime_identifier = self.runtime_identifier();
+
+        let mut boards = CONNECTED_BOARDS.lock().unwrap();
+
+        let count = boards.len();
+
+        boards.retain(|x| !Arc::ptr_eq(&x.inner, &self.inner));
+
+        if boards.len() != count {
+            notify_watchers(|| SimulatedBoardEvent::Disconnected(runtime_identifier));
+        }
+
+        drop(boards);
+
+        // Like a real board losing power, all set up modules are gone after reconnecting.
+
+        let mut state = self.inner.lock().unwrap();
+
+        state.pins.clear();
+        state.pending_signals.clear();
+        state.pending_port_changes.clear();
+        state.pin_subscriptions.clear();
+        state.pending_counts.clear();
+        state.encoders.clear();
+        state.led_strips.clear();
+        state.servos.clear();
+        state.steppers.clear();
+        state.pending_pulse_measures.clear();
+        state.i2c_buses.clear();
+
+        if let Some(connection) = state.connection.take() {
+            connection.close();
+        }
+    }
+}
+
+pub(crate) fn connected_boards() -> Vec<SimulatedBoard> {
+    CONNECTED_BOARDS.lock().unwrap().clone()
+}
+
+pub(crate) fn watch_connected_boards() -> Receiver<SimulatedBoardEvent> {
+    let (tx, rx) = unbounded();
+
+    // The registry lock is held while registering, so that no connect or disconnect is missed in between.
+
+    let boards = CONNECTED_BOARDS.lock().unwrap();
+
+    for board in boards.iter() {
+        _ = tx.try_send(SimulatedBoardEvent::Connected(board.clone()));
+    }
+
+    WATCHERS.lock().unwrap().push(tx);
+
+    rx
+}
+
+#[inline]
+fn notify_watchers(event: impl Fn() -> SimulatedBoardEvent) {
+    WATCHERS.lock().unwrap().retain(|x| x.try_send(event()).is_ok());
+}
@@ -0,0 +1,12 @@
+use crate::peripherals::i2c::I2cError;
+use std::fmt::Debug;
+
+/// Virtual device attached to a simulated I2C bus.
+/// Adjacent operations of the same direction within a transaction are merged into a single call.
+pub trait VirtualI2cDevice: Debug + Send {
+    /// Called when the controller writes bytes to the device.
+    fn write(&mut self, bytes: &[u8]) -> Result<(), I2cError>;
+
+    /// Called when the controller reads bytes from the device.
+    fn read(&mut self, buffer: &mut [u8]) -> Result<(), I2cError>;
+}
@@ -0,0 +1,44 @@
+use crate::modules::led_strip::LedColor;
+use crate::peripherals::gpio::{GpioPin, Level};
+use crate::simulator::{service, SimulatedBoardState};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Virtual pin bank of a simulated board. Drives the level seen by input pins and exposes the level of output pins.
+/// Pins that are not driven read the level implied by their pull setting.
+#[derive(Clone, Debug)]
+pub struct VirtualPinBank {
+    pub(crate) board: Arc<Mutex<SimulatedBoardState>>,
+}
+
+impl VirtualPinBank {
+    /// Drive the level seen by the given pin when used as input. Pending waits on that pin are completed accordingly.
+    #[inline]
+    pub fn set_input_level(&self, pin: GpioPin, level: Level) {
+        service::set_input_level(&mut self.board.lock().unwrap(), pin, level)
+    }
+
+    /// Stop driving the given pin, so that it reads the level implied by its pull setting again.
+    #[inline]
+    pub fn release_input_level(&self, pin: GpioPin) {
+        service::release_input_level(&mut self.board.lock().unwrap(), pin)
+    }
+
+    /// Returns the level of the given pin if it is currently set up as output.
+    #[inline]
+    pub fn get_output_level(&self, pin: GpioPin) -> Option<Level> {
+        service::get_output_level(&self.board.lock().unwrap(), pin)
+    }
+
+    /// Returns the colors currently shown by the LED strip on the given pin, scaled by its brightness.
+    #[inline]
+    pub fn get_led_strip_colors(&self, pin: GpioPin) -> Option<Vec<LedColor>> {
+        service::get_led_strip_colors(&self.board.lock().unwrap(), pin)
+    }
+
+    /// Returns the pulse width currently emitted by the servo on the given pin, following a running sweep.
+    #[inline]
+    pub fn get_servo_pulse_width(&self, pin: GpioPin) -> Option<Duration> {
+        service::get_servo_pulse_width(&self.board.lock().unwrap(), pin)
+    }
+}
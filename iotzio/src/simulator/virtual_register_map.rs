@@ -0,0 +1,89 @@
+use crate::peripherals::i2c::I2cError;
+use crate::simulator::VirtualI2cDevice;
+use std::sync::{Arc, Mutex};
+
+/// Virtual I2C device with a 256 byte register map, behaving like most I2C sensors and EEPROMs.
+/// The first byte of each write selects the register, further bytes are stored starting at that register.
+/// Reads return the register map starting at the selected register. The register pointer auto increments and wraps.
+/// Clones share the same register map, so a clone can be used to inspect and modify registers during a test.
+#[derive(Clone, Debug, Default)]
+pub struct VirtualRegisterMap {
+    inner: Arc<Mutex<VirtualRegisterMapState>>,
+}
+
+#[derive(Debug)]
+struct VirtualRegisterMapState {
+    registers: [u8; 256],
+    pointer: u8,
+}
+
+impl Default for VirtualRegisterMapState {
+    fn default() -> Self {
+        VirtualRegisterMapState {
+            registers: [0u8; 256],
+            pointer: 0,
+        }
+    }
+}
+
+impl VirtualRegisterMap {
+    /// Create a new register map with all registers set to 0x00.
+    #[inline]
+    pub fn new() -> VirtualRegisterMap {
+        VirtualRegisterMap::default()
+    }
+
+    /// Returns the value of a register.
+    #[inline]
+    pub fn get_register(&self, register: u8) -> u8 {
+        self.inner.lock().unwrap().registers[register as usize]
+    }
+
+    /// Set the value of a register.
+    #[inline]
+    pub fn set_register(&self, register: u8, value: u8) {
+        self.inner.lock().unwrap().registers[register as usize] = value;
+    }
+
+    /// Set consecutive registers starting at register. Wraps around after register 0xFF.
+    #[inline]
+    pub fn set_registers(&self, register: u8, bytes: &[u8]) {
+        let mut state = self.inner.lock().unwrap();
+
+        for (offset, byte) in bytes.iter().enumerate() {
+            state.registers[register.wrapping_add(offset as u8) as usize] = *byte;
+        }
+    }
+}
+
+impl VirtualI2cDevice for VirtualRegisterMap {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), I2cError> {
+        let mut state = self.inner.lock().unwrap();
+
+        if let Some((register, values)) = bytes.split_first() {
+            state.pointer = *register;
+
+            for value in values {
+                let pointer = state.pointer;
+
+                state.registers[pointer as usize] = *value;
+                state.pointer = pointer.wrapping_add(1);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Result<(), I2cError> {
+        let mut state = self.inner.lock().unwrap();
+
+        for value in buffer.iter_mut() {
+            let pointer = state.pointer;
+
+            *value = state.registers[pointer as usize];
+            state.pointer = pointer.wrapping_add(1);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::communication::{Command, Response};
+use crate::socket::Socket;
+
+/// Guards a command awaiting its response. If the command future is dropped before completion, the cancel command is
+/// enqueued on the socket and sent to the device ahead of the next command.
+#[derive(Debug)]
+pub struct CancellationGuard<'a> {
+    socket: &'a Socket,
+    cancel_command: Option<Command>,
+    cancel_response: Option<async_std::channel::Sender<Response>>,
+}
+
+impl<'a> CancellationGuard<'a> {
+    pub fn new(
+        socket: &'a Socket,
+        cancel_command: Command,
+        cancel_response: Option<async_std::channel::Sender<Response>>,
+    ) -> CancellationGuard<'a> {
+        CancellationGuard {
+            socket,
+            cancel_command: Some(cancel_command),
+            cancel_response,
+        }
+    }
+
+    /// The command completed, nothing needs to be cancelled.
+    pub fn disarm(mut self) {
+        self.cancel_command = None;
+    }
+}
+
+impl Drop for CancellationGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(cancel_command) = self.cancel_command.take() {
+            self.socket
+                .pending_cancellations
+                .lock()
+                .unwrap()
+                .push((cancel_command, self.cancel_response.take()));
+        }
+    }
+}
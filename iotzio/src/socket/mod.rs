@@ -5,8 +5,12 @@ cfg_if::cfg_if! {
         mod socket;
         mod socket_service;
         mod runtime_identifier;
+        mod reconnect_state;
+        mod cancellation_guard;
 
         pub(crate) use self::socket::*;
         pub(crate) use self::runtime_identifier::*;
+        pub(crate) use self::reconnect_state::*;
+        pub(crate) use self::cancellation_guard::*;
     }
 }
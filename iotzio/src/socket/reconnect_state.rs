@@ -0,0 +1,231 @@
+use crate::communication::{Command, Response};
+use crate::modules::i2c_bus::I2cConfig;
+use crate::peripherals::gpio::{GpioPin, Level};
+use crate::peripherals::i2c::I2cBusNumber;
+use async_std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32};
+
+/// State of a resilient socket. Remembers the modules alive on the board, so that they can be set up again after reconnecting.
+#[derive(Debug)]
+pub struct ReconnectState {
+    pub(crate) serial_number: String,
+    pub(crate) disconnected: AtomicBool,
+    pub(crate) reconnect_count: AtomicU32,
+    pub(crate) reconnect_mutex: Mutex<()>,
+    pub(crate) modules: std::sync::Mutex<Vec<Command>>,
+    pub(crate) unknown_stepper_positions: std::sync::Mutex<Vec<GpioPin>>,
+    pub(crate) drop_commands: std::sync::Mutex<Vec<Command>>,
+}
+
+impl ReconnectState {
+    pub fn new(serial_number: String) -> ReconnectState {
+        ReconnectState {
+            serial_number,
+            disconnected: AtomicBool::new(false),
+            reconnect_count: AtomicU32::new(0),
+            reconnect_mutex: Mutex::new(()),
+            modules: std::sync::Mutex::new(Vec::new()),
+            unknown_stepper_positions: std::sync::Mutex::new(Vec::new()),
+            drop_commands: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Record a module setup or output level change after the board confirmed it.
+    pub fn track(&self, command: &Command, response: &Response) {
+        let mut modules = self.modules.lock().unwrap();
+
+        match (command, response) {
+            (Command::InputPin_New { .. }, Response::InputPin_New { result: Ok(_) })
+            | (Command::OutputPin_New { .. }, Response::OutputPin_New { result: Ok(_) })
+            | (Command::I2c_New { .. }, Response::I2c_New { result: Ok(_) })
+            | (Command::OutputPort_New { .. }, Response::OutputPort_New { result: Ok(_) })
+            | (Command::InputPort_New { .. }, Response::InputPort_New { result: Ok(_) })
+            | (Command::Counter_New { .. }, Response::Counter_New { result: Ok(_) })
+            | (Command::QuadratureEncoder_New { .. }, Response::QuadratureEncoder_New { result: Ok(_) })
+            | (Command::LedStrip_New { .. }, Response::LedStrip_New { result: Ok(_) })
+            | (Command::Servo_New { .. }, Response::Servo_New { result: Ok(_) })
+            | (Command::Stepper_New { .. }, Response::Stepper_New { result: Ok(_) })
+            | (Command::PulseIn_New { .. }, Response::PulseIn_New { result: Ok(_) }) => modules.push(command.clone()),
+            (Command::OutputPin_SetLevel { pin, level }, Response::OutputPin_SetLevel { result: Ok(_) }) => {
+                for module in modules.iter_mut() {
+                    if let Command::OutputPin_New {
+                        pin: module_pin,
+                        initial_level,
+                        ..
+                    } = module
+                    {
+                        if module_pin == pin {
+                            *initial_level = *level;
+                        }
+                    }
+                }
+            }
+            (
+                Command::LedStrip_SetBrightness { pin, brightness },
+                Response::LedStrip_SetBrightness { result: Ok(_) },
+            ) => {
+                for module in modules.iter_mut() {
+                    if let Command::LedStrip_New {
+                        pin: module_pin,
+                        brightness: module_brightness,
+                        ..
+                    } = module
+                    {
+                        if module_pin == pin {
+                            *module_brightness = *brightness;
+                        }
+                    }
+                }
+            }
+            (Command::Servo_SetPulseWidth { pin, pulse_width }, Response::Servo_SetPulseWidth { result: Ok(_) })
+            | (Command::Servo_Sweep { pin, pulse_width, .. }, Response::Servo_Sweep { result: Ok(_) }) => {
+                for module in modules.iter_mut() {
+                    if let Command::Servo_New {
+                        pin: module_pin,
+                        pulse_width: module_pulse_width,
+                        ..
+                    } = module
+                    {
+                        if module_pin == pin {
+                            *module_pulse_width = Some(*pulse_width);
+                        }
+                    }
+                }
+            }
+            (Command::Stepper_SetPosition { step_pin, .. }, Response::Stepper_SetPosition { result: Ok(_) }) => {
+                self.unknown_stepper_positions.lock().unwrap().retain(|x| x != step_pin);
+            }
+            (Command::Stepper_SetEnabled { step_pin, enabled }, Response::Stepper_SetEnabled { result: Ok(_) }) => {
+                for module in modules.iter_mut() {
+                    if let Command::Stepper_New {
+                        config,
+                        enabled: module_enabled,
+                        ..
+                    } = module
+                    {
+                        if config.step_pin == *step_pin {
+                            *module_enabled = *enabled;
+                        }
+                    }
+                }
+            }
+            (
+                Command::OutputPort_SetLevels {
+                    pin_mask, mask, value, ..
+                },
+                Response::OutputPort_SetLevels { result: Ok(_) },
+            ) => {
+                for module in modules.iter_mut() {
+                    if let Command::OutputPort_New { pins } = module {
+                        if port_pin_mask(pins.iter().map(|x| x.pin)) == *pin_mask {
+                            for x in pins.iter_mut().filter(|x| mask & (1 << u8::from(x.pin)) != 0) {
+                                x.initial_level = match value & (1 << u8::from(x.pin)) != 0 {
+                                    true => Level::High,
+                                    false => Level::Low,
+                                };
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Remember the drop command of a module set up on the board, also of modules not set up again after reconnecting.
+    pub fn track_drop_command(&self, drop_command: Command) {
+        let mut drop_commands = self.drop_commands.lock().unwrap();
+
+        if !drop_commands.contains(&drop_command) {
+            drop_commands.push(drop_command);
+        }
+    }
+
+    /// Returns whether the command drops a module set up on the board. Its drop command is forgotten.
+    pub fn untrack_drop_command(&self, command: &Command) -> bool {
+        let mut drop_commands = self.drop_commands.lock().unwrap();
+
+        let length = drop_commands.len();
+
+        drop_commands.retain(|x| x != command);
+
+        drop_commands.len() != length
+    }
+
+    /// Prepare a module to be set up again. Steppers are set up again at position zero, as a move may have been running
+    /// while disconnected. Their position stays unknown until it is set again, e.g. after homing.
+    pub fn restore(&self, command: &Command) {
+        if let Command::Stepper_New { config, .. } = command {
+            let mut unknown_stepper_positions = self.unknown_stepper_positions.lock().unwrap();
+
+            if !unknown_stepper_positions.contains(&config.step_pin) {
+                unknown_stepper_positions.push(config.step_pin);
+            }
+        }
+    }
+
+    /// Returns whether the position of the stepper is unknown since reconnecting.
+    pub fn is_stepper_position_unknown(&self, step_pin: GpioPin) -> bool {
+        self.unknown_stepper_positions.lock().unwrap().contains(&step_pin)
+    }
+
+    /// Forget a module as soon as its drop is requested. A module dropped while disconnected must not be set up again.
+    pub fn untrack(&self, command: &Command) {
+        let mut modules = self.modules.lock().unwrap();
+
+        match command {
+            Command::InputPin_Drop { pin } => {
+                modules.retain(|x| !matches!(x, Command::InputPin_New { pin: module_pin, .. } if module_pin == pin))
+            }
+            Command::OutputPin_Drop { pin } => {
+                modules.retain(|x| !matches!(x, Command::OutputPin_New { pin: module_pin, .. } if module_pin == pin))
+            }
+            Command::I2c_Drop { identifier } => {
+                modules.retain(|x| !matches!(x, Command::I2c_New { config } if i2c_bus_number(config) == *identifier))
+            }
+            Command::Counter_Drop { pin } => {
+                modules.retain(|x| !matches!(x, Command::Counter_New { pin: module_pin, .. } if module_pin == pin))
+            }
+            Command::QuadratureEncoder_Drop { pin_a } => modules.retain(|x| {
+                !matches!(x, Command::QuadratureEncoder_New { pin_a: module_pin, .. } if module_pin == pin_a)
+            }),
+            Command::LedStrip_Drop { pin } => {
+                modules.retain(|x| !matches!(x, Command::LedStrip_New { pin: module_pin, .. } if module_pin == pin))
+            }
+            Command::Servo_Drop { pin } => {
+                modules.retain(|x| !matches!(x, Command::Servo_New { pin: module_pin, .. } if module_pin == pin))
+            }
+            Command::Stepper_Drop { step_pin } => {
+                modules.retain(|x| !matches!(x, Command::Stepper_New { config, .. } if config.step_pin == *step_pin));
+
+                self.unknown_stepper_positions
+                    .lock()
+                    .unwrap()
+                    .retain(|x| x != step_pin);
+            }
+            Command::PulseIn_Drop { trigger_pin } => modules.retain(|x| {
+                !matches!(x, Command::PulseIn_New { config } if config.trigger_pin == *trigger_pin)
+            }),
+            Command::OutputPort_Drop { pin_mask } => modules.retain(|x| {
+                !matches!(x, Command::OutputPort_New { pins } if port_pin_mask(pins.iter().map(|x| x.pin)) == *pin_mask)
+            }),
+            Command::InputPort_Drop { pin_mask } => modules.retain(|x| {
+                !matches!(x, Command::InputPort_New { pins } if port_pin_mask(pins.iter().map(|x| x.pin)) == *pin_mask)
+            }),
+            _ => {}
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn i2c_bus_number(config: &I2cConfig) -> I2cBusNumber {
+    match config {
+        I2cConfig::I2c0 { .. } => I2cBusNumber::I2c0,
+        I2cConfig::I2c1 { .. } => I2cBusNumber::I2c1,
+    }
+}
+
+#[inline]
+pub(crate) fn port_pin_mask(pins: impl Iterator<Item = GpioPin>) -> u32 {
+    pins.fold(0, |mask, x| mask | (1 << u8::from(x)))
+}
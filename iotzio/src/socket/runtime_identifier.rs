@@ -19,6 +19,23 @@ impl RuntimeIdentifier {
             }
         }
     }
+
+    /// Take over another runtime identifier, e.g. after the board was connected again. Fails if the runtime identifier is in use.
+    pub fn replace(&mut self, inner: u64) -> bool {
+        let mut active_boards = ACTIVE_BOARDS.lock().unwrap();
+
+        match inner != self.0 && active_boards.contains(&inner) {
+            true => false,
+            false => {
+                active_boards.retain(|x| x.ne(&self.0));
+                active_boards.push(inner);
+
+                self.0 = inner;
+
+                true
+            }
+        }
+    }
 }
 
 impl Drop for RuntimeIdentifier {
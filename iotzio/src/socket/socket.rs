@@ -1,19 +1,36 @@
 use crate::backend::{DeviceReader, DeviceWriter};
-use crate::communication::{Command, FatalError, Response};
+use crate::communication::{Command, DeviceEvent, FatalError, Response};
 use crate::modules::ModuleError;
-use crate::socket::{socket_service, RuntimeIdentifier};
+use crate::socket::{socket_service, CancellationGuard, ReconnectState, RuntimeIdentifier};
 use crate::{InitializationError, IotzioInfo};
 use async_oneshot::Sender;
 use async_std::sync::Mutex;
 use std::sync::atomic::AtomicU32;
+use std::time::Duration;
+
+/// Default timeout for commands answered right away by the device. Commands waiting for a signal are not affected.
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Commands awaiting their response, by identifier.
+pub type InputQueue = Vec<(u32, Sender<Result<Result<Response, ModuleError>, FatalError>>)>;
+
+/// Subscriptions awaiting events pushed by the device, by identifier.
+pub type Subscriptions = Vec<(u32, async_std::channel::Sender<DeviceEvent>)>;
+
+/// Cancel commands of dropped command futures, each with an optional channel receiving the response.
+pub type PendingCancellations = Vec<(Command, Option<async_std::channel::Sender<Response>>)>;
 
 #[derive(Debug)]
 pub struct Socket {
     pub(crate) output: Mutex<SocketOutput>,
     pub(crate) input: Mutex<SocketInput>,
     pub(crate) packet_counter: AtomicU32,
-    pub(crate) input_queue_mutex: Mutex<Vec<(u32, Sender<Result<Result<Response, ModuleError>, FatalError>>)>>,
-    pub(crate) runtime_identifier: RuntimeIdentifier,
+    pub(crate) input_queue_mutex: std::sync::Mutex<InputQueue>,
+    pub(crate) runtime_identifier: std::sync::Mutex<RuntimeIdentifier>,
+    pub(crate) reconnect_state: Option<ReconnectState>,
+    pub(crate) command_timeout: std::sync::Mutex<Option<Duration>>,
+    pub(crate) pending_cancellations: std::sync::Mutex<PendingCancellations>,
+    pub(crate) subscriptions: std::sync::Mutex<Subscriptions>,
 }
 
 #[derive(Debug)]
@@ -37,6 +54,89 @@ impl Socket {
 
     #[inline]
     pub async fn send(&self, command: Command) -> Result<Result<Response, ModuleError>, FatalError> {
-        socket_service::send_command(self, command).await
+        // The command future is boxed, as it is large and would otherwise be embedded into the future of every module
+        // method. Debug builds easily overflow the stack of a thread polling such nested futures.
+
+        match &self.reconnect_state {
+            None => Box::pin(socket_service::send_command(self, command)).await,
+            Some(x) => Box::pin(socket_service::send_command_resilient(self, x, command)).await,
+        }
+    }
+
+    /// Send several commands packed into a single report. Returns the results in order of the commands, or `None`
+    /// without sending anything if the commands do not fit a single report.
+    #[inline]
+    pub async fn send_batch(
+        &self,
+        commands: Vec<Command>,
+    ) -> Result<Option<Vec<Result<Response, ModuleError>>>, FatalError> {
+        match &self.reconnect_state {
+            None => socket_service::send_batch(self, commands).await,
+            Some(x) => socket_service::send_batch_resilient(self, x, commands).await,
+        }
+    }
+
+    /// Send a command that may wait for a long time. If the returned future is dropped before completion, the given
+    /// cancel command is sent to the device ahead of the next command.
+    #[inline]
+    pub async fn send_cancellable(
+        &self,
+        command: Command,
+        cancel_command: Command,
+    ) -> Result<Result<Response, ModuleError>, FatalError> {
+        let guard = CancellationGuard::new(self, cancel_command, None);
+
+        let result = self.send(command).await;
+
+        guard.disarm();
+
+        result
+    }
+
+    /// Send a command that may wait for a long time, like [`Socket::send_cancellable`]. The response of the cancel
+    /// command is passed to the given channel, as it may carry data the dropped command already took from the device.
+    #[inline]
+    pub async fn send_cancellable_with_response(
+        &self,
+        command: Command,
+        cancel_command: Command,
+        cancel_response: &async_std::channel::Sender<Response>,
+    ) -> Result<Result<Response, ModuleError>, FatalError> {
+        let guard = CancellationGuard::new(self, cancel_command, Some(cancel_response.clone()));
+
+        let result = self.send(command).await;
+
+        guard.disarm();
+
+        result
+    }
+
+    /// Send the cancel commands of dropped command futures right away.
+    #[inline]
+    pub async fn send_pending_cancellations(&self) {
+        socket_service::send_pending_cancellations(self).await
+    }
+
+    /// Register a subscription for events pushed by the device. The returned identifier is passed to the device
+    /// along with the subscribe command. Events are buffered until they are received.
+    #[inline]
+    pub fn subscribe(&self) -> (u32, async_std::channel::Receiver<DeviceEvent>) {
+        socket_service::subscribe(self)
+    }
+
+    /// Remove a subscription. Events still arriving for it are discarded.
+    #[inline]
+    pub fn unsubscribe(&self, subscription: u32) {
+        self.subscriptions.lock().unwrap().retain(|(id, _)| *id != subscription);
+    }
+
+    /// Wait for the next event of a subscription.
+    #[inline]
+    pub async fn receive_event(
+        &self,
+        subscription: u32,
+        receiver: &async_std::channel::Receiver<DeviceEvent>,
+    ) -> Result<DeviceEvent, FatalError> {
+        socket_service::receive_event(self, subscription, receiver).await
     }
 }
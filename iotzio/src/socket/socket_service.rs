@@ -1,26 +1,356 @@
 use crate::backend::{DeviceReader, DeviceWriter};
 use crate::communication::{
-    Command, DeviceReport, FatalError, ProtocolError, Response, DEVICE_REPORT_HEADER_SIZE, HOST_REPORT_HEADER_SIZE,
-    IOTZIO_PROTOCOL_VERSION,
+    Command, DeviceEvent, DeviceReport, FatalError, ProtocolError, Response, BATCH_HEADER_SIZE, BATCH_MAX_COUNT,
+    DEVICE_REPORT_HEADER_SIZE, HOST_REPORT_HEADER_SIZE, IOTZIO_PROTOCOL_VERSION,
 };
 use crate::communication::{HostReport, PROTOCOL_INFO_BUFFER_SIZE, PROTOCOL_INFO_REPORT_ID};
-use crate::iotzio::InitializationError;
+use crate::iotzio::{iotzio_service, InitializationError};
+use crate::modules::i2c_target::I2cTargetConfig;
+use crate::modules::spi_bus::SpiConfig;
+use crate::modules::uart::UartConfig;
 use crate::modules::ModuleError;
-use crate::socket::{RuntimeIdentifier, Socket, SocketInput, SocketOutput};
+use crate::peripherals::i2c::I2cBusNumber;
+use crate::peripherals::spi::SpiBusNumber;
+use crate::peripherals::uart::UartNumber;
+use crate::socket::{
+    i2c_bus_number, port_pin_mask, InputQueue, ReconnectState, RuntimeIdentifier, Socket, SocketInput, SocketOutput,
+    DEFAULT_COMMAND_TIMEOUT,
+};
 use crate::IotzioInfo;
 use async_oneshot::Receiver;
 use futures_concurrency::future::Race;
 use hidparser::{Report, ReportDescriptor};
 use log::Level::Trace;
-use log::{log_enabled, trace};
-use postcard::{from_bytes, to_slice};
+use log::{debug, log_enabled, trace};
+use postcard::{take_from_bytes, to_allocvec, to_slice};
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
 
 pub async fn new_socket(iotzio_info: &IotzioInfo) -> Result<Socket, InitializationError> {
     let runtime_identifier =
         RuntimeIdentifier::new(iotzio_info.runtime_identifier).ok_or(InitializationError::DeviceAlreadyInUseError)?;
 
+    let (output, input) = open_connection(iotzio_info).await?;
+
+    let identifier = AtomicU32::new(0);
+
+    Ok(Socket {
+        output: output.into(),
+        input: input.into(),
+        packet_counter: identifier,
+        input_queue_mutex: Default::default(),
+        runtime_identifier: runtime_identifier.into(),
+        reconnect_state: None,
+        command_timeout: Some(DEFAULT_COMMAND_TIMEOUT).into(),
+        pending_cancellations: Default::default(),
+        subscriptions: Default::default(),
+    })
+}
+
+pub async fn send_command_resilient(
+    socket: &Socket,
+    reconnect_state: &ReconnectState,
+    command: Command,
+) -> Result<Result<Response, ModuleError>, FatalError> {
+    reconnect_state.untrack(&command);
+
+    // A drop command is recognized by the drop commands of the modules set up before, so that no module type is missed.
+
+    let is_drop_command = reconnect_state.untrack_drop_command(&command);
+
+    if reconnect_state.disconnected.load(Ordering::Acquire) {
+        // Dropping a module does not require the board. It is set up again only as long as it is tracked.
+
+        if is_drop_command {
+            return Err(FatalError::DeviceClosed);
+        }
+
+        reconnect(socket, reconnect_state).await?;
+    }
+
+    let result = send_command(socket, command.clone()).await;
+
+    match &result {
+        Ok(Ok(response)) => {
+            reconnect_state.track(&command, response);
+
+            if let Some(drop_command) = get_drop_command(&command) {
+                reconnect_state.track_drop_command(drop_command);
+            }
+        }
+        Ok(Err(_)) => {}
+        Err(_) => reconnect_state.disconnected.store(true, Ordering::Release),
+    }
+
+    result
+}
+
+pub async fn send_command(socket: &Socket, command: Command) -> Result<Result<Response, ModuleError>, FatalError> {
+    send_pending_cancellations(socket).await;
+
+    // Commands waiting for a signal may legitimately take forever. Their callers decide on a timeout themselves.
+    // A measurement is answered by the device after its own timeout at the latest.
+
+    let command_timeout = *socket.command_timeout.lock().unwrap();
+
+    let timeout = match &command {
+        Command::PulseIn_Measure { timeout, .. } => command_timeout.map(|x| x + *timeout),
+        x if is_waiting_command(x) => None,
+        _ => command_timeout,
+    };
+
+    send_command_with_timeout(socket, command, timeout).await
+}
+
+pub async fn send_pending_cancellations(socket: &Socket) {
+    let pending_cancellations = std::mem::take(socket.pending_cancellations.lock().unwrap().deref_mut());
+
+    for (command, cancel_response) in pending_cancellations {
+        let timeout = *socket.command_timeout.lock().unwrap();
+
+        // A failed cancellation is not of interest to the caller. A fatal error shows up with the next command anyway.
+        // The receiver of a response may be gone already, the response is discarded then.
+
+        match send_command_with_timeout(socket, command, timeout).await {
+            Ok(Ok(response)) => {
+                if let Some(cancel_response) = cancel_response {
+                    _ = cancel_response.try_send(response);
+                }
+            }
+            Ok(Err(x)) => debug!("Cancellation failed: {0}", x),
+            Err(x) => debug!("Cancellation failed: {0}", x),
+        }
+    }
+}
+
+pub fn subscribe(socket: &Socket) -> (u32, async_std::channel::Receiver<DeviceEvent>) {
+    // Subscription identifiers share the counter with commands, so that they never collide with a pending command.
+
+    let subscription = socket.packet_counter.fetch_add(1, Ordering::Relaxed);
+
+    let (sender, receiver) = async_std::channel::unbounded();
+
+    socket.subscriptions.lock().unwrap().push((subscription, sender));
+
+    (subscription, receiver)
+}
+
+pub async fn receive_event(
+    socket: &Socket,
+    subscription: u32,
+    receiver: &async_std::channel::Receiver<DeviceEvent>,
+) -> Result<DeviceEvent, FatalError> {
+    if let Ok(event) = receiver.try_recv() {
+        return Ok(event);
+    }
+
+    // Events are distributed by whichever task is reading reports. If no other task is reading, we read ourselves.
+    // No response ever carries the subscription identifier, so reading only ends with a fatal error.
+
+    let future_left = async {
+        read_and_distribute_response(socket, subscription)
+            .await
+            .and(Err(FatalError::from(ProtocolError::ReceivedWrongResponse)))
+    };
+
+    // The subscription is closed after a fatal error, as the device lost it anyway.
+
+    let future_right = async { receiver.recv().await.map_err(|_| FatalError::DeviceClosed) };
+
+    (future_left, future_right).race().await
+}
+
+#[inline]
+async fn send_command_with_timeout(
+    socket: &Socket,
+    command: Command,
+    timeout: Option<Duration>,
+) -> Result<Result<Response, ModuleError>, FatalError> {
+    match timeout {
+        None => send_command_inner(socket, command).await,
+        Some(x) => {
+            // The device may still complete a timed out setup command. Its module is dropped again, so that the
+            // peripheral does not stay claimed.
+
+            let drop_command = get_drop_command(&command);
+
+            match async_std::future::timeout(x, send_command_inner(socket, command)).await {
+                Ok(x) => x,
+                Err(_) => {
+                    if let Some(drop_command) = drop_command {
+                        socket.pending_cancellations.lock().unwrap().push((drop_command, None));
+                    }
+
+                    Ok(Err(ModuleError::CommandTimeout))
+                }
+            }
+        }
+    }
+}
+
+async fn send_command_inner(socket: &Socket, command: Command) -> Result<Result<Response, ModuleError>, FatalError> {
+    let send_id = socket.packet_counter.fetch_add(1, Ordering::Relaxed);
+
+    let host_report = HostReport {
+        identifier: send_id,
+        command,
+    };
+
+    // The response is enqueued before writing, as another task may already be reading reports.
+
+    let response_channel = enqueue_for_response(socket, send_id);
+
+    let _queue_guard = QueueEntryGuard { socket, send_id };
+
+    write_report(socket.output.lock().await.deref_mut(), host_report).await?;
+
+    receive_response(socket, send_id, response_channel).await
+}
+
+pub async fn send_batch_resilient(
+    socket: &Socket,
+    reconnect_state: &ReconnectState,
+    commands: Vec<Command>,
+) -> Result<Option<Vec<Result<Response, ModuleError>>>, FatalError> {
+    if reconnect_state.disconnected.load(Ordering::Acquire) {
+        reconnect(socket, reconnect_state).await?;
+    }
+
+    let result = send_batch(socket, commands.clone()).await;
+
+    match &result {
+        Ok(Some(results)) => commands
+            .iter()
+            .zip(results)
+            .filter_map(|(command, result)| result.as_ref().ok().map(|response| (command, response)))
+            .for_each(|(command, response)| reconnect_state.track(command, response)),
+        Ok(None) => {}
+        Err(_) => reconnect_state.disconnected.store(true, Ordering::Release),
+    }
+
+    result
+}
+
+pub async fn send_batch(
+    socket: &Socket,
+    commands: Vec<Command>,
+) -> Result<Option<Vec<Result<Response, ModuleError>>>, FatalError> {
+    send_pending_cancellations(socket).await;
+
+    // All commands are packed into a single report, so that the device executes them back to back.
+
+    let capacity = socket.output.lock().await.buffer.len() - 1 - HOST_REPORT_HEADER_SIZE - BATCH_HEADER_SIZE;
+
+    let mut batch = Vec::with_capacity(commands.len());
+
+    for command in commands {
+        let host_report = HostReport {
+            identifier: socket.packet_counter.fetch_add(1, Ordering::Relaxed),
+            command,
+        };
+
+        batch.push((host_report.identifier, to_allocvec(&host_report)?));
+    }
+
+    if batch.len() > BATCH_MAX_COUNT || batch.iter().map(|(_, bytes)| bytes.len()).sum::<usize>() > capacity {
+        return Ok(None);
+    }
+
+    let timeout = *socket.command_timeout.lock().unwrap();
+
+    let mut results = Vec::with_capacity(batch.len());
+
+    match timeout {
+        None => send_batch_report(socket, &batch, &mut results).await?,
+        Some(x) => {
+            if let Ok(x) = async_std::future::timeout(x, send_batch_report(socket, &batch, &mut results)).await {
+                x?
+            }
+        }
+    }
+
+    // Only the commands without a response timed out. Commands answered before keep their results.
+
+    results.resize(batch.len(), Err(ModuleError::CommandTimeout));
+
+    Ok(Some(results))
+}
+
+async fn send_batch_report(
+    socket: &Socket,
+    batch: &[(u32, Vec<u8>)],
+    results: &mut Vec<Result<Response, ModuleError>>,
+) -> Result<(), FatalError> {
+    let batch_id = socket.packet_counter.fetch_add(1, Ordering::Relaxed);
+
+    // All responses are enqueued before writing, as they may arrive in the same input report.
+
+    let mut response_channels = Vec::with_capacity(batch.len() + 1);
+    let mut queue_guards = Vec::with_capacity(batch.len() + 1);
+
+    for send_id in std::iter::once(batch_id).chain(batch.iter().map(|(id, _)| *id)) {
+        response_channels.push((send_id, enqueue_for_response(socket, send_id)));
+        queue_guards.push(QueueEntryGuard { socket, send_id });
+    }
+
+    write_batch_report(socket.output.lock().await.deref_mut(), batch_id, batch).await?;
+
+    let mut response_channels = response_channels.into_iter();
+
+    let (send_id, response_channel) = response_channels.next().unwrap();
+
+    // The device acknowledges the batch after the responses of the contained commands. A device not supporting
+    // batches answers the batch itself with an error, but none of the contained commands.
+
+    match receive_response(socket, send_id, response_channel).await? {
+        Ok(Response::Batch { result: Ok(_) }) => {}
+        Ok(Response::Batch { result: Err(x) }) | Err(x) => {
+            results.resize(batch.len(), Err(x));
+
+            return Ok(());
+        }
+        Ok(_) => return Err(FatalError::from(ProtocolError::ReceivedWrongResponse)),
+    }
+
+    for (send_id, response_channel) in response_channels {
+        results.push(receive_response(socket, send_id, response_channel).await?);
+    }
+
+    Ok(())
+}
+
+#[inline]
+async fn receive_response(
+    socket: &Socket,
+    send_id: u32,
+    response_channel: Receiver<Result<Result<Response, ModuleError>, FatalError>>,
+) -> Result<Result<Response, ModuleError>, FatalError> {
+    // read_and_distribute_response:
+    // This method continuously waits for incoming HID reports in a loop.
+    // If it receives a report with a matching send_id, it returns the report.
+    // Otherwise, it places the report in a queue and continues looping.
+    // This method blocks if another thread/task is already blocking.
+
+    let future_left = read_and_distribute_response(&socket, send_id);
+
+    // response_channel:
+    // This method is used when read_and_distribute_response is already blocking.
+    // It waits for the other thread/task to send the desired report.
+
+    let future_right = async { response_channel.await.unwrap_or_else(|_| Err(FatalError::DeviceClosed)) };
+
+    // race:
+    // This function runs both methods concurrently.
+    // When the first thread/task receives its report and completes,
+    // the second thread/task can take its place reading reports.
+
+    (async move { future_left.await }, async move { future_right.await })
+        .race()
+        .await
+}
+
+async fn open_connection(iotzio_info: &IotzioInfo) -> Result<(SocketOutput, SocketInput), InitializationError> {
     let (mut reader, mut writer) =
         iotzio_info
             .device_info
@@ -47,64 +377,194 @@ pub async fn new_socket(iotzio_info: &IotzioInfo) -> Result<Socket, Initializati
 
     let output_buffer_size = get_buffer_size(&output_reports)?;
 
-    let identifier = AtomicU32::new(0);
-
     let output = SocketOutput {
         writer,
         buffer: vec![0u8; output_buffer_size].into_boxed_slice(),
         reports: output_reports,
-    }
-    .into();
+    };
 
     let input = SocketInput {
         reader,
         buffer: vec![0u8; input_buffer_size].into_boxed_slice(),
+    };
+
+    Ok((output, input))
+}
+
+async fn reconnect(socket: &Socket, reconnect_state: &ReconnectState) -> Result<(), FatalError> {
+    let _reconnect_guard = reconnect_state.reconnect_mutex.lock().await;
+
+    if !reconnect_state.disconnected.load(Ordering::Acquire) {
+        return Ok(()); // Another task reconnected in the meantime.
     }
-    .into();
 
-    Ok(Socket {
-        output,
-        input,
-        packet_counter: identifier,
-        input_queue_mutex: Default::default(),
-        runtime_identifier,
-    })
+    // Serial numbers may not be available before opening (Android, Webassembly). Those boards are opened and compared afterwards.
+
+    let iotzio_infos = iotzio_service::list_connected_boards()
+        .await
+        .map_err(|_| FatalError::DeviceClosed)?;
+
+    let previous_runtime_identifier = **socket.runtime_identifier.lock().unwrap();
+
+    for iotzio_info in iotzio_infos.iter().filter(|x| {
+        x.serial_number
+            .as_ref()
+            .is_none_or(|serial_number| serial_number.eq(&reconnect_state.serial_number))
+    }) {
+        match reconnect_to(socket, reconnect_state, iotzio_info).await {
+            Ok(_) => {
+                debug!("Reconnected to Iotzio board {0}.", reconnect_state.serial_number);
+
+                reconnect_state.reconnect_count.fetch_add(1, Ordering::AcqRel);
+                reconnect_state.disconnected.store(false, Ordering::Release);
+
+                return Ok(());
+            }
+            Err(x) => debug!("Reconnect attempt failed: {0}", x),
+        }
+    }
+
+    socket
+        .runtime_identifier
+        .lock()
+        .unwrap()
+        .replace(previous_runtime_identifier);
+
+    Err(FatalError::DeviceClosed)
 }
 
-pub async fn send_command(socket: &Socket, command: Command) -> Result<Result<Response, ModuleError>, FatalError> {
-    let send_id = socket.packet_counter.fetch_add(1, Ordering::Relaxed);
+async fn reconnect_to(
+    socket: &Socket,
+    reconnect_state: &ReconnectState,
+    iotzio_info: &IotzioInfo,
+) -> Result<(), InitializationError> {
+    if !socket
+        .runtime_identifier
+        .lock()
+        .unwrap()
+        .replace(iotzio_info.runtime_identifier)
+    {
+        return Err(InitializationError::DeviceAlreadyInUseError);
+    }
 
-    let host_report = HostReport {
-        identifier: send_id,
-        command,
+    let (output, input) = open_connection(iotzio_info).await?;
+
+    *socket.output.lock().await = output;
+    *socket.input.lock().await = input;
+
+    let board_info = match send_command(socket, Command::Initialize).await? {
+        Ok(Response::Initialize { board_info }) => board_info,
+        _ => {
+            return Err(InitializationError::from(FatalError::from(
+                ProtocolError::ReceivedWrongResponse,
+            )))
+        }
     };
 
-    write_report(socket.output.lock().await.deref_mut(), host_report).await?;
+    if board_info.serial_number != reconnect_state.serial_number {
+        return Err(InitializationError::DeviceOpenError {
+            error_message: "Iotzio board has a different serial number.".to_string(),
+        });
+    }
 
-    let response_channel = enqueue_for_response(&socket, send_id).await;
+    // Modules are set up again in their original order, output pins with their last known level.
 
-    // read_and_distribute_response:
-    // This method continuously waits for incoming HID reports in a loop.
-    // If it receives a report with a matching send_id, it returns the report.
-    // Otherwise, it places the report in a queue and continues looping.
-    // This method blocks if another thread/task is already blocking.
+    let modules = reconnect_state.modules.lock().unwrap().clone();
 
-    let future_left = read_and_distribute_response(&socket, send_id);
+    for command in modules {
+        reconnect_state.restore(&command);
 
-    // response_channel:
-    // This method is used when read_and_distribute_response is already blocking.
-    // It waits for the other thread/task to send the desired report.
+        send_command(socket, command)
+            .await?
+            .map_err(|x| InitializationError::DeviceOpenError {
+                error_message: format!("Error setting up module again: {0}", x),
+            })?;
+    }
 
-    let future_right = async { response_channel.await.unwrap_or_else(|_| Err(FatalError::DeviceClosed)) };
+    Ok(())
+}
 
-    // race:
-    // This function runs both methods concurrently.
-    // When the first thread/task receives its report and completes,
-    // the second thread/task can take its place reading reports.
+#[inline]
+fn is_waiting_command(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::InputPin_WaitForSignal { .. }
+            | Command::InputPort_WaitForChange { .. }
+            | Command::Counter_Measure { .. }
+            | Command::Servo_Sweep { .. }
+            | Command::Stepper_MoveTo { .. }
+            | Command::Stepper_MoveBy { .. }
+            | Command::Stepper_Stop { .. }
+            | Command::Uart_Read { .. }
+    )
+}
 
-    (async move { future_left.await }, async move { future_right.await })
-        .race()
-        .await
+/// Removes the input queue entry of a command, also if its future is dropped before the response arrived.
+struct QueueEntryGuard<'a> {
+    socket: &'a Socket,
+    send_id: u32,
+}
+
+impl Drop for QueueEntryGuard<'_> {
+    fn drop(&mut self) {
+        self.socket
+            .input_queue_mutex
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| *id != self.send_id);
+    }
+}
+
+/// Get the command dropping the module set up by a command, if any.
+fn get_drop_command(command: &Command) -> Option<Command> {
+    let drop_command = match command {
+        Command::InputPin_New { pin, .. } => Command::InputPin_Drop { pin: *pin },
+        Command::OutputPin_New { pin, .. } => Command::OutputPin_Drop { pin: *pin },
+        Command::I2c_New { config } => Command::I2c_Drop {
+            identifier: i2c_bus_number(config),
+        },
+        Command::Spi_New { config } => Command::Spi_Drop {
+            identifier: match config {
+                SpiConfig::Spi0 { .. } => SpiBusNumber::Spi0,
+                SpiConfig::Spi1 { .. } => SpiBusNumber::Spi1,
+            },
+        },
+        Command::PwmOutput_New { pin, .. } => Command::PwmOutput_Drop { pin: *pin },
+        Command::AnalogInput_New { channel } => Command::AnalogInput_Drop { channel: *channel },
+        Command::Uart_New { config } => Command::Uart_Drop {
+            identifier: match config {
+                UartConfig::Uart0 { .. } => UartNumber::Uart0,
+                UartConfig::Uart1 { .. } => UartNumber::Uart1,
+            },
+        },
+        Command::OneWire_New { pin } => Command::OneWire_Drop { pin: *pin },
+        Command::I2cTarget_New { config, .. } => Command::I2cTarget_Drop {
+            identifier: match config {
+                I2cTargetConfig::I2c0 { .. } => I2cBusNumber::I2c0,
+                I2cTargetConfig::I2c1 { .. } => I2cBusNumber::I2c1,
+            },
+        },
+        Command::OutputPort_New { pins } => Command::OutputPort_Drop {
+            pin_mask: port_pin_mask(pins.iter().map(|x| x.pin)),
+        },
+        Command::InputPort_New { pins } => Command::InputPort_Drop {
+            pin_mask: port_pin_mask(pins.iter().map(|x| x.pin)),
+        },
+        Command::InputPin_Subscribe { pin, .. } => Command::InputPin_Unsubscribe { pin: *pin },
+        Command::Counter_New { pin, .. } => Command::Counter_Drop { pin: *pin },
+        Command::QuadratureEncoder_New { pin_a, .. } => Command::QuadratureEncoder_Drop { pin_a: *pin_a },
+        Command::LedStrip_New { pin, .. } => Command::LedStrip_Drop { pin: *pin },
+        Command::Servo_New { pin, .. } => Command::Servo_Drop { pin: *pin },
+        Command::Stepper_New { config, .. } => Command::Stepper_Drop {
+            step_pin: config.step_pin,
+        },
+        Command::PulseIn_New { config } => Command::PulseIn_Drop {
+            trigger_pin: config.trigger_pin,
+        },
+        _ => return None,
+    };
+
+    Some(drop_command)
 }
 
 #[inline]
@@ -115,75 +575,120 @@ async fn read_and_distribute_response(
     let mut input = socket.input.lock().await;
 
     loop {
-        let device_report_result = read(&mut input).await;
+        let device_reports_result = read(&mut input).await;
 
         if log_enabled!(Trace) {
-            match &device_report_result {
-                Ok(x) => trace!("Received {0}", x),
+            match &device_reports_result {
+                Ok(x) => x.iter().for_each(|x| trace!("Received {0}", x)),
                 Err(x) => trace!("Received {0}", x),
             }
         }
 
-        let response_result = device_report_result.and_then(|x| match x {
-            DeviceReport::Response { identifier, result } => Ok((identifier, result)),
-            DeviceReport::FatalError { error } => Err(error),
-        });
+        // Reports are distributed without awaiting anything after reading. Otherwise, a reading future dropped
+        // in between (e.g. by a timeout) would lose the responses of other tasks.
 
-        let mut input_queue = socket.input_queue_mutex.lock().await;
+        let mut input_queue = socket.input_queue_mutex.lock().unwrap();
 
-        match response_result {
-            Ok((received_id, result)) => {
-                // Get entry from input_queue for matching received_id.
+        let device_reports = match device_reports_result {
+            Ok(x) => x,
+            Err(error) => return Err(distribute_fatal_error(socket, &mut input_queue, send_id, error)),
+        };
 
-                let (_, sender) = match input_queue.iter_mut().filter(|(id, _)| *id == received_id).next() {
-                    None => continue,
-                    Some(x) => x,
-                };
+        // A single input report may contain the responses of a whole batch. All of them are distributed,
+        // before our own response is returned.
 
-                match send_id == received_id {
-                    true => {
-                        // We send this command and this is our response, we return this report.
-                        // We do NOT send it to the queue, just remove entry from there.
+        let mut own_result = None;
 
-                        input_queue.retain(|(id, sender)| *id != received_id && !sender.is_closed());
+        for device_report in device_reports {
+            let (received_id, result) = match device_report {
+                DeviceReport::Response { identifier, result } => (identifier, result),
+                DeviceReport::FatalError { error } => {
+                    return Err(distribute_fatal_error(socket, &mut input_queue, send_id, error));
+                }
+                DeviceReport::Batch { .. } => {
+                    let error = FatalError::from(ProtocolError::ReceivedWrongResponse);
 
-                        return Ok(result);
+                    return Err(distribute_fatal_error(socket, &mut input_queue, send_id, error));
+                }
+                DeviceReport::Event { subscription, event } => {
+                    // Events of a removed subscription may still arrive, until the device processed the unsubscribe.
+
+                    if let Some((_, sender)) = socket
+                        .subscriptions
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .find(|(id, _)| *id == subscription)
+                    {
+                        _ = sender.try_send(event);
                     }
-                    false => {
-                        // We send a command, but this is some ones other response.
-                        // We send it to the queue, and then remove the entry from there.
-
-                        _ = sender.send(Ok(result));
 
-                        input_queue.retain(|(id, sender)| *id != received_id && !sender.is_closed());
-                    }
+                    continue;
                 }
-            }
-            Err(error) => {
-                // Shit hits the fan. Send error to all queue entries, except entry from our self.
-                // We empty input_queue and return the error.
+            };
+
+            // Get entry from input_queue for matching received_id.
+
+            let (_, sender) = match input_queue.iter_mut().filter(|(id, _)| *id == received_id).next() {
+                None => continue,
+                Some(x) => x,
+            };
+
+            match send_id == received_id {
+                true => {
+                    // We send this command and this is our response, we return this report.
+                    // We do NOT send it to the queue, just remove entry from there.
 
-                input_queue
-                    .iter_mut()
-                    .filter(|(id, _)| *id != send_id)
-                    .for_each(|(_, sender)| {
-                        _ = sender.send(Err(error.clone()));
-                    });
+                    input_queue.retain(|(id, sender)| *id != received_id && !sender.is_closed());
+
+                    own_result = Some(result);
+                }
+                false => {
+                    // We send a command, but this is some ones other response.
+                    // We send it to the queue, and then remove the entry from there.
 
-                input_queue.clear();
+                    _ = sender.send(Ok(result));
 
-                return Err(error);
+                    input_queue.retain(|(id, sender)| *id != received_id && !sender.is_closed());
+                }
             }
         }
+
+        if let Some(result) = own_result {
+            return Ok(result);
+        }
     }
 }
 
 #[inline]
-async fn enqueue_for_response(
+fn distribute_fatal_error(
     socket: &Socket,
+    input_queue: &mut InputQueue,
     send_id: u32,
-) -> Receiver<Result<Result<Response, ModuleError>, FatalError>> {
-    let mut input_queue = socket.input_queue_mutex.lock().await;
+    error: FatalError,
+) -> FatalError {
+    // Shit hits the fan. Send error to all queue entries, except entry from our self.
+    // We empty input_queue and return the error.
+
+    input_queue
+        .iter_mut()
+        .filter(|(id, _)| *id != send_id)
+        .for_each(|(_, sender)| {
+            _ = sender.send(Err(error.clone()));
+        });
+
+    input_queue.clear();
+
+    // Subscriptions end, once their buffered events are received.
+
+    socket.subscriptions.lock().unwrap().clear();
+
+    error
+}
+
+#[inline]
+fn enqueue_for_response(socket: &Socket, send_id: u32) -> Receiver<Result<Result<Response, ModuleError>, FatalError>> {
+    let mut input_queue = socket.input_queue_mutex.lock().unwrap();
 
     let (tx, rx) = async_oneshot::oneshot();
 
@@ -240,6 +745,73 @@ async fn write_report(output: &mut SocketOutput, host_report: HostReport) -> Res
         .map_err(|x| FatalError::read_error(format!("Error writing data to Iotzio device: {0}", x)))
 }
 
+#[inline]
+async fn write_batch_report(
+    output: &mut SocketOutput,
+    batch_id: u32,
+    batch: &[(u32, Vec<u8>)],
+) -> Result<(), FatalError> {
+    let command = Command::Batch {
+        count: batch.len() as u8,
+    };
+
+    trace!(
+        "Send {0} with identifiers {1:?}",
+        &command,
+        batch.iter().map(|(id, _)| id).collect::<Vec<_>>()
+    );
+
+    output.buffer.fill(0x00);
+
+    // Send a new batch report. A batch host report has the following format:
+    // Report ID (u8), Identifier (u32), Command ID (u16), Postcard Batch Payload ([u8]), Postcard Host Reports ([u8])
+
+    let write_buffer_size = output.buffer.len();
+
+    let mut report_length = 1usize;
+
+    {
+        let identifier = batch_id.to_le_bytes();
+
+        output.buffer[report_length..report_length + identifier.len()].copy_from_slice(&identifier);
+        report_length += identifier.len();
+    }
+
+    {
+        let command_id = command.id().to_le_bytes();
+
+        output.buffer[report_length..report_length + command_id.len()].copy_from_slice(&command_id);
+        report_length += command_id.len();
+    }
+
+    debug_assert_eq!(report_length, HOST_REPORT_HEADER_SIZE);
+
+    report_length += to_slice(
+        &command,
+        &mut output.buffer[HOST_REPORT_HEADER_SIZE..write_buffer_size - 1],
+    )
+    .map(|x| x.len())?;
+
+    for (_, bytes) in batch {
+        if report_length + bytes.len() > write_buffer_size - 1 {
+            return Err(FatalError::from(ProtocolError::ErrorSelectingReportId));
+        }
+
+        output.buffer[report_length..report_length + bytes.len()].copy_from_slice(bytes);
+        report_length += bytes.len();
+    }
+
+    let (report_id, report_count) = get_report_id(&output.reports, report_length)?;
+
+    output.buffer[0] = report_id;
+
+    output
+        .writer
+        .write_output_report(&mut output.buffer[..report_count + 1]) // Report Count + Report ID
+        .await
+        .map_err(|x| FatalError::read_error(format!("Error writing data to Iotzio device: {0}", x)))
+}
+
 #[inline]
 fn get_report_id(output_reports: &Vec<(u8, usize)>, payload_size: usize) -> Result<(u8, usize), FatalError> {
     let report_id = output_reports
@@ -253,7 +825,7 @@ fn get_report_id(output_reports: &Vec<(u8, usize)>, payload_size: usize) -> Resu
 }
 
 #[inline]
-async fn read(input: &mut SocketInput) -> Result<DeviceReport, FatalError> {
+async fn read(input: &mut SocketInput) -> Result<Vec<DeviceReport>, FatalError> {
     input.buffer.fill(0x00);
 
     let report_length = input
@@ -266,9 +838,28 @@ async fn read(input: &mut SocketInput) -> Result<DeviceReport, FatalError> {
         return Err(FatalError::from(ProtocolError::PacketTooSmall));
     }
 
-    let device_report = from_bytes::<DeviceReport>(&input.buffer[1..report_length])?;
+    // A device report has the following format:
+    // Report ID (u8), Postcard Payload ([u8])
+    // A batch device report is followed by the given count of further device reports:
+    // Report ID (u8), Postcard Batch Payload ([u8]), Postcard Payload ([u8]), Postcard Payload ([u8]), ...
+
+    let (device_report, mut remaining) = take_from_bytes::<DeviceReport>(&input.buffer[1..report_length])?;
+
+    match device_report {
+        DeviceReport::Batch { count } => {
+            let mut device_reports = Vec::with_capacity(count as usize);
+
+            for _ in 0..count {
+                let (device_report, x) = take_from_bytes::<DeviceReport>(remaining)?;
 
-    Ok(device_report)
+                device_reports.push(device_report);
+                remaining = x;
+            }
+
+            Ok(device_reports)
+        }
+        x => Ok(vec![x]),
+    }
 }
 
 #[inline]
@@ -0,0 +1,304 @@
+use async_std::stream::StreamExt;
+use async_std::task::block_on;
+use iotzio::modules::i2c_bus::{I2c0SclPin, I2c0SdaPin, I2cBusModuleError, I2cConfig};
+use iotzio::modules::input_pin::{InputPinEvent, InputPinModuleError, InterruptTrigger};
+use iotzio::modules::led_strip::{LedColor, LedStripType};
+use iotzio::modules::servo::ServoConfig;
+use iotzio::modules::ModuleError;
+use iotzio::peripherals::gpio::{Drive, GpioPin, Level, Pull, SlewRate};
+use iotzio::peripherals::i2c::{I2cAddress, I2cBusNumber, I2cError};
+use iotzio::simulator::{SimulatedBoard, VirtualRegisterMap};
+use iotzio::{BatchError, Iotzio, IotzioManager};
+use std::time::Duration;
+
+const EEPROM_ADDRESS: I2cAddress = I2cAddress::SevenBit { address: 0x50 };
+
+// Simulated boards are listed globally, so every test uses a board with its own serial number.
+
+fn open(board: &SimulatedBoard) -> Iotzio {
+    board.connect();
+
+    IotzioManager::new().open_by_serial(&board.serial_number()).unwrap()
+}
+
+fn i2c0_config() -> I2cConfig {
+    I2cConfig::I2c0 {
+        scl: I2c0SclPin::Pin1,
+        sda: I2c0SdaPin::Pin0,
+        requested_frequency_hz: None,
+        timeout: None,
+    }
+}
+
+#[test]
+fn output_pin_drives_virtual_pin() {
+    let board = SimulatedBoard::new("SIM-OUTPUT-PIN");
+    let iotzio = open(&board);
+
+    let output_pin = iotzio
+        .setup_output_pin(GpioPin::Pin2, Level::Low, Drive::FourMilliAmpere, SlewRate::Slow)
+        .unwrap();
+
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin2), Some(Level::Low));
+
+    output_pin.set_level(Level::High).unwrap();
+
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin2), Some(Level::High));
+
+    drop(output_pin);
+
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin2), None);
+}
+
+#[test]
+fn input_pin_reads_virtual_pin() {
+    let board = SimulatedBoard::new("SIM-INPUT-PIN");
+    let iotzio = open(&board);
+
+    let input_pin = iotzio.setup_input_pin(GpioPin::Pin3, Pull::Up, true).unwrap();
+
+    assert_eq!(input_pin.get_level().unwrap(), Level::High);
+
+    board.pin_bank().set_input_level(GpioPin::Pin3, Level::Low);
+
+    assert_eq!(input_pin.get_level().unwrap(), Level::Low);
+
+    board.pin_bank().release_input_level(GpioPin::Pin3);
+
+    assert_eq!(input_pin.get_level().unwrap(), Level::High);
+
+    std::thread::scope(|scope| {
+        let waiting = scope.spawn(|| input_pin.wait_for_falling_edge_timeout(Duration::from_secs(5)));
+
+        std::thread::sleep(Duration::from_millis(50));
+
+        board.pin_bank().set_input_level(GpioPin::Pin3, Level::Low);
+
+        waiting.join().unwrap().unwrap();
+    });
+}
+
+#[test]
+fn i2c_bus_talks_to_virtual_register_map() {
+    let board = SimulatedBoard::new("SIM-I2C-BUS");
+    let register_map = VirtualRegisterMap::new();
+
+    board.add_i2c_device(I2cBusNumber::I2c0, EEPROM_ADDRESS, register_map.clone());
+
+    let iotzio = open(&board);
+    let i2c_bus = iotzio.setup_i2c_bus(i2c0_config()).unwrap();
+
+    i2c_bus.write(EEPROM_ADDRESS, &[0x10, 0xAB, 0xCD]).unwrap();
+
+    assert_eq!(register_map.get_register(0x10), 0xAB);
+    assert_eq!(register_map.get_register(0x11), 0xCD);
+
+    let mut buffer = [0u8; 2];
+
+    i2c_bus.write_read(EEPROM_ADDRESS, &[0x10], &mut buffer).unwrap();
+
+    assert_eq!(buffer, [0xAB, 0xCD]);
+
+    assert_eq!(i2c_bus.scan().unwrap(), vec![0x50]);
+
+    let error = i2c_bus
+        .write(I2cAddress::SevenBit { address: 0x51 }, &[0x00])
+        .unwrap_err();
+
+    assert_eq!(
+        error,
+        I2cBusModuleError::I2cErrorWrapper {
+            error: I2cError::AbortNoAcknowledge
+        }
+    );
+}
+
+#[test]
+fn i2c_bus_reads_chunked() {
+    let board = SimulatedBoard::new("SIM-I2C-BUS-CHUNKED");
+    let register_map = VirtualRegisterMap::new();
+
+    let registers: Vec<u8> = (0..=255).collect();
+
+    register_map.set_registers(0x00, &registers);
+
+    board.add_i2c_device(I2cBusNumber::I2c0, EEPROM_ADDRESS, register_map);
+
+    let iotzio = open(&board);
+    let i2c_bus = iotzio.setup_i2c_bus(i2c0_config()).unwrap();
+
+    // Larger than a single bus buffer, so the read is split into chunks.
+
+    let mut buffer = vec![0u8; 1000];
+
+    i2c_bus.write_read(EEPROM_ADDRESS, &[0x00], &mut buffer).unwrap();
+
+    assert!(buffer.iter().enumerate().all(|(index, value)| *value == index as u8));
+}
+
+#[test]
+fn resilient_reconnect_restores_output_pin_level() {
+    let board = SimulatedBoard::new("SIM-RESILIENT");
+
+    board.connect();
+
+    let iotzio = IotzioManager::new()
+        .list_connected_boards()
+        .unwrap()
+        .into_iter()
+        .find(|x| x.serial_number() == Some(board.serial_number()))
+        .unwrap()
+        .open_resilient()
+        .unwrap();
+
+    let _output_pin = iotzio
+        .setup_output_pin(GpioPin::Pin2, Level::High, Drive::FourMilliAmpere, SlewRate::Slow)
+        .unwrap();
+
+    board.disconnect();
+
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin2), None);
+
+    // The first command after losing the board fails, the next one reconnects and sets up the output pin again.
+
+    assert!(iotzio.setup_input_pin(GpioPin::Pin3, Pull::Up, true).is_err());
+
+    board.connect();
+
+    let _input_pin = iotzio.setup_input_pin(GpioPin::Pin3, Pull::Up, true).unwrap();
+
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin2), Some(Level::High));
+}
+
+#[test]
+fn input_pin_wait_is_cancelled_after_timeout() {
+    let board = SimulatedBoard::new("SIM-INPUT-PIN-CANCEL");
+    let iotzio = open(&board);
+
+    let input_pin = iotzio.setup_input_pin(GpioPin::Pin3, Pull::Up, true).unwrap();
+
+    let error = input_pin.wait_for_low_timeout(Duration::from_millis(50)).unwrap_err();
+
+    assert_eq!(
+        error,
+        InputPinModuleError::ModuleErrorWrapper {
+            error: ModuleError::CommandTimeout
+        }
+    );
+
+    // The cancellation of the timed out wait is sent ahead of the next command. Its late response must not
+    // be taken for the response of the next wait.
+
+    assert_eq!(input_pin.get_level().unwrap(), Level::High);
+
+    std::thread::scope(|scope| {
+        let waiting = scope.spawn(|| input_pin.wait_for_falling_edge_timeout(Duration::from_secs(5)));
+
+        std::thread::sleep(Duration::from_millis(50));
+
+        board.pin_bank().set_input_level(GpioPin::Pin3, Level::Low);
+
+        waiting.join().unwrap().unwrap();
+    });
+
+    assert_eq!(input_pin.get_level().unwrap(), Level::Low);
+}
+
+#[test]
+fn batch_runs_all_commands_and_returns_first_error() {
+    let board = SimulatedBoard::new("SIM-BATCH");
+    let iotzio = open(&board);
+
+    let output_pin_a = iotzio
+        .setup_output_pin(GpioPin::Pin2, Level::Low, Drive::FourMilliAmpere, SlewRate::Slow)
+        .unwrap();
+
+    let output_pin_b = iotzio
+        .setup_output_pin(GpioPin::Pin4, Level::Low, Drive::FourMilliAmpere, SlewRate::Slow)
+        .unwrap();
+
+    let i2c_bus = iotzio.setup_i2c_bus(i2c0_config()).unwrap();
+
+    // Nothing answers at the I2C address, so the second command fails. The commands after it are still executed.
+
+    let error = iotzio
+        .batch(|batch| {
+            batch
+                .set_level(&output_pin_a, Level::High)
+                .i2c_write(&i2c_bus, I2cAddress::SevenBit { address: 0x51 }, &[0x00])
+                .set_level(&output_pin_b, Level::High);
+        })
+        .unwrap_err();
+
+    assert!(matches!(error, BatchError::I2cBusCommandFailed { index: 1, .. }));
+
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin2), Some(Level::High));
+    assert_eq!(board.pin_bank().get_output_level(GpioPin::Pin4), Some(Level::High));
+}
+
+#[test]
+fn input_pin_subscription_receives_events_during_other_command() {
+    let board = SimulatedBoard::new("SIM-INPUT-PIN-SUBSCRIPTION");
+    let iotzio = open(&board);
+
+    let subscribed_pin = iotzio.setup_input_pin(GpioPin::Pin3, Pull::Up, true).unwrap();
+    let waiting_pin = iotzio.setup_input_pin(GpioPin::Pin5, Pull::Down, true).unwrap();
+
+    let mut events = subscribed_pin.subscribe(InterruptTrigger::EdgeLow).unwrap();
+
+    std::thread::scope(|scope| {
+        let waiting = scope.spawn(|| waiting_pin.wait_for_high_timeout(Duration::from_secs(5)));
+
+        std::thread::sleep(Duration::from_millis(50));
+
+        for _ in 0..2 {
+            board.pin_bank().set_input_level(GpioPin::Pin3, Level::Low);
+
+            let event = block_on(events.next()).unwrap().unwrap();
+
+            assert!(matches!(event, InputPinEvent::Triggered { level: Level::Low, .. }));
+
+            board.pin_bank().release_input_level(GpioPin::Pin3);
+        }
+
+        assert!(!waiting.is_finished());
+
+        board.pin_bank().set_input_level(GpioPin::Pin5, Level::High);
+
+        waiting.join().unwrap().unwrap();
+    });
+}
+
+#[test]
+fn led_strip_shows_colors() {
+    let board = SimulatedBoard::new("SIM-LED-STRIP");
+    let iotzio = open(&board);
+
+    let led_strip = iotzio
+        .setup_led_strip(GpioPin::Pin4, LedStripType::Ws2812, 2, 255)
+        .unwrap();
+
+    let colors = [LedColor::rgb(255, 0, 0), LedColor::rgb(0, 0, 255)];
+
+    led_strip.write(&colors).unwrap();
+
+    assert_eq!(
+        board.pin_bank().get_led_strip_colors(GpioPin::Pin4),
+        Some(colors.to_vec())
+    );
+}
+
+#[test]
+fn servo_sets_pulse_width() {
+    let board = SimulatedBoard::new("SIM-SERVO");
+    let iotzio = open(&board);
+
+    let servo = iotzio.setup_servo(GpioPin::Pin6, ServoConfig::default()).unwrap();
+
+    servo.set_angle(90.0).unwrap();
+
+    assert_eq!(
+        board.pin_bank().get_servo_pulse_width(GpioPin::Pin6),
+        Some(Duration::from_micros(1500))
+    );
+}